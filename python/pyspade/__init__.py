@@ -5,7 +5,173 @@ This module provides Python bindings for the Spade library, offering high-perfor
 constrained Delaunay triangulation with mesh refinement capabilities.
 """
 
-from .pyspade import triangulate
+import asyncio
+import functools
+import logging
+from typing import Any, Dict
+
+from .pyspade import (
+    triangulate,
+    voronoi,
+    power_diagram,
+    largest_empty_circle,
+    emst,
+    knn_graph,
+    gabriel_graph,
+    relative_neighborhood_graph,
+    triangulate_regions,
+    mesh_subdomains,
+    triangulate_tiled,
+    mesh_terrain,
+    mesh_polylines,
+    tin,
+    thin_points,
+    grid_to_tin,
+    adaptive_tin,
+    simplify_tin,
+    lods,
+    rasterize,
+    clip,
+    merge,
+    half_edges,
+    vertex_adjacency,
+    to_csr,
+    dual_graph,
+    boundary_levels,
+    connected_components,
+    partition,
+    halo,
+    optimize_vertex_cache,
+    planar_uvs,
+    vertex_normals,
+    vertex_gradients,
+    vertex_curvature,
+    flow_accumulation,
+    filled_contours,
+    isobands,
+    orient_facets,
+    hillshade,
+    triangulate_anisotropic,
+    quadrangulate,
+    quadratic_elements,
+    remesh,
+    smooth,
+    refine_adaptive,
+    split_edge,
+    collapse_edge,
+    flip_edge,
+    validate_mesh,
+    mesh_statistics,
+    mesh_distance,
+    sample_points,
+    polygon_area,
+    polygon_centroid,
+    polygon_is_ccw,
+    polygon_contains_point,
+    points_in_polygon,
+    distance_to_constraints,
+    intersect_segment,
+    line_of_sight,
+    lines_of_sight,
+    TriangulationBuilder,
+    Triangulator,
+    Interpolator,
+    check_constraints,
+    validate,
+    repair,
+    suggest_parameters,
+)
 
 __version__ = "0.1.0"
-__all__ = ["triangulate"]
+
+# Silent unless the application configures logging
+logging.getLogger(__name__).addHandler(logging.NullHandler())
+
+
+async def triangulate_async(*args: Any, **kwargs: Any) -> Dict[str, Any]:
+    """
+    Awaitable version of triangulate(), taking the same arguments.
+
+    The triangulation runs on a worker thread and releases the GIL while
+    meshing, so an asyncio event loop (e.g. a FastAPI service) keeps serving
+    other requests during long refinements.
+
+    Example:
+        >>> mesh = await pyspade.triangulate_async(outer, holes, max_edge_length=0.5)
+    """
+    loop = asyncio.get_running_loop()
+    return await loop.run_in_executor(None, functools.partial(triangulate, *args, **kwargs))
+
+
+__all__ = [
+    "triangulate",
+    "voronoi",
+    "power_diagram",
+    "largest_empty_circle",
+    "emst",
+    "knn_graph",
+    "gabriel_graph",
+    "relative_neighborhood_graph",
+    "triangulate_regions",
+    "mesh_subdomains",
+    "triangulate_tiled",
+    "mesh_terrain",
+    "mesh_polylines",
+    "tin",
+    "thin_points",
+    "grid_to_tin",
+    "adaptive_tin",
+    "simplify_tin",
+    "lods",
+    "rasterize",
+    "clip",
+    "merge",
+    "half_edges",
+    "vertex_adjacency",
+    "to_csr",
+    "dual_graph",
+    "boundary_levels",
+    "connected_components",
+    "partition",
+    "halo",
+    "optimize_vertex_cache",
+    "planar_uvs",
+    "vertex_normals",
+    "vertex_gradients",
+    "vertex_curvature",
+    "flow_accumulation",
+    "filled_contours",
+    "isobands",
+    "orient_facets",
+    "hillshade",
+    "triangulate_anisotropic",
+    "quadrangulate",
+    "quadratic_elements",
+    "remesh",
+    "smooth",
+    "refine_adaptive",
+    "split_edge",
+    "collapse_edge",
+    "flip_edge",
+    "validate_mesh",
+    "mesh_statistics",
+    "mesh_distance",
+    "sample_points",
+    "polygon_area",
+    "polygon_centroid",
+    "polygon_is_ccw",
+    "polygon_contains_point",
+    "points_in_polygon",
+    "distance_to_constraints",
+    "intersect_segment",
+    "line_of_sight",
+    "lines_of_sight",
+    "TriangulationBuilder",
+    "Triangulator",
+    "Interpolator",
+    "triangulate_async",
+    "check_constraints",
+    "validate",
+    "repair",
+    "suggest_parameters",
+]
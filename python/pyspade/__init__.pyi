@@ -1,29 +1,196 @@
 """Type stubs for pyspade"""
 
-from typing import Dict, List, Optional, Tuple
+from typing import Any, Callable, Dict, Iterable, List, Optional, Tuple, Union
+
+# A ring vertex (x, y) or a circular arc dict with 'center', 'radius', 'start', 'end'
+RingItem = Union[Tuple[float, float], Dict[str, Any]]
+
+# An (n, 2) NumPy array or a list of (x, y) tuples
+PointArray = Union[Any, List[Tuple[float, float]]]
 
 def triangulate(
-    outer: List[Tuple[float, float]],
-    holes: Optional[List[List[Tuple[float, float]]]] = None,
+    outer: List[RingItem],
+    holes: Optional[List[List[RingItem]]] = None,
     max_edge_length: Optional[float] = None,
     min_angle: Optional[float] = None,
     triangulate_holes: bool = False,
-) -> Dict[str, List]:
+    region_sizes: Optional[List[Tuple[float, float, float]]] = None,
+    protected_segments: Optional[List[int]] = None,
+    segment_markers: Optional[List[int]] = None,
+    vertex_attributes: Optional[Dict[str, List[float]]] = None,
+    boundary_layers: Optional[Dict[str, Any]] = None,
+    diagnostics: bool = False,
+    grid_size: Optional[float] = None,
+    sliver_tolerance: Optional[float] = None,
+    relax_min_angle: bool = False,
+    max_edge_length_rel: Optional[float] = None,
+    timeout: Optional[float] = None,
+    return_hole_triangles: bool = False,
+    return_hull_triangles: bool = False,
+    winding: str = "ccw",
+    classify_edges: bool = False,
+    no_refine_zones: Optional[List[List[Tuple[float, float]]]] = None,
+    point_sizes: Optional[List[Tuple[float, float, float, float]]] = None,
+    line_sizes: Optional[List[Tuple[List[Tuple[float, float]], float, float]]] = None,
+    boundary_grading: Optional[Tuple[float, float]] = None,
+    background_mesh: Optional[Dict[str, Any]] = None,
+    background_sizes: Optional[List[float]] = None,
+    density_points: Optional[PointArray] = None,
+    density_neighbors: int = 4,
+    density_scale: float = 1.0,
+) -> Dict[str, Any]:
     """
     Triangulate a polygon with optional holes using constrained Delaunay triangulation.
 
+    Rings may contain circular arcs in place of vertices, given as dicts with
+    'center', 'radius', 'start' and 'end' (degrees, counter-clockwise from start
+    to end). Arcs are discretized to max_edge_length (and at most 22.5° per
+    piece) before meshing; segment and vertex numbering below refers to the
+    discretized rings.
+
     Args:
-        outer: Exterior boundary vertices as list of (x, y) tuples
-        holes: List of hole polygons, each as list of (x, y) tuples
-        max_edge_length: Target maximum edge length for mesh refinement
+        outer: Exterior boundary vertices as list of (x, y) tuples or arc dicts
+        holes: List of hole polygons, each as list of (x, y) tuples or arc dicts.
+            A hole made of one full arc (start=0, end=360) is a circle. Holes may
+            share vertices or edges with the outer ring or each other
+        max_edge_length: Target maximum edge length for mesh refinement.
+            Must be positive and finite; None means no limit
         min_angle: Minimum angle constraint in degrees (0-33.9°)
         triangulate_holes: If True, mesh inside holes; if False, exclude them
+        region_sizes: Local size limits as (x, y, max_edge_length) tuples. The region
+            around each seed point, bounded by the constraint edges, is meshed with
+            edges of roughly that length; elsewhere max_edge_length applies. As
+            max_edge_length caps every region, a region size above it raises a ValueError
+        protected_segments: Indices of input segments that refinement must not split,
+            numbered in input order (outer ring first, then each hole). All other
+            segments are subdivided up front to max_edge_length instead
+        segment_markers: Integer boundary marker per input segment (numbered as for
+            protected_segments), or one marker per ring. Markers are carried over to
+            every piece a segment is split into
+        vertex_attributes: Named per-vertex value arrays (e.g. temperature,
+            classification), one value per input vertex in input order (outer ring,
+            then each hole)
+        boundary_layers: Graded layers of thin triangles along selected input segments,
+            as a dict with 'segments' (segment indices, numbered as for
+            protected_segments), 'first_height' (thickness of the first layer),
+            'num_layers' and 'growth_rate' (thickness ratio of consecutive layers,
+            default 1.2). The layers are built before refinement and kept out of it;
+            requires max_edge_length and cannot be combined with triangulate_holes
+        diagnostics: If True, also return a report of what happened to the input
+            during meshing
+        grid_size: Snap all input coordinates to a uniform grid with this spacing
+            before meshing, e.g. 0.001 to absorb sub-millimeter jitter in survey
+            data. Vertices snapped together are merged and the segments between
+            them dropped; vertex and segment numbering refers to the input as given
+        sliver_tolerance: Remove slivers, triangles whose height over their longest
+            edge is below this length, by collapsing the vertex opposite that edge.
+            Nearly straight boundary runs are straightened within the tolerance;
+            constrained edges are kept (merged where a run is straightened) and
+            segment markers must agree across a merge
+        relax_min_angle: If refinement stops at its vertex limit before meeting
+            min_angle, retry with the angle lowered in steps of 5° until refinement
+            completes (down to 5°)
+        max_edge_length_rel: max_edge_length as a fraction of the diagonal of the
+            input's bounding box, e.g. 0.02 for 2%, so one setting suits inputs of
+            any scale. Cannot be combined with max_edge_length
+        timeout: Wall-clock limit in seconds for refinement. When it passes,
+            refinement stops and the partially refined mesh is returned with
+            'timed_out' set. The clock is checked between refinement rounds, so a
+            call can overrun it by one round; refinement in rounds has no vertex
+            limit, so the timeout is what bounds it
+        return_hole_triangles: If True, return the triangles meshed inside excluded
+            holes as 'hole_triangles' instead of discarding them, e.g. to give
+            courtyards a different material
+        return_hull_triangles: If True, return the triangles between the outer ring
+            and the convex hull of the input as 'hull_triangles', and leave them out
+            of 'triangles' (where they otherwise appear unless holes are excluded)
+        winding: Orientation of every output triangle's (i, j, k) in the xy plane,
+            "ccw" (counter-clockwise) or "cw"
+        classify_edges: If True, also return every edge of 'triangles' with its
+            origin
+        no_refine_zones: Polygons, each as list of (x, y) tuples, inside which
+            refinement inserts no vertices, e.g. under building slabs whose mesh is
+            replaced later. A zone's outline is added as constrained edges (split to
+            max_edge_length) and its inside keeps the triangulation of those edges.
+            Zones must lie inside the domain without overlapping holes or each
+            other; cannot be combined with triangulate_holes
+        point_sizes: Local sizes around point features (tower bases, inlets) as
+            (x, y, size, radius) tuples. Edges are about size long at the point,
+            growing linearly into max_edge_length at distance radius (without
+            max_edge_length, size applies up to radius). Input segments are split
+            to follow the sizes
+        line_sizes: Local sizes along line features (roads, rivers) as (polyline,
+            size, width) tuples, the polyline as list of (x, y) tuples. Sizes grow
+            from the line as they do from point_sizes, with width in place of
+            radius. The polylines only steer the size; they are not inserted as edges
+        boundary_grading: (h0, slope) for edges that grow with the distance d from
+            the outer and hole rings as h0 + slope * d (capped by max_edge_length
+            if given)
+        background_mesh: Mesh with 'vertices' and 'triangles' carrying a size field,
+            e.g. a coarse mesh from a previous adaptive step. Sizes are interpolated
+            linearly over its triangles and do not limit the mesh outside it.
+            Requires background_sizes
+        background_sizes: Target edge length at each vertex of background_mesh
+        density_points: Point cloud as an (n, 2) NumPy array or list of (x, y) tuples,
+            e.g. survey points, whose local spacing sets the size: at each point,
+            density_scale times the distance to its density_neighbors-th nearest
+            neighbor, interpolated linearly between the points
+        density_neighbors: Which nearest neighbor measures the spacing; 4 gives the
+            grid spacing on a square grid
+        density_scale: Factor from point spacing to edge length
 
     Returns:
         Dictionary with keys:
-            - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
-            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'vertices': List of (x, y, z) vertex coordinates (z=0.0). If every
+              input vertex is given as a pair of integers (and grid_size is not set),
+              the input vertices keep integer x and y; only vertices added by meshing
+              are floats
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based), all
+              oriented as given by winding
             - 'edges': List of (i, j) constrained edge indices
+            - 'edge_markers': Marker of each constrained edge, 0 where no input segment
+              covers it (only if segment_markers is given)
+            - 'vertex_attributes': The vertex_attributes arrays aligned with 'vertices',
+              linearly interpolated at vertices inserted by refinement (only if
+              vertex_attributes is given)
+            - 'achieved_min_angle': Smallest angle of the output triangles in
+              degrees, None if there are none (only if min_angle is given)
+            - 'min_angle_used': The angle limit of the final attempt (only if
+              relax_min_angle)
+            - 'mesh_edges': Every (i, j) edge of 'triangles' (only if classify_edges
+              is given)
+            - 'edge_origins': Origin of each mesh edge (only if classify_edges is
+              given): 'outer' or 'hole' for pieces of an input ring, 'constraint' for
+              other constrained edges (closing off boundary layers or no-refine
+              zones) and 'delaunay'
+              for unconstrained edges
+            - 'edge_rings': Ring of each mesh edge, 0 for the outer ring and k for
+              hole k - 1, or -1 if it is not on a ring (only if classify_edges is
+              given)
+            - 'hole_triangles': Triangles inside holes as (i, j, k) indices into
+              'vertices' (only if return_hole_triangles is given; empty with
+              triangulate_holes=True)
+            - 'hole_ids': Index into holes of the hole containing each hole triangle
+              (only if return_hole_triangles is given)
+            - 'hull_triangles': Triangles outside the outer ring within the convex
+              hull (only if return_hull_triangles is given)
+            - 'timed_out': True if refinement stopped at the timeout (only if
+              timeout is given)
+            - 'removed_slivers': Number of slivers removed (only if sliver_tolerance
+              is given)
+            - 'diagnostics': Dict (only if diagnostics=True) with keys:
+                - 'duplicate_vertices': Input vertices dropped as duplicates of an
+                  earlier one
+                - 'clockwise_rings': Rings given clockwise (0 is the outer ring, k
+                  hole k - 1), meshed as given
+                - 'degenerate_segments': Input segments skipped as their end points
+                  coincide
+                - 'split_segments': Input segments split into several constrained edges
+                - 'excluded_faces': Number of triangles left out as holes or exterior
+                - 'refinement': 'not_run', 'complete', 'vertex_limit' when
+                  refinement stopped before meeting every size and angle target, or
+                  'timeout'
+                - 'added_vertices': Number of vertices inserted after the input vertices
 
     Example:
         >>> import pyspade
@@ -35,4 +202,2355 @@ def triangulate(
     """
     ...
 
-__all__ = ["triangulate"]
+def voronoi(
+    points: List[Tuple[float, float]],
+    bounds: Optional[Tuple[float, float, float, float]] = None,
+) -> Dict[str, List]:
+    """
+    Compute the Voronoi diagram of a point set, clipped to a bounding box.
+
+    Args:
+        points: Site coordinates as list of (x, y) tuples
+        bounds: Clipping box as (xmin, ymin, xmax, ymax). Defaults to the
+            bounding box of the sites padded by 10% of its extent
+
+    Returns:
+        Dictionary with keys:
+            - 'cells': List of Voronoi cell polygons (counter-clockwise (x, y) lists), one per site
+            - 'areas': List of cell areas, one per site; duplicate sites share their cell's
+              area evenly, so the areas sum to the area of the clipping box
+            - 'centroids': List of (x, y) cell centroids, one per site
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.voronoi([(0, 0), (1, 0), (0, 1), (1, 1)])
+        >>> print(result['areas'])
+    """
+    ...
+
+def power_diagram(
+    points: List[Tuple[float, float]],
+    weights: List[float],
+    bounds: Optional[Tuple[float, float, float, float]] = None,
+) -> Dict[str, List]:
+    """
+    Compute the power diagram (weighted Voronoi diagram) of weighted sites and
+    its dual regular (weighted Delaunay) triangulation.
+
+    A point belongs to the cell of the site with the smallest power distance
+    |x - p|² - w, so a heavier site claims more area. Sites can be redundant:
+    a site outweighed by its neighbors has an empty cell and no triangles.
+    Coincident sites keep only the heaviest (the first on a tie).
+
+    Args:
+        points: Site coordinates as list of (x, y) tuples
+        weights: Weight of each site, in squared distance units (a weight of r²
+            grows a cell as a circle of radius r would)
+        bounds: Clipping box as (xmin, ymin, xmax, ymax). Defaults to the
+            bounding box of the sites padded by 10% of its extent
+
+    Returns:
+        Dictionary with keys:
+            - 'cells': List of power cell polygons (counter-clockwise (x, y) lists), one per
+              site; empty for redundant sites
+            - 'areas': List of cell areas, one per site
+            - 'centroids': List of (x, y) cell centroids, one per site ((nan, nan) if empty)
+            - 'edges': List of (i, j) site pairs whose cells share a boundary segment
+            - 'triangles': Regular triangulation of the non-redundant sites as (i, j, k) site
+              indices, counter-clockwise
+            - 'redundant': Indices of the sites with empty cells
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.power_diagram(depots, [c * c for c in capacities], bounds=(0, 0, 100, 100))
+        >>> print(result['areas'])
+    """
+    ...
+
+def largest_empty_circle(
+    points: List[Tuple[float, float]],
+    boundary: Optional[List[Tuple[float, float]]] = None,
+) -> Dict[str, Any]:
+    """
+    Find the largest circle whose center lies in the domain and that contains no input point.
+
+    Args:
+        points: Input points as list of (x, y) tuples
+        boundary: Polygon restricting the circle center. Defaults to the convex hull of the points
+
+    Returns:
+        Dictionary with keys:
+            - 'center': (x, y) center of the largest empty circle
+            - 'radius': Radius of the circle (distance to the nearest input point)
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.largest_empty_circle([(0, 0), (10, 0), (10, 10), (0, 10)])
+        >>> print(result['center'], result['radius'])
+    """
+    ...
+
+def emst(points: List[Tuple[float, float]]) -> Dict[str, List]:
+    """
+    Compute the Euclidean minimum spanning tree of a point set.
+
+    The tree is built with Kruskal's algorithm over the Delaunay edges,
+    which always contain the EMST.
+
+    Args:
+        points: Input points as list of (x, y) tuples
+
+    Returns:
+        Dictionary with keys:
+            - 'edges': List of (i, j) input point indices, one per tree edge
+            - 'lengths': List of edge lengths
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.emst([(0, 0), (1, 0), (5, 0), (5, 1)])
+        >>> print(result['edges'], sum(result['lengths']))
+    """
+    ...
+
+def knn_graph(points: List[Tuple[float, float]], k: int) -> Dict[str, Any]:
+    """
+    Compute the k nearest neighbors of every input point.
+
+    Neighbors are found by a best-first walk over the Delaunay graph, so no
+    separate spatial index is needed.
+
+    Args:
+        points: Input points as list of (x, y) tuples
+        k: Number of neighbors per point. Every point has N - 1 others, so k must be
+            at most N - 1; a larger k raises a RuntimeError rather than padding the rows
+
+    Returns:
+        Dictionary with keys:
+            - 'indices': (N, k) int64 NumPy array of neighbor indices, nearest first
+            - 'distances': (N, k) float64 NumPy array of the corresponding distances
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.knn_graph([(0, 0), (1, 0), (0, 1), (3, 3)], k=2)
+        >>> print(result['indices'][0])
+    """
+    ...
+
+def gabriel_graph(points: List[Tuple[float, float]]) -> Dict[str, List]:
+    """
+    Extract the Gabriel graph of a point set.
+
+    A Delaunay edge belongs to the Gabriel graph if the circle having the edge
+    as diameter contains no other input point.
+
+    Args:
+        points: Input points as list of (x, y) tuples
+
+    Returns:
+        Dictionary with keys:
+            - 'edges': List of (i, j) input point indices
+            - 'lengths': List of edge lengths
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.gabriel_graph([(0, 0), (2, 0), (1, 0.2), (1, 3)])
+        >>> print(result['edges'])
+    """
+    ...
+
+def relative_neighborhood_graph(
+    points: List[Tuple[float, float]],
+    method: str = "exact",
+) -> Dict[str, List]:
+    """
+    Extract the relative neighborhood graph (RNG) of a point set.
+
+    An edge (a, b) belongs to the RNG if no other point is closer to both a
+    and b than they are to each other.
+
+    Args:
+        points: Input points as list of (x, y) tuples
+        method: "exact" for the true RNG, or "urquhart" for the Urquhart graph
+            (Delaunay minus the longest edge of every triangle), a cheaper
+            superset of the RNG
+
+    Returns:
+        Dictionary with keys:
+            - 'edges': List of (i, j) input point indices
+            - 'lengths': List of edge lengths
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.relative_neighborhood_graph([(0, 0), (2, 0), (1, 0.5), (1, 3)])
+        >>> print(result['edges'])
+    """
+    ...
+
+def triangulate_regions(
+    regions: List[List[Tuple[float, float]]],
+    ids: Optional[List[int]] = None,
+    max_edge_length: Optional[float] = None,
+    min_angle: Optional[float] = None,
+) -> Dict[str, List]:
+    """
+    Triangulate several polygons that may share boundaries as one conforming mesh.
+
+    Vertices at identical positions are merged, so an edge shared by two
+    regions is constrained exactly once. Triangles outside all regions are dropped,
+    along with the vertices only they used.
+
+    Args:
+        regions: Region polygons, each as list of (x, y) tuples
+        ids: Integer id per region. Defaults to the region index
+        max_edge_length: Target maximum edge length for mesh refinement
+        min_angle: Minimum angle constraint in degrees (0-33.9°)
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edge indices
+            - 'labels': Region id of each triangle
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.triangulate_regions(
+        ...     regions=[[(0, 0), (5, 0), (5, 10), (0, 10)],
+        ...              [(5, 0), (10, 0), (10, 10), (5, 10)]],
+        ...     ids=[1, 2],
+        ...     max_edge_length=1.0
+        ... )
+        >>> print(set(result['labels']))
+    """
+    ...
+
+def mesh_subdomains(
+    domains: List[List[Tuple[float, float]]],
+    max_edge_length: Optional[float] = None,
+    min_angle: Optional[float] = None,
+) -> Dict[str, Any]:
+    """
+    Mesh adjacent subdomains so that their shared interfaces conform exactly.
+
+    All subdomains share one vertex array, so a vertex on an interface has
+    the same index in every subdomain that touches it.
+
+    Args:
+        domains: Subdomain polygons, each as list of (x, y) tuples
+        max_edge_length: Target maximum edge length for mesh refinement
+        min_angle: Minimum angle constraint in degrees (0-33.9°)
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates shared by all subdomains (z=0.0)
+            - 'domains': Per-subdomain lists of (i, j, k) triangles indexing 'vertices'
+            - 'interfaces': Dict mapping a subdomain pair (a, b) with a < b to the
+              sorted indices of the vertices on their common interface
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.mesh_subdomains(
+        ...     domains=[[(0, 0), (5, 0), (5, 10), (0, 10)],
+        ...              [(5, 0), (10, 0), (10, 10), (5, 10)]],
+        ...     max_edge_length=1.0
+        ... )
+        >>> print(result['interfaces'][(0, 1)])
+    """
+    ...
+
+def triangulate_tiled(
+    outer: List[Tuple[float, float]],
+    tile_size: float,
+    max_edge_length: float,
+    holes: Optional[List[List[Tuple[float, float]]]] = None,
+    min_angle: Optional[float] = None,
+    num_threads: Optional[int] = None,
+) -> Dict[str, Any]:
+    """
+    Mesh a large domain as a grid of independently meshed tiles.
+
+    The bounding box of `outer` is cut into square tiles of side `tile_size`,
+    which are meshed in parallel. Tile borders and the domain boundary are
+    split into segments of at most `max_edge_length` at positions computed
+    from global coordinates only, and refinement never splits them further, so
+    neighboring tiles have identical vertices along their common border and
+    the tiles join into one conforming mesh. Tiles outside the domain are
+    left out.
+
+    Args:
+        outer: Outer boundary as list of (x, y) tuples
+        tile_size: Side length of the square tiles
+        max_edge_length: Target maximum edge length for mesh refinement
+        holes: List of holes, each as list of (x, y) tuples
+        min_angle: Minimum angle constraint in degrees (0-33.9°)
+        num_threads: Worker threads. Default: the PYSPADE_NUM_THREADS
+            environment variable, or all available cores
+
+    Returns:
+        Dictionary with keys:
+            - 'tiles': List of tile meshes, each a dict with 'vertices', 'triangles',
+              'edges' (domain boundary edges in the tile), 'tile' ((column, row) in the
+              tile grid), 'bounds' ((xmin, ymin, xmax, ymax)) and 'global_indices'
+              (index into 'vertices' of each tile vertex)
+            - 'vertices': List of (x, y, z) coordinates of all distinct vertices (z=0.0);
+              a vertex on a tile border has the same global index in every tile
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.triangulate_tiled(
+        ...     outer=[(0, 0), (10000, 0), (10000, 10000), (0, 10000)],
+        ...     tile_size=1000.0,
+        ...     max_edge_length=10.0
+        ... )
+        >>> print(len(result['tiles']), len(result['vertices']))
+    """
+    ...
+
+def mesh_terrain(
+    domain: List[Tuple[float, float]],
+    footprints: List[List[Tuple[float, float]]],
+    max_edge_length: Optional[float] = None,
+    min_angle: Optional[float] = None,
+    footprint_max_edge_length: Optional[float] = None,
+) -> Dict[str, List]:
+    """
+    Mesh a terrain domain with building footprints constrained into it.
+
+    Footprint interiors are meshed too and labeled, so callers can drop,
+    extrude or flatten them.
+
+    Args:
+        domain: Terrain boundary as list of (x, y) tuples
+        footprints: Building footprint polygons, each as list of (x, y) tuples
+        max_edge_length: Target maximum edge length for mesh refinement
+        min_angle: Minimum angle constraint in degrees (0-33.9°)
+        footprint_max_edge_length: Finer target edge length inside footprints
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edge indices
+            - 'labels': Per triangle, -1 for ground or the index of the footprint containing it
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.mesh_terrain(
+        ...     domain=[(0, 0), (100, 0), (100, 100), (0, 100)],
+        ...     footprints=[[(20, 20), (40, 20), (40, 40), (20, 40)]],
+        ...     max_edge_length=10.0
+        ... )
+        >>> ground = [t for t, l in zip(result['triangles'], result['labels']) if l == -1]
+    """
+    ...
+
+def mesh_polylines(
+    domain: List[Tuple[float, float]],
+    polylines: List[List[Tuple[float, float]]],
+    max_edge_length: Optional[float] = None,
+    min_angle: Optional[float] = None,
+    corridor_width: Optional[float] = None,
+    corridor_max_edge_length: Optional[float] = None,
+) -> Dict[str, List]:
+    """
+    Mesh a domain in which every polyline becomes a chain of constrained edges.
+
+    Crossing polylines are split into shared vertices. With corridor_width, a
+    band around every polyline is meshed at corridor_max_edge_length.
+
+    Args:
+        domain: Domain boundary as list of (x, y) tuples
+        polylines: Open polylines, each as list of (x, y) tuples
+        max_edge_length: Target maximum edge length for mesh refinement
+        min_angle: Minimum angle constraint in degrees (0-33.9°)
+        corridor_width: Total width of the densified band around each polyline
+        corridor_max_edge_length: Edge length inside the corridor. Defaults to corridor_width / 2
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edge indices
+            - 'polylines': Per input polyline, the vertex indices of its constrained chain
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.mesh_polylines(
+        ...     domain=[(0, 0), (100, 0), (100, 100), (0, 100)],
+        ...     polylines=[[(10, 50), (90, 50)], [(50, 10), (50, 90)]],
+        ...     max_edge_length=10.0,
+        ...     corridor_width=8.0
+        ... )
+        >>> print(result['polylines'][0])
+    """
+    ...
+
+def tin(
+    points_xyz: Union[Any, List[Tuple[float, float, float]], Iterable[Any]],
+    boundary: Optional[List[Tuple[float, float]]] = None,
+    max_edge_length: Optional[float] = None,
+    vertex_attributes: Optional[Dict[str, List[float]]] = None,
+) -> Dict[str, Any]:
+    """
+    Build a ground TIN from scattered (x, y, z) points.
+
+    Args:
+        points_xyz: Ground points as an (n, 3) NumPy array or list of (x, y, z)
+            tuples, or any other iterable (e.g. a list of arrays, a generator or a
+            laspy chunk reader) yielding such arrays, which is consumed chunk by
+            chunk without concatenating the chunks
+        boundary: Clipping polygon as list of (x, y) tuples. Its vertices are inserted
+            with z interpolated from the points and only triangles inside it are kept
+        max_edge_length: Drop triangles with any edge longer than this, which removes
+            the long, flat triangles that bridge data gaps and concave hulls
+        vertex_attributes: Named per-point value arrays (e.g. intensity,
+            classification), one value per input point
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates
+            - 'normals': Area-weighted unit vertex normals
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) boundary edge indices (empty without a boundary)
+            - 'vertex_attributes': The vertex_attributes arrays aligned with 'vertices',
+              interpolated at boundary vertices like z (only if vertex_attributes is given)
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.tin(
+        ...     [(0, 0, 1.0), (10, 0, 2.0), (10, 10, 3.0), (0, 10, 2.5), (5, 5, 4.0)],
+        ...     boundary=[(1, 1), (9, 1), (9, 9), (1, 9)]
+        ... )
+        >>> print(result['vertices'][:3])
+    """
+    ...
+
+def thin_points(
+    points_xyz: List[Tuple[float, float, float]],
+    cell_size: float,
+    tolerance: Optional[float] = None,
+) -> Dict[str, List]:
+    """
+    Decimate a dense point cloud on a regular grid before triangulation.
+
+    Every grid cell keeps the point closest to its center. With tolerance, points
+    deviating from the cell's least-squares plane by more than tolerance are kept too.
+
+    Args:
+        points_xyz: Points as list of (x, y, z) tuples
+        cell_size: Grid cell size in x/y units
+        tolerance: Vertical deviation from the local plane above which additional
+            points are kept. Defaults to keeping one point per cell only
+
+    Returns:
+        Dictionary with keys:
+            - 'indices': Sorted indices of the kept input points
+            - 'points': The kept (x, y, z) points
+
+    Example:
+        >>> import pyspade
+        >>> thinned = pyspade.thin_points(points_xyz, cell_size=1.0, tolerance=0.1)
+        >>> mesh = pyspade.tin(thinned['points'])
+    """
+    ...
+
+def grid_to_tin(
+    array: List[List[float]],
+    transform: Tuple[float, float, float, float, float, float],
+    max_error: float,
+    max_vertices: Optional[int] = None,
+) -> Dict[str, Any]:
+    """
+    Convert a DEM raster into an adaptive TIN by greedy insertion.
+
+    Args:
+        array: Raster rows (top to bottom) of heights; NaN marks no-data cells
+        transform: GDAL-style geotransform (x0, pixel_width, row_rotation,
+            y0, column_rotation, pixel_height); use transform.to_gdal() for rasterio
+        max_error: Maximum allowed vertical deviation at any cell center
+        max_vertices: Stop once the TIN has this many vertices
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates at cell centers
+            - 'normals': Area-weighted unit vertex normals
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'max_error': Largest remaining vertical error
+
+    Example:
+        >>> import pyspade
+        >>> dem = [[0.0, 1.0, 2.0], [1.0, 2.0, 3.0], [2.0, 3.0, 9.0]]
+        >>> result = pyspade.grid_to_tin(dem, (0, 1, 0, 3, 0, -1), max_error=0.5)
+        >>> print(len(result['vertices']))
+    """
+    ...
+
+def adaptive_tin(
+    points_xyz: Union[Any, List[Tuple[float, float, float]]],
+    max_error: float,
+    max_vertices: Optional[int] = None,
+) -> Dict[str, Any]:
+    """
+    Build a TIN from a subset of scattered samples by greedy insertion.
+
+    Starting from the extreme samples, the sample with the largest vertical
+    error in every triangle is inserted, pass after pass, until no sample
+    deviates from the TIN by more than max_error: the adaptive DEM-to-TIN
+    algorithm of grid_to_tin() for point clouds such as lidar ground returns.
+    Samples sharing an (x, y) position keep the first height.
+
+    Args:
+        points_xyz: Samples as an (n, 3) NumPy array or list of (x, y, z) tuples
+        max_error: Maximum allowed vertical deviation at any sample
+        max_vertices: Stop once the TIN has this many vertices
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates, all of them samples
+            - 'normals': Area-weighted unit vertex normals
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'indices': Index into points_xyz of each vertex
+            - 'max_error': Largest remaining vertical error
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.adaptive_tin(ground_points, max_error=0.1)
+        >>> print(f"kept {len(result['vertices'])} of {len(ground_points)} points")
+    """
+    ...
+
+def simplify_tin(mesh: Dict[str, Any], max_error: float) -> Dict[str, Any]:
+    """
+    Simplify a 2.5D mesh to a vertical error tolerance.
+
+    Vertices are dropped as long as the surface stays within max_error of
+    every input vertex, for publishing lightweight terrain tiles. Boundary
+    edges and the constrained 'edges' are kept as they are. The result is
+    built by greedy insertion from the vertices of those edges, adding the
+    worst-fitting input vertex of every triangle until all are within the
+    tolerance.
+
+    Args:
+        mesh: Mesh with (x, y, z) 'vertices', 'triangles' and optionally 'edges'
+        max_error: Maximum allowed vertical deviation at any input vertex
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates, all of them input vertices
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edges (boundary and input 'edges')
+            - 'indices': Input index of each vertex
+            - 'max_error': Largest vertical error at an input vertex
+
+    Example:
+        >>> import pyspade
+        >>> terrain = pyspade.tin(points_xyz, boundary=tile)
+        >>> light = pyspade.simplify_tin(terrain, max_error=0.25)
+    """
+    ...
+
+def lods(
+    mesh: Dict[str, Any],
+    max_errors: Optional[List[float]] = None,
+    target_counts: Optional[List[int]] = None,
+) -> List[Dict[str, Any]]:
+    """
+    Generate levels of detail of a 2.5D mesh with shared vertex numbering.
+
+    Levels are simplified as in simplify_tin(), by one greedy insertion run
+    from the boundary and constrained edges that stops at each level in turn.
+    Every level therefore uses the first vertices of the next finer one,
+    numbered the same, so a client streaming terrain at several zoom levels
+    only adds vertices when it zooms in.
+
+    Args:
+        mesh: Mesh with (x, y, z) 'vertices', 'triangles' and optionally 'edges'
+        max_errors: Vertical error tolerance of each level
+        target_counts: Vertex count of each level, instead of max_errors
+
+    Returns:
+        One mesh dict per level, in the order given, with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates, the first vertices
+              of every finer level
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edges (boundary and input 'edges')
+            - 'indices': Input index of each vertex
+            - 'max_error': Largest vertical error at an input vertex
+
+    Example:
+        >>> import pyspade
+        >>> levels = pyspade.lods(terrain, max_errors=[4.0, 1.0, 0.25])
+        >>> print([len(level['triangles']) for level in levels])
+    """
+    ...
+
+def rasterize(
+    mesh: Dict[str, Any],
+    resolution: Optional[float] = None,
+    shape: Optional[Tuple[int, int]] = None,
+    bounds: Optional[Tuple[float, float, float, float]] = None,
+    method: str = "linear",
+    outside: str = "nan",
+    fill_value: Optional[float] = None,
+) -> Dict[str, Any]:
+    """
+    Sample a triangulated surface onto a regular grid.
+
+    Exactly one of resolution and shape must be given. Cells whose center is
+    not covered by any triangle get a value chosen by outside.
+
+    Args:
+        mesh: Mesh with 'vertices' ((x, y, z) tuples) and 'triangles', e.g. from tin()
+        resolution: Cell size in x/y units
+        shape: Grid size as (rows, cols)
+        bounds: Grid extent as (xmin, ymin, xmax, ymax). Defaults to the mesh bounding box
+        method: "linear" (barycentric) or "nearest" (closest triangle corner)
+        outside: Value of cells outside the mesh: "nan", "nearest" (the z of the
+            closest mesh vertex) or "constant" (fill_value)
+        fill_value: Value of cells outside the mesh for outside="constant"
+
+    Returns:
+        Dictionary with keys:
+            - 'array': (rows, cols) float64 NumPy array, rows from top (ymax) to bottom (ymin)
+            - 'transform': GDAL-style geotransform (xmin, cell_width, 0, ymax, 0, -cell_height)
+
+    Example:
+        >>> import numpy as np
+        >>> import pyspade
+        >>> mesh = pyspade.tin([(0, 0, 0.0), (10, 0, 1.0), (10, 10, 2.0), (0, 10, 1.0)])
+        >>> grid = pyspade.rasterize(mesh, resolution=1.0)
+        >>> dem = grid['array']
+        >>> print(dem.shape, np.nanmax(dem))
+    """
+    ...
+
+def clip(
+    mesh: Dict[str, Any],
+    polygon: List[Tuple[float, float]],
+) -> Dict[str, Any]:
+    """
+    Cut a mesh along a polygon boundary.
+
+    Triangles crossed by the polygon are split along it and re-triangulated;
+    all other triangles are kept unchanged. z values of new vertices are
+    interpolated linearly from the triangle they fall in.
+
+    Args:
+        mesh: Mesh with 'vertices' ((x, y) or (x, y, z) tuples) and 'triangles'
+        polygon: Clip polygon as list of (x, y) tuples
+
+    Returns:
+        Dictionary with keys:
+            - 'inside': Mesh dict ('vertices', 'triangles') of the part inside the polygon
+            - 'outside': Mesh dict of the remaining part
+            - 'inside_parents': Index of the input triangle each inside triangle came from
+            - 'outside_parents': Same for the outside triangles
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (100, 0), (100, 100), (0, 100)], max_edge_length=5.0)
+        >>> parts = pyspade.clip(mesh, [(0, 0), (50, 0), (50, 50), (0, 50)])
+        >>> tile = parts['inside']
+    """
+    ...
+
+def merge(
+    meshes: List[Dict[str, Any]],
+    tolerance: float = 1e-6,
+) -> Dict[str, Any]:
+    """
+    Stitch adjacent meshes (e.g. tiles) into a single mesh.
+
+    Boundary vertices of the input meshes that lie within tolerance of each
+    other are welded. Where neighboring tiles discretize a shared boundary
+    differently, the triangles along it are split at the other tile's boundary
+    vertices so the result has no T-junctions.
+
+    Args:
+        meshes: Mesh dicts with 'vertices' and 'triangles'
+        tolerance: Weld distance
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'sources': Index of the input mesh each triangle came from
+            - 'unmatched': (i, j) boundary edges that run along another mesh's
+              boundary but could not be stitched to it
+
+    Example:
+        >>> import pyspade
+        >>> left = pyspade.triangulate([(0, 0), (50, 0), (50, 100), (0, 100)], max_edge_length=5.0)
+        >>> right = pyspade.triangulate([(50, 0), (100, 0), (100, 100), (50, 100)], max_edge_length=8.0)
+        >>> merged = pyspade.merge([left, right], tolerance=1e-6)
+        >>> assert not merged['unmatched']
+    """
+    ...
+
+def half_edges(mesh: Dict[str, Any]) -> Dict[str, List[int]]:
+    """
+    Half-edge (DCEL) connectivity of a triangle mesh.
+
+    Triangle t owns the half-edges 3t, 3t + 1 and 3t + 2, running from its
+    first to second, second to third and third to first vertex. Boundary
+    half-edges have no twin and get -1.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+
+    Returns:
+        Dictionary with keys:
+            - 'origin': Start vertex of each half-edge
+            - 'twin': Opposite half-edge, or -1 on the boundary
+            - 'next': Next half-edge around the same triangle
+            - 'face': Triangle of each half-edge
+            - 'vertex_half_edge': One outgoing half-edge per vertex (a boundary one where
+              possible), or -1 for unused vertices
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)])
+        >>> he = pyspade.half_edges(mesh)
+        >>> boundary = [h for h, t in enumerate(he['twin']) if t == -1]
+    """
+    ...
+
+def vertex_adjacency(mesh: Dict[str, Any]) -> Dict[str, Any]:
+    """
+    Vertex-to-vertex adjacency of a mesh in compressed sparse row form.
+
+    The neighbors of vertex v are indices[offsets[v]:offsets[v + 1]], sorted
+    ascending.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+
+    Returns:
+        Dictionary with keys:
+            - 'offsets': len(vertices) + 1 row offsets as an int64 NumPy array
+            - 'indices': Concatenated neighbor lists as an int64 NumPy array
+
+    Example:
+        >>> import numpy as np
+        >>> import scipy.sparse as sp
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], max_edge_length=2.0)
+        >>> adj = pyspade.vertex_adjacency(mesh)
+        >>> n = len(mesh['vertices'])
+        >>> A = sp.csr_matrix((np.ones(len(adj['indices'])), adj['indices'], adj['offsets']), shape=(n, n))
+    """
+    ...
+
+def to_csr(mesh: Dict[str, Any], weighted: bool = False) -> Dict[str, Any]:
+    """
+    Vertex connectivity graph of a mesh as compressed sparse row arrays.
+
+    The arrays match SciPy's csr_matrix((data, indices, indptr), shape)
+    constructor, so graph algorithms (shortest paths, spectral methods) run on
+    the mesh without edge loops in Python. Every edge appears in both
+    directions, with neighbors sorted ascending.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+        weighted: If True, each entry is the edge's length (in 3D for (x, y, z)
+            vertices); otherwise 1.0
+
+    Returns:
+        Dictionary with keys:
+            - 'data': Entry values as a float64 NumPy array
+            - 'indices': Column (neighbor) index of each entry as an int64 NumPy array
+            - 'indptr': len(vertices) + 1 row offsets as an int64 NumPy array
+            - 'shape': (len(vertices), len(vertices))
+
+    Example:
+        >>> import pyspade
+        >>> from scipy.sparse import csr_matrix
+        >>> from scipy.sparse.csgraph import dijkstra
+        >>> csr = pyspade.to_csr(mesh, weighted=True)
+        >>> graph = csr_matrix((csr['data'], csr['indices'], csr['indptr']), shape=csr['shape'])
+        >>> distances = dijkstra(graph, indices=0)
+    """
+    ...
+
+def dual_graph(mesh: Dict[str, Any], weighted: bool = False) -> Dict[str, List]:
+    """
+    Dual graph of a mesh: triangles joined where they share an edge.
+
+    Used for region growing and segmentation over triangles. Each pair is
+    listed once as (s, t) with s < t, sorted; an edge shared by more than two
+    triangles joins every pair of them.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+        weighted: If True, each weight is the length of the shared edge (in 3D for
+            (x, y, z) vertices); otherwise 1.0
+
+    Returns:
+        Dictionary with keys:
+            - 'edges': List of (s, t) triangle index pairs
+            - 'weights': Weight of each pair
+
+    Example:
+        >>> import pyspade
+        >>> dual = pyspade.dual_graph(mesh, weighted=True)
+        >>> for (s, t), length in zip(dual['edges'], dual['weights']):
+        ...     print(s, t, length)
+    """
+    ...
+
+def boundary_levels(mesh: Dict[str, Any], max_level: Optional[int] = None) -> List[int]:
+    """
+    Hop distance of every triangle from the boundary of the mesh.
+
+    Triangles with an edge on the boundary (an edge no other triangle shares)
+    are level 0; every other triangle is one more than its lowest neighbor
+    across an edge. Schemes that stabilize or damp near the boundary can pick
+    their layers from these levels.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+        max_level: Stop counting here; deeper triangles get this level
+
+    Returns:
+        Level of each triangle, or -1 where no boundary can be reached (closed
+        surfaces)
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], max_edge_length=1.0)
+        >>> levels = pyspade.boundary_levels(mesh, max_level=2)
+        >>> interior = [t for t, level in enumerate(levels) if level == 2]
+    """
+    ...
+
+def connected_components(mesh: Dict[str, Any], through_vertices: bool = False) -> Dict[str, Any]:
+    """
+    Label the connected components of a mesh.
+
+    Excluding holes or meshing several polygons at once can leave a mesh in
+    pieces. Components are numbered in order of their first triangle.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+        through_vertices: If True, triangles sharing only a vertex are connected
+            too; otherwise they must share an edge
+
+    Returns:
+        Dictionary with keys:
+            - 'labels': Component of each triangle
+            - 'count': Number of components
+            - 'sizes': Number of triangles in each component
+
+    Example:
+        >>> import pyspade
+        >>> components = pyspade.connected_components(mesh)
+        >>> for c in range(components['count']):
+        ...     part = [t for t, label in zip(mesh['triangles'], components['labels']) if label == c]
+    """
+    ...
+
+def partition(mesh: Dict[str, Any], n_parts: int) -> Dict[str, Any]:
+    """
+    Split a mesh into parts of balanced area by recursive coordinate bisection.
+
+    The triangles' centroids are split across the longer side of their
+    bounding box at the position that divides the area in proportion to the
+    parts on either side, and each half is split again until n_parts remain.
+    Parts are compact blocks, which keeps the edge cut small without a graph
+    partitioner such as METIS.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+        n_parts: Number of parts
+
+    Returns:
+        Dictionary with keys:
+            - 'parts': Part of each triangle, from 0 to n_parts - 1
+            - 'areas': Total triangle area of each part
+            - 'edge_cut': Number of edges shared by triangles of different parts
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.partition(mesh, n_parts=8)
+        >>> mine = [t for t, part in zip(mesh['triangles'], result['parts']) if part == rank]
+    """
+    ...
+
+def halo(
+    mesh: Dict[str, Any],
+    triangles: List[int],
+    layers: int = 1,
+    through_vertices: bool = True,
+) -> Dict[str, Any]:
+    """
+    Extract a subset of triangles together with layers of neighboring halo
+    (ghost) triangles, as a standalone local mesh.
+
+    Local triangles are the subset in the given order, followed by the first
+    halo layer, then the second, and so on; local vertices are numbered in
+    order of first use. The index maps translate local results back to the
+    global mesh, which is what overlapping-domain computations exchange.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles', e.g. from triangulate()
+        triangles: Global indices of the triangles in the subset, e.g. those of one
+            part from partition()
+        layers: Number of halo layers to add
+        through_vertices: If True, a triangle sharing only a vertex with the previous
+            layer joins the halo; otherwise it must share an edge
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': Local vertices
+            - 'triangles': Local (i, j, k) triangles
+            - 'triangle_map': Global index of each local triangle
+            - 'vertex_map': Global index of each local vertex
+            - 'layers': Layer of each local triangle, 0 for the subset
+            - 'num_owned': Number of subset triangles at the start of 'triangles'
+
+    Example:
+        >>> import pyspade
+        >>> parts = pyspade.partition(mesh, n_parts=size)['parts']
+        >>> mine = [t for t, part in enumerate(parts) if part == rank]
+        >>> local = pyspade.halo(mesh, mine, layers=2)
+    """
+    ...
+
+def optimize_vertex_cache(
+    mesh: Dict[str, Any],
+    cache_size: int = 32,
+) -> Dict[str, Any]:
+    """
+    Reorder a mesh for GPU vertex cache locality.
+
+    Triangles are reordered with Forsyth's linear-speed vertex cache
+    optimization, then vertices are renumbered in order of first use so the
+    vertex buffer is fetched sequentially as well. The geometry is unchanged.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles'
+        cache_size: Simulated vertex cache size
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': Reordered vertex coordinates
+            - 'triangles': Reordered triangle vertex indices (same winding as the input)
+            - 'vertex_map': Input index of each output vertex
+            - 'acmr': (before, after) average cache misses per triangle
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (100, 0), (100, 100), (0, 100)], max_edge_length=1.0)
+        >>> fast = pyspade.optimize_vertex_cache(mesh)
+        >>> print(fast['acmr'])
+    """
+    ...
+
+def planar_uvs(
+    mesh: Dict[str, Any],
+    offset: Optional[Tuple[float, float]] = None,
+    scale: Optional[Tuple[float, float]] = None,
+    bounds: Optional[Tuple[float, float, float, float]] = None,
+) -> List[Tuple[float, float]]:
+    """
+    Planar texture coordinates for a mesh.
+
+    With scale, u = (x - offset_x) * scale_x and v = (y - offset_y) * scale_y.
+    Otherwise x/y are normalized to [0, 1] over bounds, e.g. the extent of an
+    orthophoto, so the image maps directly onto the mesh.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles'
+        offset: (x, y) origin of the mapping when scale is given. Defaults to (0, 0)
+        scale: (sx, sy) UV units per x/y unit
+        bounds: (xmin, ymin, xmax, ymax) mapped to the unit square. Defaults to the
+            mesh bounding box
+
+    Returns:
+        (u, v) per vertex
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.tin(points_xyz)
+        >>> uvs = pyspade.planar_uvs(mesh, bounds=(xmin, ymin, xmax, ymax))
+    """
+    ...
+
+def vertex_normals(mesh: Dict[str, Any]) -> List[Tuple[float, float, float]]:
+    """
+    Area-weighted per-vertex normals of a 2.5D mesh.
+
+    Every triangle contributes its unnormalized face normal (whose length is
+    twice its area) to its three corners. Normals point up (+z) for
+    counter-clockwise triangles; unused vertices get (0, 0, 1).
+
+    Args:
+        mesh: Mesh with (x, y, z) 'vertices' and 'triangles'
+
+    Returns:
+        Unit (nx, ny, nz) normal per vertex
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.grid_to_tin(dem, transform, max_error=0.1)
+        >>> normals = pyspade.vertex_normals(mesh)
+    """
+    ...
+
+def vertex_gradients(
+    mesh: Dict[str, Any],
+    values: Optional[List[float]] = None,
+    method: str = "area",
+) -> List[Tuple[float, float]]:
+    """
+    Gradient of a piecewise linear scalar field at every vertex.
+
+    With method="area" each vertex gets the average of the (constant)
+    gradients of its triangles, weighted by triangle area. With
+    method="least_squares" a plane through the vertex value is fitted to the
+    values of its edge neighbors, which is exact for linear fields on any
+    mesh; vertices whose neighbors do not span the plane fall back to the area
+    average. Vertices used by no triangle get (NaN, NaN).
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles'
+        values: One value per vertex. Default: the z coordinates
+        method: "area" or "least_squares"
+
+    Returns:
+        (df/dx, df/dy) per vertex
+
+    Example:
+        >>> import numpy as np
+        >>> import pyspade
+        >>> terrain = pyspade.tin(points_xyz)
+        >>> gradients = np.array(pyspade.vertex_gradients(terrain))
+        >>> slope_deg = np.degrees(np.arctan(np.hypot(gradients[:, 0], gradients[:, 1])))
+    """
+    ...
+
+def vertex_curvature(mesh: Dict[str, Any]) -> Dict[str, List[float]]:
+    """
+    Mean and Gaussian curvature at every vertex of a 2.5D mesh.
+
+    Uses the discrete operators over each vertex's 1-ring: Gaussian curvature
+    is the angle defect (2π minus the corner angles) and mean curvature half
+    the cotangent Laplacian of the position along the vertex normal, both per
+    unit of the vertex's area (a third of its triangles' area). Mean curvature
+    is positive on ridges and hilltops and negative in valleys and pits for
+    counter-clockwise triangles. Sharp breaklines show up as large |mean|.
+    Boundary vertices and vertices used by no triangle get NaN, as their
+    1-ring is incomplete.
+
+    Args:
+        mesh: Mesh with (x, y, z) 'vertices' and 'triangles'
+
+    Returns:
+        Dictionary with keys:
+            - 'mean': Mean curvature per vertex (1/length)
+            - 'gaussian': Gaussian curvature per vertex (1/length²)
+
+    Example:
+        >>> import numpy as np
+        >>> import pyspade
+        >>> terrain = pyspade.tin(points_xyz)
+        >>> curvature = pyspade.vertex_curvature(terrain)
+        >>> breaklines = np.abs(curvature['mean']) > 0.5
+    """
+    ...
+
+def flow_accumulation(mesh: Dict[str, Any]) -> Dict[str, List]:
+    """
+    Steepest-descent flow routing and flow accumulation over the triangles of
+    a terrain mesh.
+
+    Every triangle drains along its steepest descent, the negative gradient of
+    its plane. Its receiver is the neighbor across the edge that this
+    direction leaves through from the centroid, if that neighbor's centroid is
+    lower; otherwise the neighbor with the lowest centroid below its own.
+    Triangles with no lower neighbor are pits, and triangles draining across
+    the mesh boundary are outlets; both have no receiver. As every step goes
+    strictly downhill, flow paths never loop.
+
+    Accumulation is the planimetric area draining through each triangle,
+    including its own, and triangles with the same outlet form a watershed.
+
+    Args:
+        mesh: Terrain mesh with (x, y, z) 'vertices' and 'triangles'
+
+    Returns:
+        Dictionary with keys:
+            - 'directions': Unit (dx, dy) steepest-descent direction per triangle ((0, 0) for
+              flat triangles)
+            - 'receivers': Triangle each triangle drains into, or -1 for pits and outlets
+            - 'accumulation': Upstream area per triangle, its own area included
+            - 'outlets': Pit or outlet triangle that each triangle's flow path ends in,
+              labeling the watersheds
+
+    Example:
+        >>> import numpy as np
+        >>> import pyspade
+        >>> terrain = pyspade.tin(points_xyz)
+        >>> flow = pyspade.flow_accumulation(terrain)
+        >>> streams = np.array(flow['accumulation']) > 10000.0
+    """
+    ...
+
+def filled_contours(mesh: Dict[str, Any], levels: List[float]) -> Dict[str, List]:
+    """
+    Filled contour polygons of the terrain between consecutive levels.
+
+    Band `i` is the region where `levels[i] <= z < levels[i + 1]`, traced
+    exactly on the piecewise linear surface and returned as polygons with
+    holes. Exterior rings are counter-clockwise and holes clockwise, as in
+    GeoJSON. Pass -inf and inf as the first and last level to cover the
+    whole mesh.
+
+    Args:
+        mesh: Terrain mesh with (x, y, z) 'vertices' and 'triangles'
+        levels: Increasing contour levels
+
+    Returns:
+        Dictionary with keys:
+            - 'bands': (lower, upper) levels of each band
+            - 'polygons': Per band, a list of polygons, each a list of rings (exterior first,
+              then holes) of (x, y) tuples, without repeating the first point
+
+    Example:
+        >>> import pyspade
+        >>> terrain = pyspade.tin(points_xyz)
+        >>> result = pyspade.filled_contours(terrain, levels=[0.0, 1.0, 2.0, 5.0])
+        >>> flooded = result['polygons'][0]
+    """
+    ...
+
+def isobands(mesh: Dict[str, Any], values: List[float], breaks: List[float]) -> Dict[str, List]:
+    """
+    Band polygons of a per-vertex scalar field between consecutive breaks.
+
+    Works like `filled_contours()` on any field given at the mesh vertices,
+    such as noise levels or pollutant concentrations from a simulation on the
+    mesh, and returns a flat list of polygons labeled with their band, ready
+    to be written as GIS features. Band `i` is where
+    `breaks[i] <= value < breaks[i + 1]`; vertices with NaN values are in no
+    band.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles'
+        values: One value per vertex
+        breaks: Increasing band limits
+
+    Returns:
+        Dictionary with keys:
+            - 'polygons': List of polygons, each a list of rings (exterior first, then holes)
+              of (x, y) tuples, without repeating the first point
+            - 'labels': Band index of each polygon
+            - 'bands': (lower, upper) limits of each band
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.isobands(mesh, noise_db, breaks=[45.0, 50.0, 55.0, 60.0, 65.0])
+        >>> loud = [p for p, label in zip(result['polygons'], result['labels']) if label >= 2]
+    """
+    ...
+
+def orient_facets(mesh: Dict[str, Any], up: bool = True) -> Dict[str, Any]:
+    """
+    Orient every facet of a 2.5D mesh to face up (or down), for export.
+
+    STL, OBJ and glTF viewers derive a facet's front side from its winding,
+    so a mesh assembled from several sources can show inside-out patches.
+    Triangles whose normal points the wrong way in z have their last two
+    indices swapped; vertical and degenerate triangles are left as they are.
+
+    Args:
+        mesh: Mesh with (x, y, z) 'vertices' and 'triangles'
+        up: If True, normals point to +z (counter-clockwise seen from above);
+            if False, to -z
+
+    Returns:
+        The mesh with keys:
+            - 'vertices': Vertex coordinates, unchanged
+            - 'triangles': Triangles, consistently oriented
+            - 'edges': Constrained edges, unchanged (only if the input has any)
+            - 'flipped': Indices of the triangles that were flipped
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.orient_facets(pyspade.tin(points_xyz))
+        >>> normals = pyspade.vertex_normals(mesh)
+    """
+    ...
+
+def hillshade(
+    mesh: Dict[str, Any],
+    azimuth: float = 315.0,
+    altitude: float = 45.0,
+    z_factor: float = 1.0,
+    per_vertex: bool = False,
+) -> List[float]:
+    """
+    Hillshade of a terrain mesh lit by the sun, for quick-look shaded relief.
+
+    The illumination is the cosine of the angle between the surface normal
+    and the direction to the sun, clamped at 0 for slopes facing away, as in
+    GIS hillshade tools but without rasterizing first. Normals are taken
+    facing up whatever the triangle winding. Per vertex, the area-weighted
+    vertex normals give smooth shading when the values are interpolated
+    across the triangles. Degenerate triangles and unused vertices get NaN.
+
+    Args:
+        mesh: Terrain mesh with (x, y, z) 'vertices' and 'triangles'
+        azimuth: Direction to the sun in degrees, clockwise from north
+            (+y). Default: 315.0 (northwest)
+        altitude: Angle of the sun above the horizon in degrees.
+            Default: 45.0
+        z_factor: Vertical exaggeration applied to z. Default: 1.0
+        per_vertex: If True, return one value per vertex instead of one
+            per triangle. Default: False
+
+    Returns:
+        Illumination in [0, 1] per triangle (or per vertex)
+
+    Example:
+        >>> import pyspade
+        >>> terrain = pyspade.tin(points_xyz)
+        >>> shade = pyspade.hillshade(terrain, azimuth=315.0, altitude=45.0, z_factor=2.0)
+        >>> gray = [int(255 * s) for s in shade]
+    """
+    ...
+
+def triangulate_anisotropic(
+    outer: List[Tuple[float, float]],
+    holes: Optional[List[List[Tuple[float, float]]]] = None,
+    *,
+    max_edge_length: float,
+    directions: List[Tuple[float, float, float, float]],
+) -> Dict[str, List]:
+    """
+    Triangulate a polygon with elongated triangles aligned to a direction field.
+
+    The direction field is given as samples (x, y, angle, ratio): around each
+    sample (its Voronoi cell among the samples) triangles are stretched along
+    angle (degrees, counter-clockwise from the x axis) with edges of about
+    max_edge_length along that direction and max_edge_length / ratio across
+    it. Boundaries are split at the across spacing. A single sample gives one
+    global anisotropy.
+
+    The interior points are placed on stretched lattices and triangulated
+    without quality refinement, which would undo the stretching; where the
+    cells of two samples meet the transition is abrupt.
+
+    Args:
+        outer: Exterior boundary vertices as list of (x, y) tuples
+        holes: List of hole polygons, each as list of (x, y) tuples
+        max_edge_length: Edge length along the preferred direction
+        directions: Direction samples as (x, y, angle_deg, ratio) tuples, ratio >= 1
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edge indices
+
+    Example:
+        >>> import pyspade
+        >>> # A road corridor running along x, resolved 5x finer across than along
+        >>> result = pyspade.triangulate_anisotropic(
+        ...     outer=[(0, 0), (200, 0), (200, 20), (0, 20)],
+        ...     max_edge_length=10.0,
+        ...     directions=[(100, 10, 0.0, 5.0)]
+        ... )
+    """
+    ...
+
+def quadrangulate(
+    mesh: Dict[str, Any],
+    min_quality: float = 0.5,
+) -> Dict[str, List]:
+    """
+    Convert a triangle mesh into a quad-dominant mesh.
+
+    Pairs of adjacent triangles whose union is a convex quad with all corner
+    angles within 90° ± (1 - min_quality) * 90° are merged. Candidate pairs are
+    matched greedily from the best quad down, so every triangle ends up in at
+    most one quad; unmatched triangles are returned as they are.
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles'
+        min_quality: Minimum quad quality in [0, 1], where 1 is a rectangle and 0
+            allows any convex quad. The default 0.5 keeps corners within 45-135°
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': The input vertices
+            - 'quads': List of (i, j, k, l) counter-clockwise quads
+            - 'triangles': Remaining (i, j, k) triangles
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], max_edge_length=1.0)
+        >>> mixed = pyspade.quadrangulate(mesh, min_quality=0.6)
+        >>> print(len(mixed['quads']), len(mixed['triangles']))
+    """
+    ...
+
+def quadratic_elements(mesh: Dict[str, Any]) -> Dict[str, Any]:
+    """
+    Convert a mesh of 3-node triangles into 6-node quadratic (P2) triangles.
+
+    A node is added at the midpoint of every edge, shared by the triangles on
+    either side. Nodes are numbered after the corner vertices, in the order
+    their edges are first met walking the triangles. Each element lists its
+    corners and then the midpoints of edges (0, 1), (1, 2) and (2, 0), the
+    ordering used by Gmsh and VTK.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges', e.g. from
+            triangulate()
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': Corner vertices followed by the midpoint nodes
+            - 'triangles': List of (a, b, c, ab, bc, ca) node indices
+            - 'edges': The mesh's constrained edges as (i, j, midpoint) 3-node edges, if it has any
+            - 'mesh_edges': Every edge of the mesh as an (i, j) pair with i < j, in node order
+            - 'midpoints': Midpoint node of each edge in 'mesh_edges'
+            - 'num_corners': Number of corner vertices; nodes from this index on are midpoints
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (1, 0), (1, 1), (0, 1)], max_edge_length=0.2)
+        >>> p2 = pyspade.quadratic_elements(mesh)
+        >>> print(len(p2['vertices']) - p2['num_corners'], "midpoint nodes")
+    """
+    ...
+
+def remesh(
+    mesh: Dict[str, Any],
+    target_length: float,
+    iterations: int = 5,
+    frozen: Optional[Union[List[int], List[bool]]] = None,
+) -> Dict[str, List]:
+    """
+    Remesh to near-equilateral triangles of a uniform edge length.
+
+    Boundary edges and the mesh's constrained 'edges' (if present) are kept as
+    features: they are resampled to target_length but their geometry never
+    changes. Each iteration splits interior edges longer than 4/3 of the target,
+    collapses edges shorter than 4/5 of it, restores the Delaunay property by
+    flips and moves every free vertex to the average of its neighbors. z values
+    are interpolated from the input mesh.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges'
+        target_length: Desired edge length
+        iterations: Number of split/collapse/flip/smooth cycles
+        frozen: Vertices kept in place like the features, as a list of vertex
+            indices or a boolean mask with one entry per vertex
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edges (resampled features)
+
+    Example:
+        >>> import pyspade
+        >>> raw = pyspade.triangulate(outer, holes, max_edge_length=2.0, min_angle=20.0)
+        >>> smooth = pyspade.remesh(raw, target_length=2.0, iterations=10)
+    """
+    ...
+
+def smooth(
+    mesh: Dict[str, Any],
+    iterations: int = 10,
+    frozen: Optional[Union[List[int], List[bool]]] = None,
+    min_angle: Optional[float] = None,
+    tolerance: Optional[float] = None,
+    method: str = "laplacian",
+) -> Dict[str, Any]:
+    """
+    Smooth a mesh by moving every free vertex to the average of its neighbors,
+    or by optimal Delaunay triangulation (ODT) updates.
+
+    The "odt" method moves a vertex to the area-weighted average of the
+    circumcenters of its triangles, which tends to give better-conditioned
+    triangles for PDE solvers than plain Laplacian averaging. Boundary
+    vertices and the end points of the mesh's constrained 'edges' are pinned,
+    as are the frozen vertices (surveyed control points, sensor nodes). The
+    connectivity is kept: a move that would invert or flatten an incident
+    triangle is halved once and otherwise skipped. z values are interpolated
+    from the input mesh at the new positions.
+
+    Sweeps stop early once the smallest angle reaches min_angle or no vertex
+    moves more than tolerance in a sweep, so iterations is an upper bound when
+    either target is given.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges', e.g. from
+            triangulate()
+        iterations: Number of smoothing sweeps, or the most to run when a target
+            is given
+        frozen: Vertices that must not move, as a list of vertex indices or a
+            boolean mask with one entry per vertex
+        min_angle: Stop once no triangle angle is below this many degrees
+        tolerance: Stop once a sweep moves no vertex farther than this
+        method: "laplacian" (average of the neighbors) or "odt" (area-weighted
+            average of the incident circumcenters)
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates, in input order
+            - 'triangles': The input triangles
+            - 'edges': The input constrained edges (if any)
+            - 'iterations': Number of sweeps run
+            - 'min_angle': Smallest triangle angle in degrees after smoothing
+            - 'max_displacement': Largest distance a vertex moved in the last sweep
+              (0.0 if none ran)
+            - 'converged': True if a given target was met (always False without
+              targets)
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate(outer, max_edge_length=1.0)
+        >>> smoothed = pyspade.smooth(mesh, iterations=100, min_angle=30.0, tolerance=1e-3, method="odt")
+        >>> print(smoothed['iterations'], smoothed['min_angle'], smoothed['converged'])
+    """
+    ...
+
+def refine_adaptive(
+    mesh: Dict[str, Any],
+    error_fn: Union[Callable[[List[Tuple[float, float]]], Any], List[float]],
+    max_error: float,
+    max_iterations: int = 10,
+) -> Dict[str, Any]:
+    """
+    Refine a mesh where an error estimate exceeds a threshold, round by round.
+
+    Each round, every triangle whose error is above max_error has its three
+    edges split at their midpoints and the mesh is made Delaunay again, so
+    flagged areas get about four times as many triangles while the rest of the
+    mesh stays as it is. Boundary edges and the constrained 'edges' are kept
+    (split where refined). z values are interpolated from the input mesh.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges'
+        error_fn: Function called once per round with the list of (x, y) triangle
+            centroids, returning one error per triangle (e.g. from
+            np.asarray(centroids)); or a fixed list of errors for the input
+            triangles, which drives a single round
+        max_error: Triangles with a larger error are split
+        max_iterations: Maximum number of refinement rounds
+
+    Returns:
+        Dictionary with keys:
+            - 'vertices': List of (x, y, z) vertex coordinates
+            - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+            - 'edges': List of (i, j) constrained edges (boundary and input 'edges')
+            - 'iterations': Number of rounds that split triangles
+            - 'max_error': Largest error of the last estimate (of the input
+              triangles for a fixed list)
+            - 'converged': True if the last estimate had no error above max_error
+
+    Example:
+        >>> import numpy as np
+        >>> import pyspade
+        >>> def error(centroids):
+        ...     xy = np.asarray(centroids)
+        ...     return np.exp(-((xy - 50.0) ** 2).sum(axis=1) / 100.0)
+        >>> mesh = pyspade.triangulate(outer, max_edge_length=10.0)
+        >>> result = pyspade.refine_adaptive(mesh, error, max_error=0.1)
+    """
+    ...
+
+def split_edge(
+    mesh: Dict[str, Any],
+    edge: Tuple[int, int],
+    t: float = 0.5,
+) -> Dict[str, Any]:
+    """
+    Split an edge at a point along it.
+
+    A new vertex is placed at (1 - t) * edge[0] + t * edge[1] (z interpolated
+    too) and each triangle on the edge is split in two. A constrained edge is
+    replaced by its two constrained halves.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges'
+        edge: (i, j) vertex indices of an edge of the mesh
+        t: Position of the new vertex along the edge, in (0, 1)
+
+    Returns:
+        The edited mesh ('vertices', 'triangles', 'edges' if any) plus 'vertex',
+        the index of the new vertex (always the last one)
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)])
+        >>> mesh = pyspade.split_edge(mesh, mesh['triangles'][0][:2])
+        >>> print(mesh['vertex'])
+    """
+    ...
+
+def collapse_edge(
+    mesh: Dict[str, Any],
+    edge: Tuple[int, int],
+) -> Dict[str, Any]:
+    """
+    Collapse an edge by merging one endpoint into the other.
+
+    The removed endpoint must be free: not on the mesh boundary and not on a
+    constrained edge. edge[1] is removed if it is free, otherwise edge[0]. The
+    collapse is refused if it would invert or flatten a triangle or make the
+    mesh non-manifold. Vertex indices above the removed one shift down by one.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges'
+        edge: (i, j) vertex indices of an edge of the mesh
+
+    Returns:
+        The edited mesh ('vertices', 'triangles', 'edges' if any) plus
+        'removed', the index of the removed vertex in the input mesh
+
+    Example:
+        >>> import pyspade
+        >>> fan = {'vertices': [(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)],
+        ...        'triangles': [(0, 1, 4), (1, 2, 4), (2, 3, 4), (3, 0, 4)]}
+        >>> print(pyspade.collapse_edge(fan, (0, 4))['triangles'])
+    """
+    ...
+
+def flip_edge(
+    mesh: Dict[str, Any],
+    edge: Tuple[int, int],
+) -> Dict[str, Any]:
+    """
+    Flip an interior edge to the other diagonal of its two triangles.
+
+    The flip is refused for boundary and constrained edges and when the two
+    triangles do not form a strictly convex quad.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and optionally 'edges'
+        edge: (i, j) vertex indices of an interior edge of the mesh
+
+    Returns:
+        The edited mesh ('vertices', 'triangles', 'edges' if any)
+
+    Example:
+        >>> import pyspade
+        >>> mesh = {'vertices': [(0, 0), (1, 0), (1, 1), (0, 1)], 'triangles': [(0, 1, 2), (0, 2, 3)]}
+        >>> print(pyspade.flip_edge(mesh, (0, 2))['triangles'])
+    """
+    ...
+
+def validate_mesh(
+    vertices: List[Tuple[float, ...]],
+    triangles: List[Tuple[int, int, int]],
+    tolerance: float = 0.0,
+) -> Dict[str, Any]:
+    """
+    Check a triangle mesh for common defects.
+
+    Unlike the functions taking a mesh dict, nothing is rejected up front:
+    triangles with out-of-range indices are reported and left out of the
+    remaining checks.
+
+    Args:
+        vertices: Vertex coordinates as (x, y) or (x, y, z) tuples
+        triangles: Triangles as (i, j, k) vertex indices
+        tolerance: Distance below which two vertices count as duplicates and a
+            triangle's height counts as zero. The default 0.0 is exact
+
+    Returns:
+        Dictionary with keys:
+            - 'valid': True if no defect was found
+            - 'invalid_triangles': Triangles with a negative or out-of-range index
+            - 'degenerate_triangles': Triangles with a repeated index or zero area
+            - 'flipped_triangles': Triangles whose winding (in the xy plane) is
+              opposite to that of the majority
+            - 'inconsistent_edges': (i, j) edges traversed in the same direction
+              by both of their triangles
+            - 'non_manifold_edges': (i, j) edges shared by more than two triangles
+            - 'duplicate_vertices': (i, j) pairs where vertex j repeats vertex i < j
+            - 'unreferenced_vertices': Vertices used by no triangle
+
+    Example:
+        >>> import pyspade
+        >>> report = pyspade.validate_mesh(mesh['vertices'], mesh['triangles'])
+        >>> if not report['valid']:
+        ...     print(report['non_manifold_edges'])
+    """
+    ...
+
+def mesh_statistics(
+    vertices: List[Tuple[float, ...]],
+    triangles: List[Tuple[int, int, int]],
+) -> Dict[str, Any]:
+    """
+    Compute size, topology and quality statistics of a triangle mesh.
+
+    Areas and lengths are measured in 3D, so they are surface measures for
+    terrain meshes and plain planar ones when z is 0.
+
+    Args:
+        vertices: Vertex coordinates as (x, y) or (x, y, z) tuples
+        triangles: Triangles as (i, j, k) vertex indices
+
+    Returns:
+        Dictionary with keys:
+            - 'num_vertices': Number of vertices used by triangles
+            - 'num_edges': Number of distinct edges
+            - 'num_triangles': Number of triangles
+            - 'area': Total triangle area
+            - 'boundary_length': Total length of edges used by one triangle
+            - 'boundary_loops': Number of closed boundary loops
+            - 'euler_characteristic': V - E + F over the used vertices
+            - 'components': Number of components connected through shared vertices
+            - 'min_angle', 'max_angle': Extreme triangle angles in degrees
+            - 'edge_length': (min, mean, max) edge length
+            - 'quality': (min, mean) radius ratio 2r/R, which is 1 for equilateral triangles
+
+    Example:
+        >>> import pyspade
+        >>> stats = pyspade.mesh_statistics(mesh['vertices'], mesh['triangles'])
+        >>> print(f"{stats['num_triangles']} triangles, min angle {stats['min_angle']:.1f}°")
+    """
+    ...
+
+def mesh_distance(
+    mesh_a: Dict[str, Any],
+    mesh_b: Dict[str, Any],
+    samples: int = 10000,
+    num_threads: Optional[int] = None,
+) -> Dict[str, Any]:
+    """
+    Symmetric Hausdorff and mean distance between two meshes.
+
+    Each mesh is sampled at its vertices plus about `samples` points spread
+    over its surface in proportion to triangle area, and the 3D distance from
+    every sample to the closest point on the other mesh is measured. The
+    samples follow a low-discrepancy sequence, so results are reproducible.
+
+    Args:
+        mesh_a: First mesh with 'vertices' and 'triangles'
+        mesh_b: Second mesh with 'vertices' and 'triangles'
+        samples: Number of surface samples per mesh
+        num_threads: Worker threads. Default: the PYSPADE_NUM_THREADS
+            environment variable, or all available cores
+
+    Returns:
+        Dictionary with keys:
+            - 'hausdorff': Largest sample distance in either direction
+            - 'mean': Mean sample distance over both directions
+            - 'a_to_b': (max, mean) distance from samples of mesh_a to mesh_b
+            - 'b_to_a': (max, mean) distance from samples of mesh_b to mesh_a
+
+    Example:
+        >>> import pyspade
+        >>> fine = pyspade.tin(points, max_edge_length=2.0)
+        >>> coarse = pyspade.tin(points, max_edge_length=10.0)
+        >>> d = pyspade.mesh_distance(fine, coarse)
+        >>> print(f"Hausdorff {d['hausdorff']:.3f}, mean {d['mean']:.3f}")
+    """
+    ...
+
+def sample_points(
+    mesh: Dict[str, Any],
+    n: int,
+    weighting: str = "area",
+    seed: Optional[int] = None,
+) -> Dict[str, Any]:
+    """
+    Draw random points on a mesh.
+
+    A triangle is picked with probability proportional to its area and a point
+    is drawn uniformly inside it (barycentric sampling), so points are
+    uniformly distributed: over the xy footprint with weighting="area", or over
+    the 3D surface with weighting="uniform_density" (which puts more points on
+    steep terrain).
+
+    Args:
+        mesh: Mesh with 'vertices' and 'triangles'
+        n: Number of points to draw
+        weighting: "area" (planar area) or "uniform_density" (surface area)
+        seed: Random seed for reproducible samples
+
+    Returns:
+        Dictionary with keys:
+            - 'points': (n, 3) float64 NumPy array of (x, y, z) sample points on the mesh
+            - 'triangles': int64 NumPy array with the index of the triangle each point lies in
+
+    Example:
+        >>> import pyspade
+        >>> ground = pyspade.tin(points_xyz)
+        >>> samples = pyspade.sample_points(ground, 1_000_000, seed=42)
+    """
+    ...
+
+def polygon_area(polygon: PointArray, signed: bool = False) -> float:
+    """
+    Area of a simple polygon.
+
+    Args:
+        polygon: Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+        signed: Return the signed area, positive for counter-clockwise rings
+
+    Returns:
+        The polygon area
+
+    Example:
+        >>> import pyspade
+        >>> pyspade.polygon_area([(0, 0), (4, 0), (4, 3), (0, 3)])
+        12.0
+    """
+    ...
+
+def polygon_centroid(polygon: PointArray) -> Tuple[float, float]:
+    """
+    Area centroid of a simple polygon (the vertex average for degenerate,
+    zero-area rings).
+
+    Args:
+        polygon: Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+
+    Returns:
+        (x, y) centroid, NaN for an empty ring
+
+    Example:
+        >>> import pyspade
+        >>> pyspade.polygon_centroid([(0, 0), (4, 0), (4, 2), (0, 2)])
+        (2.0, 1.0)
+    """
+    ...
+
+def polygon_is_ccw(polygon: PointArray) -> bool:
+    """
+    Whether a ring runs counter-clockwise (has positive signed area).
+
+    Args:
+        polygon: Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+
+    Returns:
+        True for counter-clockwise rings
+
+    Example:
+        >>> import pyspade
+        >>> pyspade.polygon_is_ccw([(0, 0), (0, 1), (1, 0)])
+        False
+    """
+    ...
+
+def polygon_contains_point(polygon: PointArray, point: Tuple[float, float]) -> bool:
+    """
+    Even-odd point-in-polygon test. Points exactly on the boundary may be
+    classified either way.
+
+    Args:
+        polygon: Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+        point: The (x, y) query point
+
+    Returns:
+        True if the point lies inside the ring
+
+    Example:
+        >>> import pyspade
+        >>> pyspade.polygon_contains_point([(0, 0), (4, 0), (4, 4), (0, 4)], (1, 2))
+        True
+    """
+    ...
+
+def points_in_polygon(
+    points: PointArray,
+    outer: PointArray,
+    holes: Optional[List[PointArray]] = None,
+    num_threads: Optional[int] = None,
+) -> List[bool]:
+    """
+    Classify many points against a polygon with holes in one call.
+
+    Uses the same even-odd rule as polygon_contains_point(): a point is inside
+    if it lies inside the outer ring and in none of the holes. Points exactly
+    on a ring may be classified either way.
+
+    Args:
+        points: Query points as an (n, 2) NumPy array or list of (x, y) tuples
+        outer: Exterior ring as an (m, 2) NumPy array or list of (x, y) tuples
+        holes: Hole rings, each as an array or list of (x, y) tuples
+        num_threads: Worker threads. Default: the PYSPADE_NUM_THREADS
+            environment variable, or all available cores
+
+    Returns:
+        One bool per point, True if the point lies inside the polygon
+
+    Example:
+        >>> import numpy as np
+        >>> import pyspade
+        >>> inside = np.array(pyspade.points_in_polygon(samples, outer, holes))
+        >>> samples = samples[inside]
+    """
+    ...
+
+def distance_to_constraints(
+    mesh: Dict[str, Any],
+    points: PointArray,
+    num_threads: Optional[int] = None,
+) -> Dict[str, List]:
+    """
+    Signed distance from query points to the nearest constrained edge.
+
+    Distances are negative for points inside the mesh and positive outside, so
+    abs(distance) < buffer selects a buffer zone on both sides of the meshed
+    boundaries. Edges are bucketed in a grid and searched outward from each
+    point, so large query arrays stay fast.
+
+    Args:
+        mesh: Mesh with 'vertices', 'triangles' and 'edges', e.g. from triangulate()
+        points: Query points as an (n, 2) NumPy array or list of (x, y) tuples
+        num_threads: Worker threads. Default: the PYSPADE_NUM_THREADS
+            environment variable, or all available cores
+
+    Returns:
+        Dictionary with keys:
+            - 'distances': Signed distance per point (negative inside the mesh)
+            - 'edges': Index into mesh['edges'] of the nearest constrained edge
+
+    Example:
+        >>> import pyspade
+        >>> mesh = pyspade.triangulate(outer, holes, max_edge_length=5.0)
+        >>> result = pyspade.distance_to_constraints(mesh, sample_xy)
+        >>> near_walls = [abs(d) < 2.0 for d in result['distances']]
+    """
+    ...
+
+def intersect_segment(
+    mesh: Dict[str, Any],
+    p0: Tuple[float, float],
+    p1: Tuple[float, float],
+) -> Dict[str, List]:
+    """
+    Triangles and edge crossings along a segment over a mesh.
+
+    The walk starts in the triangle containing p0 (or where the segment first
+    enters the mesh) and steps across shared edges towards p1. Where the
+    segment leaves through the boundary it continues with the next re-entry,
+    so holes and concave outlines are handled. Crossing points get their z
+    interpolated along the crossed edge, which makes the result a terrain
+    profile for line-of-sight or routing checks.
+
+    Args:
+        mesh: Mesh with consistently oriented 'vertices' and 'triangles'
+        p0: (x, y) start of the segment
+        p1: (x, y) end of the segment
+
+    Returns:
+        Dictionary with keys:
+            - 'triangles': Triangles visited, in order from p0 to p1
+            - 'points': (x, y, z) edge-crossing points, in order
+            - 'parameters': Position t in [0, 1] of each crossing along the segment
+            - 'edges': (i, j) vertex indices of each crossed edge
+
+    Example:
+        >>> import pyspade
+        >>> ground = pyspade.tin(points_xyz)
+        >>> profile = pyspade.intersect_segment(ground, (10.0, 20.0), (480.0, 350.0))
+        >>> heights = [z for _, _, z in profile['points']]
+    """
+    ...
+
+def line_of_sight(
+    mesh: Dict[str, Any],
+    p_from: Tuple[float, ...],
+    p_to: Tuple[float, ...],
+    observer_height: float = 0.0,
+    target_height: float = 0.0,
+) -> Dict[str, Any]:
+    """
+    Whether two points see each other over a terrain mesh.
+
+    The sightline between the two points is compared with the terrain
+    profile under it, which is linear inside each triangle, so checking the
+    edge crossings is exact. Parts of the sightline outside the mesh (holes,
+    concave outlines) never block it. A point given as (x, y) sits on the
+    terrain; observer_height and target_height are added on top of the
+    points, e.g. eye and facade heights.
+
+    Args:
+        mesh: Terrain mesh with consistently oriented (x, y, z) 'vertices' and 'triangles'
+        p_from: Observer as (x, y) or (x, y, z)
+        p_to: Target as (x, y) or (x, y, z)
+        observer_height: Height added to the observer
+        target_height: Height added to the target
+
+    Returns:
+        Dictionary with keys:
+            - 'visible': True if the terrain nowhere rises above the sightline
+            - 'blocked_at': (x, y, z) terrain point where the sightline is first blocked,
+              or None
+            - 'parameter': Position t in [0, 1] of 'blocked_at' along the sightline, or None
+            - 'clearance': Smallest height of the sightline above the terrain at the edge
+              crossings (negative if blocked, infinite without crossings)
+
+    Example:
+        >>> import pyspade
+        >>> ground = pyspade.tin(points_xyz)
+        >>> sight = pyspade.line_of_sight(ground, (10.0, 20.0), (480.0, 350.0), observer_height=1.7)
+        >>> print(sight['visible'], sight['blocked_at'])
+    """
+    ...
+
+def lines_of_sight(
+    mesh: Dict[str, Any],
+    p_from: List[Tuple[float, ...]],
+    p_to: List[Tuple[float, ...]],
+    observer_height: float = 0.0,
+    target_height: float = 0.0,
+    num_threads: Optional[int] = None,
+) -> Dict[str, List]:
+    """
+    Line-of-sight checks for many pairs of points over one terrain mesh.
+
+    The same test as line_of_sight(), with the mesh connectivity built once
+    and the pairs checked in parallel, for viewsheds and visibility studies.
+
+    Args:
+        mesh: Terrain mesh with consistently oriented (x, y, z) 'vertices' and 'triangles'
+        p_from: Observers as (x, y) or (x, y, z); a single observer is paired with
+            every target
+        p_to: Targets as (x, y) or (x, y, z), one per observer
+        observer_height: Height added to every observer
+        target_height: Height added to every target
+        num_threads: Worker threads. Default: the PYSPADE_NUM_THREADS
+            environment variable, or all available cores
+
+    Returns:
+        Dictionary with keys, one entry per pair:
+            - 'visible': True if the terrain nowhere rises above the sightline
+            - 'blocked_at': (x, y, z) first blocking terrain point, or None
+            - 'parameter': Position t in [0, 1] of 'blocked_at', or None
+            - 'clearance': Smallest height of the sightline above the terrain
+
+    Example:
+        >>> import pyspade
+        >>> ground = pyspade.tin(points_xyz)
+        >>> sight = pyspade.lines_of_sight(ground, [tower], facade_points, observer_height=30.0)
+        >>> seen = sum(sight['visible'])
+    """
+    ...
+
+class TriangulationBuilder:
+    """
+    Builds a Delaunay triangulation from points that arrive in chunks.
+
+    Each add_points() call inserts its chunk straight into the triangulation
+    with the GIL released, so point sets larger than comfortably fit in one
+    array (e.g. LiDAR tiles read chunk by chunk) never need to be
+    concatenated. Points at an already inserted position are skipped; the
+    first one wins.
+
+    Example:
+        >>> import pyspade
+        >>> builder = pyspade.TriangulationBuilder()
+        >>> for chunk in reader.chunk_iterator(1_000_000):
+        ...     builder.add_points(np.column_stack([chunk.x, chunk.y, chunk.z]))
+        >>> mesh = builder.finish()
+    """
+
+    def __init__(self) -> None: ...
+    def add_points(self, points: Union[Any, List[Tuple[float, ...]]]) -> None:
+        """Insert a chunk of (n, 2) or (n, 3) points."""
+        ...
+    def __len__(self) -> int:
+        """Number of distinct points inserted so far."""
+        ...
+    def finish(self) -> Dict[str, List]:
+        """
+        The triangulation of all points inserted so far, as a dict with
+        'vertices' ((x, y, z) tuples) and 'triangles'. The builder stays
+        usable, so more chunks can be added afterwards.
+        """
+        ...
+
+class Triangulator:
+    """
+    Triangulates polygon after polygon with fixed settings, reusing the
+    internal triangulation's memory between calls.
+
+    For services meshing thousands of small polygons per second, where
+    allocation and argument parsing dominate. Each call gives the same result
+    as triangulate() with the same settings.
+
+    Args:
+        max_edge_length: Target maximum edge length for mesh refinement
+        min_angle: Minimum angle constraint in degrees (0-33.9°)
+        triangulate_holes: If True, mesh inside holes; if False, exclude them
+        grid_size: Snap input coordinates to a grid with this spacing, as in
+            triangulate()
+        sliver_tolerance: Remove slivers thinner than this, as in triangulate()
+        relax_min_angle: Lower min_angle when refinement stalls, as in triangulate()
+        max_edge_length_rel: max_edge_length as a fraction of each polygon's
+            bounding-box diagonal, for batches of polygons of very different sizes.
+            Cannot be combined with max_edge_length
+        timeout: Refinement time limit in seconds per polygon, as in triangulate()
+        winding: Output triangle orientation, "ccw" or "cw"
+
+    Example:
+        >>> import pyspade
+        >>> triangulator = pyspade.Triangulator(max_edge_length=2.0, min_angle=25.0)
+        >>> meshes = [triangulator.triangulate(footprint) for footprint in footprints]
+    """
+
+    def __init__(
+        self,
+        max_edge_length: Optional[float] = None,
+        min_angle: Optional[float] = None,
+        triangulate_holes: bool = False,
+        grid_size: Optional[float] = None,
+        sliver_tolerance: Optional[float] = None,
+        relax_min_angle: bool = False,
+        max_edge_length_rel: Optional[float] = None,
+        timeout: Optional[float] = None,
+        winding: str = "ccw",
+    ) -> None: ...
+    def triangulate(
+        self,
+        outer: List[RingItem],
+        holes: Optional[List[List[RingItem]]] = None,
+        diagnostics: bool = False,
+    ) -> Dict[str, Any]:
+        """Triangulate one polygon with optional holes, as triangulate() does."""
+        ...
+
+class Interpolator:
+    """
+    Interpolates values given at scattered points or mesh vertices, built once
+    and queried many times.
+
+    The triangulation is built when the interpolator is created. Each call
+    only locates the query points, starting every search from where the
+    previous point was found, so queries in scan order (raster cells, profile
+    samples) are cheap. Calls can pass new values for the same points, e.g.
+    one time step of a series at a time, without rebuilding anything. Points
+    at an already inserted position are skipped; the first one wins.
+
+    Values may have several channels, given as an (n, c) array, e.g.
+    elevation, temperature and pollutant concentration side by side. Every
+    query point is then located once for all channels and the results are
+    (m, c) rows.
+
+    Values are interpolated linearly in the triangle containing the query
+    point. Points outside the convex hull of the input points, or outside the
+    triangles of a mesh, get a value chosen by outside.
+
+    Args:
+        points: (n, 2) or (n, 3) NumPy array or list of (x, y[, z]) tuples
+        values: One value per point, or an (n, c) array of c values per point.
+            Default: the z coordinates
+        outside: Value outside the domain: "nan", "nearest" (the value of the
+            closest input point) or "constant" (fill_value)
+        fill_value: Value outside the domain for outside="constant"
+
+    Example:
+        >>> import pyspade
+        >>> interpolator = pyspade.Interpolator(stations, values=temperature[0])
+        >>> grid = interpolator(cell_centers)
+        >>> series = [interpolator(cell_centers, values=step) for step in temperature]
+    """
+
+    def __init__(
+        self,
+        points: Union[Any, List[Tuple[float, ...]]],
+        values: Optional[Union[Any, List[float], List[List[float]]]] = None,
+        outside: str = "nan",
+        fill_value: Optional[float] = None,
+    ) -> None: ...
+    @staticmethod
+    def from_mesh(
+        mesh: Dict[str, Any],
+        values: Optional[Union[Any, List[float], List[List[float]]]] = None,
+        outside: str = "nan",
+        fill_value: Optional[float] = None,
+    ) -> "Interpolator":
+        """Interpolator over the triangles of a mesh; values default to the vertex z."""
+        ...
+    def __call__(
+        self,
+        points: PointArray,
+        values: Optional[Union[Any, List[float], List[List[float]]]] = None,
+    ) -> Any:
+        """
+        Interpolated float64 value per query point as a NumPy array, of shape
+        (n, c) for multi-channel values, using `values` (one row per input
+        point) instead of the stored ones if given.
+        """
+        ...
+    def __len__(self) -> int:
+        """Number of distinct input points."""
+        ...
+
+async def triangulate_async(
+    outer: List[RingItem],
+    holes: Optional[List[List[RingItem]]] = None,
+    max_edge_length: Optional[float] = None,
+    min_angle: Optional[float] = None,
+    triangulate_holes: bool = False,
+    region_sizes: Optional[List[Tuple[float, float, float]]] = None,
+    protected_segments: Optional[List[int]] = None,
+    segment_markers: Optional[List[int]] = None,
+    vertex_attributes: Optional[Dict[str, List[float]]] = None,
+    boundary_layers: Optional[Dict[str, Any]] = None,
+    diagnostics: bool = False,
+    grid_size: Optional[float] = None,
+    sliver_tolerance: Optional[float] = None,
+    relax_min_angle: bool = False,
+    max_edge_length_rel: Optional[float] = None,
+    timeout: Optional[float] = None,
+    return_hole_triangles: bool = False,
+    return_hull_triangles: bool = False,
+    winding: str = "ccw",
+    classify_edges: bool = False,
+    no_refine_zones: Optional[List[List[Tuple[float, float]]]] = None,
+    point_sizes: Optional[List[Tuple[float, float, float, float]]] = None,
+    line_sizes: Optional[List[Tuple[List[Tuple[float, float]], float, float]]] = None,
+    boundary_grading: Optional[Tuple[float, float]] = None,
+    background_mesh: Optional[Dict[str, Any]] = None,
+    background_sizes: Optional[List[float]] = None,
+    density_points: Optional[PointArray] = None,
+    density_neighbors: int = 4,
+    density_scale: float = 1.0,
+) -> Dict[str, Any]:
+    """
+    Awaitable version of triangulate(), taking the same arguments.
+
+    The triangulation runs on a worker thread and releases the GIL while
+    meshing, so an asyncio event loop (e.g. a FastAPI service) keeps serving
+    other requests during long refinements.
+
+    Example:
+        >>> mesh = await pyspade.triangulate_async(outer, holes, max_edge_length=0.5)
+    """
+    ...
+
+def check_constraints(
+    outer: List[Tuple[float, float]],
+    holes: Optional[List[List[Tuple[float, float]]]] = None,
+    segments: Optional[List[Tuple[Tuple[float, float], Tuple[float, float]]]] = None,
+) -> Dict[str, List]:
+    """
+    Find input segments that intersect or overlap, without triangulating.
+
+    Constrained triangulation needs segments that meet only at shared end
+    points. This reports every pair that doesn't, so bad data can be fixed
+    upstream. Segments sharing an end point are fine and not reported.
+
+    Args:
+        outer: Exterior boundary vertices as list of (x, y) tuples
+        holes: List of hole polygons, each as list of (x, y) tuples
+        segments: Additional segments as ((x0, y0), (x1, y1)) tuples
+
+    Returns:
+        Dictionary with keys:
+            - 'pairs': (i, j) indices of conflicting segments, i < j. Segments are
+              numbered as for triangulate()'s protected_segments (outer ring, then
+              each hole), followed by the additional segments
+            - 'kinds': Per pair, 'cross' for a proper crossing, 'touch' where an end
+              point of one segment lies inside the other, or 'overlap' for collinear
+              segments sharing a stretch
+            - 'points': Per pair, the (x, y) crossing or touching point, or the
+              middle of the shared stretch
+
+    Example:
+        >>> import pyspade
+        >>> report = pyspade.check_constraints(outer, holes)
+        >>> for (i, j), kind, point in zip(report['pairs'], report['kinds'], report['points']):
+        ...     print(f"segments {i} and {j}: {kind} at {point}")
+    """
+    ...
+
+def validate(
+    outer: List[Tuple[float, float]],
+    holes: Optional[List[List[Tuple[float, float]]]] = None,
+) -> Dict[str, Any]:
+    """
+    Check a polygon with holes for defects, without triangulating it.
+
+    Rings are numbered 0 for the outer ring and k for hole k - 1; segments as
+    for triangulate()'s protected_segments. A last vertex repeating the first
+    closes the ring and is not reported as a duplicate.
+
+    Args:
+        outer: Exterior boundary vertices as list of (x, y) tuples
+        holes: List of hole polygons, each as list of (x, y) tuples
+
+    Returns:
+        Dictionary with keys:
+            - 'valid': True if no defect was found (orientation is not a defect)
+            - 'intersections': (i, j) pairs of segments that cross, touch or overlap
+            - 'intersection_points': (x, y) location of each intersection
+            - 'duplicate_points': ((ring, vertex), (ring, vertex)) pairs where the
+              second vertex repeats the first
+            - 'degenerate_rings': Rings with fewer than 3 distinct vertices or zero area
+            - 'clockwise_rings': Rings running clockwise
+            - 'holes_outside': Indices into holes of holes not inside the outer ring
+            - 'nested_holes': (i, j) index pairs where holes[j] lies inside holes[i]
+
+    Example:
+        >>> import pyspade
+        >>> report = pyspade.validate(outer, holes)
+        >>> if not report['valid']:
+        ...     print(report['intersections'], report['holes_outside'])
+    """
+    ...
+
+def repair(
+    outer: List[Tuple[float, float]],
+    holes: Optional[List[List[Tuple[float, float]]]] = None,
+    tolerance: float = 0.0,
+) -> Dict[str, Any]:
+    """
+    Fix common defects of a polygon with holes.
+
+    In order: vertices closer than `tolerance` (across all rings) are snapped
+    onto the first of them, repeated consecutive points are removed, rings are
+    split where they cross or touch themselves, zero-area pieces are dropped,
+    outer rings are made counter-clockwise and holes clockwise, and each hole
+    is assigned to the outer ring containing it. A self-intersecting outer
+    ring (e.g. a bow tie) can fall apart into several polygons.
+
+    Args:
+        outer: Exterior boundary vertices as list of (x, y) tuples
+        holes: List of hole polygons, each as list of (x, y) tuples
+        tolerance: Distance below which vertices are snapped together
+
+    Returns:
+        Dictionary with keys:
+            - 'polygons': List of (outer, holes) tuples ready for triangulate()
+            - 'changes': Human-readable log of every change made
+
+    Example:
+        >>> import pyspade
+        >>> result = pyspade.repair(outer, holes, tolerance=1e-3)
+        >>> meshes = [pyspade.triangulate(o, h) for o, h in result['polygons']]
+    """
+    ...
+
+def suggest_parameters(
+    outer: List[Tuple[float, float]],
+    holes: Optional[List[List[Tuple[float, float]]]] = None,
+    max_edge_length: Optional[float] = None,
+    min_angle: Optional[float] = None,
+    target_triangles: int = 1000,
+) -> Dict[str, Any]:
+    """
+    Propose refinement parameters for a polygon from its size and features.
+
+    The proposed max_edge_length gives roughly `target_triangles` triangles over
+    the polygon's area; the proposed min_angle is 25° unless a sharper corner
+    between input segments makes that unreachable. Requested values are checked
+    against the same measures and a warning is returned (and logged) for each
+    that looks likely to explode the mesh.
+
+    Args:
+        outer: Exterior boundary vertices as list of (x, y) tuples
+        holes: List of hole polygons, each as list of (x, y) tuples
+        max_edge_length: Requested edge length to check
+        min_angle: Requested angle limit in degrees to check
+        target_triangles: Triangle count the proposed max_edge_length aims for
+
+    Returns:
+        Dictionary with keys:
+            - 'max_edge_length': Proposed max_edge_length, rounded to two
+              significant digits
+            - 'min_angle': Proposed min_angle in degrees
+            - 'bbox_diagonal': Diagonal of the bounding box of all rings
+            - 'area': Area of the outer ring minus the holes
+            - 'shortest_edge': Length of the shortest input segment (ignoring
+              repeated points)
+            - 'smallest_angle': Smallest angle in degrees between consecutive
+              input segments, measured inside the domain
+            - 'estimated_triangles': Rough triangle count for the requested
+              max_edge_length, or the proposed one if none is given
+            - 'warnings': Human-readable problems with the requested values
+
+    Example:
+        >>> import pyspade
+        >>> params = pyspade.suggest_parameters(outer, holes)
+        >>> mesh = pyspade.triangulate(
+        ...     outer, holes, max_edge_length=params['max_edge_length'], min_angle=params['min_angle']
+        ... )
+    """
+    ...
+
+__all__ = [
+    "triangulate",
+    "voronoi",
+    "power_diagram",
+    "largest_empty_circle",
+    "emst",
+    "knn_graph",
+    "gabriel_graph",
+    "relative_neighborhood_graph",
+    "triangulate_regions",
+    "mesh_subdomains",
+    "triangulate_tiled",
+    "mesh_terrain",
+    "mesh_polylines",
+    "tin",
+    "thin_points",
+    "grid_to_tin",
+    "adaptive_tin",
+    "simplify_tin",
+    "lods",
+    "rasterize",
+    "clip",
+    "merge",
+    "half_edges",
+    "vertex_adjacency",
+    "to_csr",
+    "dual_graph",
+    "boundary_levels",
+    "connected_components",
+    "partition",
+    "halo",
+    "optimize_vertex_cache",
+    "planar_uvs",
+    "vertex_normals",
+    "vertex_gradients",
+    "vertex_curvature",
+    "flow_accumulation",
+    "filled_contours",
+    "isobands",
+    "orient_facets",
+    "hillshade",
+    "triangulate_anisotropic",
+    "quadrangulate",
+    "quadratic_elements",
+    "remesh",
+    "smooth",
+    "refine_adaptive",
+    "split_edge",
+    "collapse_edge",
+    "flip_edge",
+    "validate_mesh",
+    "mesh_statistics",
+    "mesh_distance",
+    "sample_points",
+    "polygon_area",
+    "polygon_centroid",
+    "polygon_is_ccw",
+    "polygon_contains_point",
+    "points_in_polygon",
+    "distance_to_constraints",
+    "intersect_segment",
+    "line_of_sight",
+    "lines_of_sight",
+    "TriangulationBuilder",
+    "Triangulator",
+    "Interpolator",
+    "triangulate_async",
+    "check_constraints",
+    "validate",
+    "repair",
+    "suggest_parameters",
+]
@@ -0,0 +1,108 @@
+"""
+Apache Arrow and Parquet export of pyspade meshes.
+
+Requires the optional ``pyarrow`` dependency (``pip install pyspade[arrow]``).
+"""
+
+from typing import Any, Dict, Tuple
+
+# Mesh keys holding one value per triangle, exported as triangle columns:
+# region labels from triangulate() and tin(), input meshes from merge_meshes()
+# and parts from partition() when stored on the mesh
+TRIANGLE_KEYS = ("labels", "sources", "parts")
+
+
+def _pyarrow():
+    try:
+        import pyarrow
+    except ImportError as e:
+        raise ImportError("Arrow export requires pyarrow: pip install pyspade[arrow]") from e
+    return pyarrow
+
+
+def _columns(rows: Any, names: Tuple[str, ...], dtype: str) -> Dict[str, Any]:
+    """Split an (n, k) array or list of k-tuples into named Arrow arrays."""
+    pa = _pyarrow()
+    try:
+        import numpy as np
+    except ImportError:
+        return {name: pa.array([row[i] for row in rows]) for i, name in enumerate(names)}
+    array = np.asarray(rows, dtype=dtype).reshape(-1, len(names))
+    # Contiguous columns are handed to Arrow without another copy
+    return {name: pa.array(np.ascontiguousarray(array[:, i])) for i, name in enumerate(names)}
+
+
+def mesh_to_arrow(mesh: Dict[str, Any]) -> Tuple[Any, Any]:
+    """
+    Convert a mesh into vertex and triangle Arrow record batches.
+
+    The vertex table has columns 'x', 'y', 'z', plus 'nx', 'ny', 'nz' when the
+    mesh has 'normals' and one column per entry of 'vertex_attributes'. The
+    triangle table has columns 'v0', 'v1', 'v2' plus one column per key of
+    TRIANGLE_KEYS ('labels', 'sources', 'parts') present in the mesh.
+
+    Args:
+        mesh: Mesh dict, e.g. from triangulate() or tin()
+
+    Returns:
+        (vertices, triangles) as pyarrow.RecordBatch objects
+
+    Example:
+        >>> import pyspade
+        >>> from pyspade.arrow import mesh_to_arrow
+        >>> vertices, triangles = mesh_to_arrow(pyspade.tin(points_xyz))
+        >>> df = triangles.to_pandas()
+    """
+    pa = _pyarrow()
+    vertices = mesh["vertices"]
+    triangles = mesh["triangles"]
+    num_vertices, num_triangles = len(vertices), len(triangles)
+
+    if num_vertices and len(vertices[0]) == 2:
+        vertices = [(x, y, 0.0) for x, y in vertices]
+    vertex_columns = _columns(vertices, ("x", "y", "z"), "float64")
+    if "normals" in mesh:
+        vertex_columns.update(_columns(mesh["normals"], ("nx", "ny", "nz"), "float64"))
+    for name, values in mesh.get("vertex_attributes", {}).items():
+        vertex_columns[name] = pa.array(values, type=pa.float64())
+
+    triangle_columns = _columns(triangles, ("v0", "v1", "v2"), "int64")
+    for name in TRIANGLE_KEYS:
+        if name not in mesh:
+            continue
+        values = mesh[name]
+        if len(values) != num_triangles:
+            raise ValueError(f"mesh['{name}'] has {len(values)} values, expected one per triangle ({num_triangles})")
+        triangle_columns[name] = pa.array(values)
+
+    return (
+        pa.RecordBatch.from_pydict(vertex_columns),
+        pa.RecordBatch.from_pydict(triangle_columns),
+    )
+
+
+def write_mesh_parquet(
+    mesh: Dict[str, Any],
+    vertices_path: str,
+    triangles_path: str,
+    compression: str = "zstd",
+) -> None:
+    """
+    Write the vertex and triangle tables of a mesh to two Parquet files.
+
+    Args:
+        mesh: Mesh dict, e.g. from triangulate() or tin()
+        vertices_path: Output path of the vertex table
+        triangles_path: Output path of the triangle table
+        compression: Parquet compression codec. Default: "zstd"
+
+    Example:
+        >>> from pyspade.arrow import write_mesh_parquet
+        >>> write_mesh_parquet(mesh, "vertices.parquet", "triangles.parquet")
+    """
+    pa = _pyarrow()
+    import pyarrow.parquet as pq
+
+    vertices, triangles = mesh_to_arrow(mesh)
+    pq.write_table(pa.Table.from_batches([vertices]), vertices_path, compression=compression)
+    pq.write_table(pa.Table.from_batches([triangles]), triangles_path, compression=compression)
@@ -0,0 +1,79 @@
+"""
+GeoJSON export of pyspade Voronoi diagrams.
+
+Uses only the standard library, so the output can be opened in QGIS or any
+other GIS tool without further dependencies.
+"""
+
+import json
+from typing import Any, Dict, List, Optional, Sequence, Tuple
+
+from . import voronoi
+
+
+def voronoi_geojson(
+    points: List[Tuple[float, float]],
+    bounds: Optional[Tuple[float, float, float, float]] = None,
+    attributes: Optional[Dict[str, Sequence[Any]]] = None,
+) -> Dict[str, Any]:
+    """
+    Compute the Voronoi diagram of a point set as a GeoJSON FeatureCollection.
+
+    Each site becomes a Polygon feature with properties 'site' (its index),
+    'x', 'y', 'area' and one entry per attribute. Sites whose cell is empty
+    get a null geometry.
+
+    Args:
+        points: Site coordinates as list of (x, y) tuples
+        bounds: Clipping box as (xmin, ymin, xmax, ymax), as in voronoi()
+        attributes: Extra properties as a dict of per-site value lists
+
+    Returns:
+        GeoJSON FeatureCollection as a dict
+
+    Example:
+        >>> from pyspade.geojson import voronoi_geojson
+        >>> collection = voronoi_geojson(stations, attributes={"name": names})
+    """
+    attributes = attributes or {}
+    for name, values in attributes.items():
+        if len(values) != len(points):
+            raise ValueError(f"attribute '{name}' has {len(values)} values, expected one per site ({len(points)})")
+
+    result = voronoi(points, bounds)
+    features = []
+    for site, ((x, y), cell, area) in enumerate(zip(points, result["cells"], result["areas"])):
+        geometry = None
+        if len(cell) >= 3:
+            ring = [list(p) for p in cell]
+            if ring[0] != ring[-1]:
+                ring.append(ring[0])
+            geometry = {"type": "Polygon", "coordinates": [ring]}
+        properties = {"site": site, "x": x, "y": y, "area": area}
+        properties.update({name: values[site] for name, values in attributes.items()})
+        features.append({"type": "Feature", "geometry": geometry, "properties": properties})
+
+    return {"type": "FeatureCollection", "features": features}
+
+
+def write_voronoi_geojson(
+    path: str,
+    points: List[Tuple[float, float]],
+    bounds: Optional[Tuple[float, float, float, float]] = None,
+    attributes: Optional[Dict[str, Sequence[Any]]] = None,
+) -> None:
+    """
+    Write the Voronoi diagram of a point set to a GeoJSON file.
+
+    Args:
+        path: Output path, conventionally ending in .geojson
+        points: Site coordinates as list of (x, y) tuples
+        bounds: Clipping box as (xmin, ymin, xmax, ymax), as in voronoi()
+        attributes: Extra properties as a dict of per-site value lists
+
+    Example:
+        >>> from pyspade.geojson import write_voronoi_geojson
+        >>> write_voronoi_geojson("cells.geojson", stations, attributes={"name": names})
+    """
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(voronoi_geojson(points, bounds, attributes), f)
@@ -0,0 +1,71 @@
+"""
+NetworkX export of pyspade meshes.
+
+Requires the optional ``networkx`` dependency (``pip install pyspade[networkx]``).
+"""
+
+from typing import Any, Dict
+
+from . import dual_graph, to_csr
+
+
+def _networkx():
+    try:
+        import networkx
+    except ImportError as e:
+        raise ImportError("NetworkX export requires networkx: pip install pyspade[networkx]") from e
+    return networkx
+
+
+def to_networkx(mesh: Dict[str, Any], mode: str = "vertex") -> Any:
+    """
+    Build a NetworkX graph of a mesh's vertex connectivity or its dual graph.
+
+    In "vertex" mode nodes are vertex indices with attributes 'pos' (x, y) and
+    'z', and edges are mesh edges with attribute 'length'. In "triangle" mode
+    nodes are triangle indices with attributes 'pos' (the centroid),
+    'vertices' and 'area', and edges join triangles sharing an edge, with the
+    shared edge's 'length'. 'pos' works directly with networkx.draw().
+
+    Args:
+        mesh: Mesh dict with 'vertices' and 'triangles', e.g. from triangulate()
+        mode: "vertex" or "triangle". Default: "vertex"
+
+    Returns:
+        networkx.Graph
+
+    Example:
+        >>> import networkx as nx
+        >>> from pyspade.graph import to_networkx
+        >>> graph = to_networkx(mesh)
+        >>> path = nx.shortest_path(graph, 0, 42, weight="length")
+    """
+    nx = _networkx()
+    vertices = [tuple(v) + (0.0,) * (3 - len(v)) for v in mesh["vertices"]]
+    graph = nx.Graph()
+
+    if mode == "vertex":
+        graph.add_nodes_from((v, {"pos": (x, y), "z": z}) for v, (x, y, z) in enumerate(vertices))
+        csr = to_csr(mesh, weighted=True)
+        indptr, indices, lengths = csr["indptr"], csr["indices"], csr["data"]
+        graph.add_edges_from(
+            (v, indices[k], {"length": lengths[k]})
+            for v in range(len(vertices))
+            for k in range(indptr[v], indptr[v + 1])
+            if v < indices[k]
+        )
+    elif mode == "triangle":
+        for t, triangle in enumerate(mesh["triangles"]):
+            (ax, ay, _), (bx, by, _), (cx, cy, _) = (vertices[v] for v in triangle)
+            graph.add_node(
+                t,
+                pos=((ax + bx + cx) / 3.0, (ay + by + cy) / 3.0),
+                vertices=tuple(triangle),
+                area=0.5 * abs((bx - ax) * (cy - ay) - (by - ay) * (cx - ax)),
+            )
+        dual = dual_graph(mesh, weighted=True)
+        graph.add_edges_from((s, t, {"length": length}) for (s, t), length in zip(dual["edges"], dual["weights"]))
+    else:
+        raise ValueError(f"unknown mode '{mode}', expected 'vertex' or 'triangle'")
+
+    return graph
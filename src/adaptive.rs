@@ -0,0 +1,230 @@
+//! Solution-adaptive refinement of an existing mesh, driven by error estimates
+//! from Python.
+
+use pyo3::prelude::*;
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::collections::{HashMap, HashSet};
+
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::regions::{add_constraint_checked, constraint_edges, Cdt};
+use crate::terrain::barycentric_weights;
+
+/// Refine a mesh where an error estimate exceeds a threshold, round by round.
+///
+/// Each round, every triangle whose error is above `max_error` has its three
+/// edges split at their midpoints and the mesh is made Delaunay again, so
+/// flagged areas get about four times as many triangles while the rest of the
+/// mesh stays as it is. Boundary edges and the constrained 'edges' are kept
+/// (split where refined). z values are interpolated from the input mesh.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges'
+///     error_fn (callable or list): Function called once per round with the list of (x, y)
+///         triangle centroids, returning one error per triangle (e.g. from
+///         `np.asarray(centroids)`); or a fixed list of errors for the input triangles, which
+///         drives a single round
+///     max_error (float): Triangles with a larger error are split
+///     max_iterations (int, optional): Maximum number of refinement rounds. Default: 10
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edges (boundary and input 'edges')
+///         - 'iterations': Number of rounds that split triangles
+///         - 'max_error': Largest error of the last estimate (of the input triangles for a
+///           fixed list)
+///         - 'converged': True if the last estimate had no error above max_error
+///
+/// Example:
+///     >>> import numpy as np
+///     >>> import pyspade
+///     >>> def error(centroids):
+///     ...     xy = np.asarray(centroids)
+///     ...     return np.exp(-((xy - 50.0) ** 2).sum(axis=1) / 100.0)
+///     >>> mesh = pyspade.triangulate(outer, max_edge_length=10.0)
+///     >>> result = pyspade.refine_adaptive(mesh, error, max_error=0.1)
+#[pyfunction]
+#[pyo3(signature = (mesh, error_fn, max_error, max_iterations=10))]
+pub fn refine_adaptive(
+    mesh: Mesh,
+    error_fn: &Bound<'_, PyAny>,
+    max_error: f64,
+    max_iterations: usize,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let mut fixed: Option<Vec<f64>> = if error_fn.is_callable() { None } else { Some(error_fn.extract()?) };
+        let estimate = |centroids: &[(f64, f64)]| -> Result<Option<Vec<f64>>, Box<dyn std::error::Error>> {
+            if error_fn.is_callable() {
+                Ok(Some(error_fn.call1((centroids.to_vec(),))?.extract()?))
+            } else {
+                Ok(fixed.take())
+            }
+        };
+        // Exceptions raised by error_fn reach the caller unchanged
+        let result =
+            refine_adaptive_impl(&mesh, estimate, max_error, max_iterations).map_err(|e| match e.downcast::<PyErr>() {
+                Ok(e) => *e,
+                Err(e) => crate::to_py_err(e),
+            })?;
+
+        let mut output = result.mesh.into_dict(py);
+        output.insert("iterations".to_string(), result.iterations.into_py(py));
+        output.insert("max_error".to_string(), result.max_error.into_py(py));
+        output.insert("converged".to_string(), result.converged.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct Adapted {
+    pub mesh: Mesh,
+    pub iterations: usize,
+    pub max_error: f64,
+    pub converged: bool,
+}
+
+/// Refine `mesh` by the errors `estimate` returns for the triangle centroids
+/// of each round; `None` ends refinement without a new estimate.
+pub(crate) fn refine_adaptive_impl(
+    mesh: &Mesh,
+    mut estimate: impl FnMut(&[(f64, f64)]) -> Result<Option<Vec<f64>>, Box<dyn std::error::Error>>,
+    max_error: f64,
+    max_iterations: usize,
+) -> Result<Adapted, Box<dyn std::error::Error>> {
+    if max_error.is_nan() || max_error < 0.0 {
+        return Err(format!("max_error must be non-negative, got {}", max_error).into());
+    }
+    if mesh.triangles.is_empty() {
+        return Err("mesh has no triangles".into());
+    }
+
+    let mut cdt = Cdt::default();
+    let mut heights = Vec::new();
+    let mut handles = Vec::with_capacity(mesh.vertices.len());
+    for &(x, y, z) in &mesh.vertices {
+        let handle = cdt.insert(Point2::new(x, y))?;
+        if handle.index() == heights.len() {
+            heights.push(z);
+        }
+        handles.push(handle);
+    }
+
+    // Boundary edges (used by one triangle) and constrained edges stay in place
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((u.min(v), u.max(v))).or_default() += 1;
+        }
+    }
+    let mut features: Vec<(usize, usize)> = uses
+        .into_iter()
+        .filter(|&(_, count)| count == 1)
+        .map(|(edge, _)| edge)
+        .chain(mesh.edges.iter().map(|&(u, v)| (u.min(v), u.max(v))))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    features.sort_unstable();
+    for (u, v) in features {
+        add_constraint_checked(&mut cdt, handles[u], handles[v])?;
+    }
+
+    let grid = TriangleGrid::new(mesh);
+    let height_at = |p: Point2<f64>, fallback: f64| match grid.locate(mesh, p.x, p.y) {
+        Some(t) => {
+            let corners = mesh.corners(t);
+            let [a, b, c] = corners.map(|(x, y, _)| Point2::new(x, y));
+            let w = barycentric_weights(a, b, c, p);
+            w[0] * corners[0].2 + w[1] * corners[1].2 + w[2] * corners[2].2
+        }
+        None => fallback,
+    };
+
+    // The first round rates the input triangles, later ones the triangulation's
+    let mut triangles: Vec<[usize; 3]> = mesh
+        .triangles
+        .iter()
+        .map(|&(a, b, c)| [handles[a].index(), handles[b].index(), handles[c].index()])
+        .collect();
+    let mut iterations = 0;
+    let mut worst = 0.0;
+    let mut converged = false;
+    let mut errors = estimate(&centroids(&cdt, &triangles))?;
+    while let Some(values) = errors.take() {
+        if values.len() != triangles.len() {
+            return Err(format!("expected {} errors (one per triangle), got {}", triangles.len(), values.len()).into());
+        }
+        worst = values.iter().copied().fold(0.0, f64::max);
+        let marked: Vec<usize> = (0..triangles.len()).filter(|&t| values[t] > max_error).collect();
+        if marked.is_empty() {
+            converged = true;
+            break;
+        }
+        if iterations == max_iterations {
+            break;
+        }
+
+        let mut split = HashSet::new();
+        let mut midpoints = Vec::new();
+        for t in marked {
+            let [a, b, c] = triangles[t];
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                if split.insert((u.min(v), u.max(v))) {
+                    let (p, q) = (vertex_position(&cdt, u), vertex_position(&cdt, v));
+                    let mid = Point2::new(0.5 * (p.x + q.x), 0.5 * (p.y + q.y));
+                    midpoints.push((mid, 0.5 * (heights[u] + heights[v])));
+                }
+            }
+        }
+        for (mid, fallback) in midpoints {
+            let handle = cdt.insert(mid)?;
+            if handle.index() == heights.len() {
+                heights.push(height_at(mid, fallback));
+            }
+        }
+        iterations += 1;
+
+        triangles = cdt
+            .inner_faces()
+            .filter(|face| {
+                let c = face.center();
+                grid.locate(mesh, c.x, c.y).is_some()
+            })
+            .map(|face| face.vertices().map(|v| v.fix().index()))
+            .collect();
+        errors = estimate(&centroids(&cdt, &triangles))?;
+    }
+
+    let vertices = cdt
+        .vertices()
+        .map(|v| {
+            let p = v.position();
+            (p.x, p.y, heights[v.fix().index()])
+        })
+        .collect();
+    Ok(Adapted {
+        mesh: Mesh {
+            vertices,
+            triangles: triangles.into_iter().map(|[a, b, c]| (a, b, c)).collect(),
+            edges: constraint_edges(&cdt),
+        },
+        iterations,
+        max_error: worst,
+        converged,
+    })
+}
+
+fn vertex_position(cdt: &Cdt, v: usize) -> Point2<f64> {
+    cdt.vertex(FixedVertexHandle::from_index(v)).position()
+}
+
+fn centroids(cdt: &Cdt, triangles: &[[usize; 3]]) -> Vec<(f64, f64)> {
+    triangles
+        .iter()
+        .map(|&[a, b, c]| {
+            let (p, q, r) = (vertex_position(cdt, a), vertex_position(cdt, b), vertex_position(cdt, c));
+            ((p.x + q.x + r.x) / 3.0, (p.y + q.y + r.y) / 3.0)
+        })
+        .collect()
+}
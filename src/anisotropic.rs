@@ -0,0 +1,219 @@
+use pyo3::prelude::*;
+use spade::{Point2, PositionInTriangulation, Triangulation};
+use std::collections::HashMap;
+
+use crate::geometry;
+use crate::regions::{constraint_edges, insert_ring, label_faces, output_vertices, Cdt};
+use crate::sizing::{distance_2, segment_distance_2};
+
+/// Triangulate a polygon with elongated triangles aligned to a direction field.
+///
+/// The direction field is given as samples `(x, y, angle, ratio)`: around
+/// each sample (its Voronoi cell among the samples) triangles are stretched
+/// along `angle` (degrees, counter-clockwise from the x axis) with edges of
+/// about `max_edge_length` along that direction and `max_edge_length / ratio`
+/// across it. Boundaries are split at the across spacing. A single sample
+/// gives one global anisotropy.
+///
+/// The interior points are placed on stretched lattices and triangulated
+/// without quality refinement, which would undo the stretching; where the
+/// cells of two samples meet the transition is abrupt.
+///
+/// Args:
+///     outer (list): Exterior boundary vertices as list of (x, y) tuples
+///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples. Default: []
+///     max_edge_length (float): Edge length along the preferred direction
+///     directions (list): Direction samples as (x, y, angle_deg, ratio) tuples, ratio >= 1
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edge indices
+///
+/// Example:
+///     >>> import pyspade
+///     >>> # A road corridor running along x, resolved 5x finer across than along
+///     >>> result = pyspade.triangulate_anisotropic(
+///     ...     outer=[(0, 0), (200, 0), (200, 20), (0, 20)],
+///     ...     max_edge_length=10.0,
+///     ...     directions=[(100, 10, 0.0, 5.0)]
+///     ... )
+#[pyfunction]
+#[pyo3(signature = (outer, holes=None, *, max_edge_length, directions))]
+pub fn triangulate_anisotropic(
+    outer: Vec<(f64, f64)>,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    max_edge_length: f64,
+    directions: Vec<(f64, f64, f64, f64)>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let mut rings = vec![outer];
+        rings.extend(holes.unwrap_or_default());
+        let result = triangulate_anisotropic_impl(&rings, max_edge_length, &directions).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct AnisotropicMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// One direction sample: unit direction, along-spacing and across-spacing.
+struct Stretch {
+    origin: (f64, f64),
+    direction: (f64, f64),
+    along: f64,
+    across: f64,
+}
+
+impl Stretch {
+    fn new((x, y, angle, ratio): (f64, f64, f64, f64), h: f64) -> Self {
+        let theta = angle.to_radians();
+        Stretch {
+            origin: (x, y),
+            direction: (theta.cos(), theta.sin()),
+            along: h,
+            across: h / ratio,
+        }
+    }
+
+    /// Stretched triangular lattice points inside `cell`.
+    fn lattice(&self, cell: &[(f64, f64)]) -> Vec<Point2<f64>> {
+        let (dx, dy) = self.direction;
+        let (nx, ny) = (-dy, dx);
+        let local = |(x, y): (f64, f64)| {
+            let (rx, ry) = (x - self.origin.0, y - self.origin.1);
+            (rx * dx + ry * dy, rx * nx + ry * ny)
+        };
+        let corners: Vec<(f64, f64)> = cell.iter().map(|&p| local(p)).collect();
+        let Some((umin, vmin, umax, vmax)) = geometry::bounding_box(&corners) else {
+            return Vec::new();
+        };
+
+        let row_spacing = self.across * 3f64.sqrt() / 2.0;
+        let mut points = Vec::new();
+        for j in (vmin / row_spacing).ceil() as i64..=(vmax / row_spacing).floor() as i64 {
+            let shift = if j.rem_euclid(2) == 1 { 0.5 * self.along } else { 0.0 };
+            let v = j as f64 * row_spacing;
+            for i in ((umin - shift) / self.along).ceil() as i64..=((umax - shift) / self.along).floor() as i64 {
+                let u = shift + i as f64 * self.along;
+                let p = (self.origin.0 + u * dx + v * nx, self.origin.1 + u * dy + v * ny);
+                if geometry::point_in_polygon(p, cell) {
+                    points.push(Point2::new(p.0, p.1));
+                }
+            }
+        }
+        points
+    }
+}
+
+pub(crate) fn triangulate_anisotropic_impl(
+    rings: &[Vec<(f64, f64)>],
+    max_edge_length: f64,
+    directions: &[(f64, f64, f64, f64)],
+) -> Result<AnisotropicMesh, Box<dyn std::error::Error>> {
+    if rings[0].len() < 3 {
+        return Err("outer boundary must have at least 3 vertices".into());
+    }
+    crate::validate_edge_length(Some(max_edge_length))?;
+    if directions.is_empty() {
+        return Err("at least one direction sample is required".into());
+    }
+    if let Some(&(_, _, _, ratio)) = directions.iter().find(|&&(_, _, _, ratio)| ratio.is_nan() || ratio < 1.0) {
+        return Err(format!("anisotropy ratio must be at least 1, got {}", ratio).into());
+    }
+    let stretches: Vec<Stretch> = directions.iter().map(|&d| Stretch::new(d, max_edge_length)).collect();
+    let nearest = |(x, y): (f64, f64)| {
+        stretches
+            .iter()
+            .min_by(|a, b| {
+                let da = (a.origin.0 - x).hypot(a.origin.1 - y);
+                let db = (b.origin.0 - x).hypot(b.origin.1 - y);
+                da.total_cmp(&db)
+            })
+            .expect("directions are not empty")
+    };
+
+    let mut cdt = Cdt::default();
+    for ring in rings {
+        insert_ring(&mut cdt, ring)?;
+    }
+    // Split the boundary at the local across spacing; points inserted on a
+    // constraint edge split it into constrained halves
+    for ring in rings {
+        for (p, q) in geometry::ring_edges(ring) {
+            let h = nearest((0.5 * (p.0 + q.0), 0.5 * (p.1 + q.1))).across;
+            let pieces = ((q.0 - p.0).hypot(q.1 - p.1) / h).ceil().max(1.0) as usize;
+            for k in 1..pieces {
+                let t = k as f64 / pieces as f64;
+                cdt.insert(Point2::new(p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1)))?;
+            }
+        }
+    }
+
+    // Every sample fills its Voronoi cell (clipped to the domain's bounding box)
+    let (xmin, ymin, xmax, ymax) = geometry::bounding_box(&rings[0]).ok_or("empty outer boundary")?;
+    let labels = label_faces(&cdt, rings);
+    let mut points = Vec::new();
+    for (s, stretch) in stretches.iter().enumerate() {
+        let mut cell = vec![(xmin, ymin), (xmax, ymin), (xmax, ymax), (xmin, ymax)];
+        for (t, other) in stretches.iter().enumerate() {
+            if s == t || other.origin == stretch.origin {
+                continue;
+            }
+            let (a, b) = (other.origin.0 - stretch.origin.0, other.origin.1 - stretch.origin.1);
+            let c = 0.5 * (a * (other.origin.0 + stretch.origin.0) + b * (other.origin.1 + stretch.origin.1));
+            cell = geometry::clip_half_plane(&cell, a, b, c);
+        }
+        for p in stretch.lattice(&cell) {
+            let face = match cdt.locate(p) {
+                PositionInTriangulation::OnFace(face) => cdt.face(face),
+                _ => continue,
+            };
+            if labels[&face.fix()] != Some(0) {
+                continue;
+            }
+            let min_2 = 0.25 * stretch.across * stretch.across;
+            let crowded = face.positions().iter().any(|&v| distance_2(p, v) < min_2)
+                || face
+                    .adjacent_edges()
+                    .iter()
+                    .filter(|edge| edge.as_undirected().is_constraint_edge())
+                    .any(|edge| {
+                        let [from, to] = edge.positions();
+                        segment_distance_2(p, from, to) < min_2
+                    });
+            if !crowded {
+                points.push(p);
+            }
+        }
+    }
+    for p in points {
+        cdt.insert(p)?;
+    }
+
+    let labels = label_faces(&cdt, rings);
+    let triangles = cdt
+        .inner_faces()
+        .filter(|face| labels[&face.fix()] == Some(0))
+        .map(|face| {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            (a, b, c)
+        })
+        .collect();
+
+    Ok(AnisotropicMesh {
+        vertices: output_vertices(&cdt),
+        triangles,
+        edges: constraint_edges(&cdt),
+    })
+}
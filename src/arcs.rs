@@ -0,0 +1,139 @@
+//! Circular-arc items in boundary rings, discretized before constraint insertion.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::f64::consts::PI;
+
+/// Maximum angle spanned by one arc piece, so coarse sizes still keep arcs round.
+const MAX_ARC_STEP: f64 = PI / 8.0;
+
+/// Angle spanned by one arc piece when no target edge length is given.
+const DEFAULT_ARC_STEP: f64 = PI / 18.0;
+
+/// Largest integer magnitude below which every integer is exactly an `f64`.
+const MAX_EXACT_INTEGER: i64 = 1 << 53;
+
+/// One item of a boundary ring: a vertex `(x, y)` or a circular arc given as
+/// a dict with 'center', 'radius', 'start' and 'end' (degrees,
+/// counter-clockwise from start to end; end < start runs clockwise).
+/// Vertices given as two Python (or NumPy) integers are kept apart, so that
+/// integer input can be returned as integers.
+pub(crate) enum RingItem {
+    Point((f64, f64)),
+    IntegerPoint((i64, i64)),
+    Arc {
+        center: (f64, f64),
+        radius: f64,
+        start: f64,
+        end: f64,
+    },
+}
+
+impl<'py> FromPyObject<'py> for RingItem {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if !ob.is_instance_of::<PyDict>() {
+            if let Ok(point) = ob.extract() {
+                return Ok(RingItem::IntegerPoint(point));
+            }
+            return Ok(RingItem::Point(ob.extract()?));
+        }
+        Ok(RingItem::Arc {
+            center: ob.get_item("center")?.extract()?,
+            radius: ob.get_item("radius")?.extract()?,
+            start: ob.get_item("start")?.extract()?,
+            end: ob.get_item("end")?.extract()?,
+        })
+    }
+}
+
+/// Replace every arc in `ring` by vertices spaced at most `max_edge_length`
+/// apart along the arc (and at most 22.5° apart). Consecutive duplicate
+/// vertices, including the closing one of a full circle, are dropped.
+pub(crate) fn expand_ring(
+    ring: &[RingItem],
+    max_edge_length: Option<f64>,
+) -> Result<Vec<(f64, f64)>, Box<dyn std::error::Error>> {
+    crate::validate_edge_length(max_edge_length)?;
+    let mut points: Vec<(f64, f64)> = Vec::with_capacity(ring.len());
+    let mut push = |p: (f64, f64)| {
+        if points.last() != Some(&p) {
+            points.push(p);
+        }
+    };
+    for item in ring {
+        match *item {
+            RingItem::Point(p) => push(p),
+            RingItem::IntegerPoint((x, y)) => {
+                if x.abs() > MAX_EXACT_INTEGER || y.abs() > MAX_EXACT_INTEGER {
+                    return Err(format!("integer vertex ({}, {}) exceeds 2**53 and is not exact as a float", x, y).into());
+                }
+                push((x as f64, y as f64))
+            }
+            RingItem::Arc {
+                center,
+                radius,
+                start,
+                end,
+            } => {
+                if radius.is_nan() || radius <= 0.0 {
+                    return Err(format!("arc radius must be positive, got {}", radius).into());
+                }
+                let (start, sweep) = (start.to_radians(), (end - start).to_radians());
+                let pieces = match max_edge_length {
+                    Some(h) => ((radius * sweep.abs() / h).ceil()).max((sweep.abs() / MAX_ARC_STEP).ceil()),
+                    None => (sweep.abs() / DEFAULT_ARC_STEP).ceil(),
+                }
+                .max(1.0) as usize;
+                for k in 0..=pieces {
+                    let angle = start + sweep * k as f64 / pieces as f64;
+                    push((center.0 + radius * angle.cos(), center.1 + radius * angle.sin()));
+                }
+            }
+        }
+    }
+    // A full circle ends where it started
+    if points.len() > 1 {
+        let (first, last) = (points[0], points[points.len() - 1]);
+        if (first.0 - last.0).hypot(first.1 - last.1) <= 1e-9 * (1.0 + first.0.abs().max(first.1.abs())) {
+            points.pop();
+        }
+    }
+    Ok(points)
+}
+
+/// Whether all `rings` consist of integer vertices only.
+pub(crate) fn all_integer<'a>(rings: impl IntoIterator<Item = &'a [RingItem]>) -> bool {
+    rings
+        .into_iter()
+        .flatten()
+        .all(|item| matches!(item, RingItem::IntegerPoint(_)))
+}
+
+/// The absolute edge length from `max_edge_length`, or from `relative`, a
+/// fraction of the bounding-box diagonal of `rings` (with arcs at their
+/// default resolution). At most one of the two may be given.
+pub(crate) fn resolve_edge_length<'a>(
+    rings: impl IntoIterator<Item = &'a [RingItem]>,
+    max_edge_length: Option<f64>,
+    relative: Option<f64>,
+) -> Result<Option<f64>, Box<dyn std::error::Error>> {
+    crate::validate_edge_length(max_edge_length)?;
+    let fraction = match (max_edge_length, relative) {
+        (_, None) => return Ok(max_edge_length),
+        (Some(_), Some(_)) => return Err("give max_edge_length or max_edge_length_rel, not both".into()),
+        (None, Some(fraction)) if fraction.is_nan() || fraction <= 0.0 => {
+            return Err(format!("max_edge_length_rel must be positive, got {}", fraction).into());
+        }
+        (None, Some(fraction)) => fraction,
+    };
+    let mut points = Vec::new();
+    for ring in rings {
+        points.extend(expand_ring(ring, None)?);
+    }
+    let (min_x, min_y, max_x, max_y) = crate::geometry::bounding_box(&points).ok_or("outer ring is empty")?;
+    let h = fraction * (max_x - min_x).hypot(max_y - min_y);
+    if !(h.is_finite() && h > 0.0) {
+        return Err(format!("max_edge_length_rel gives an edge length of {} for a degenerate outer ring", h).into());
+    }
+    Ok(Some(h))
+}
@@ -0,0 +1,121 @@
+//! Point arrays passed in from Python, read straight from NumPy buffers when
+//! possible, and NumPy arrays passed back.
+
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyByteArray;
+
+use crate::mesh::vertex_row;
+
+/// An (n, 2) array of points: a float64 NumPy array (copied in one go
+/// through the buffer protocol) or any sequence of (x, y) rows. Extra
+/// columns, such as z, are ignored.
+pub(crate) struct PointArray(pub Vec<(f64, f64)>);
+
+impl<'py> FromPyObject<'py> for PointArray {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(buffer) = PyBuffer::<f64>::get_bound(ob) {
+            let shape = buffer.shape();
+            if buffer.dimensions() == 2 && shape[1] >= 2 {
+                let columns = shape[1];
+                let values = buffer.to_vec(ob.py())?;
+                return Ok(PointArray(values.chunks_exact(columns).map(|row| (row[0], row[1])).collect()));
+            }
+        }
+
+        let rows: Vec<Vec<f64>> = ob.extract()?;
+        let points = rows
+            .iter()
+            .map(|row| match row[..] {
+                [x, y, ..] => Ok((x, y)),
+                _ => Err(PyValueError::new_err("points must have at least 2 coordinates")),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PointArray(points))
+    }
+}
+
+/// An (n, 2) or (n, 3) array of points as (x, y, z), with z = 0.0 for 2D
+/// input; read like `PointArray`.
+pub(crate) struct PointArrayXYZ(pub Vec<(f64, f64, f64)>);
+
+impl<'py> FromPyObject<'py> for PointArrayXYZ {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(buffer) = PyBuffer::<f64>::get_bound(ob) {
+            let shape = buffer.shape();
+            if buffer.dimensions() == 2 && (shape[1] == 2 || shape[1] == 3) {
+                let columns = shape[1];
+                let values = buffer.to_vec(ob.py())?;
+                let z = |row: &[f64]| if columns == 3 { row[2] } else { 0.0 };
+                return Ok(PointArrayXYZ(values.chunks_exact(columns).map(|row| (row[0], row[1], z(row))).collect()));
+            }
+        }
+
+        let rows: Vec<Vec<f64>> = ob.extract()?;
+        let points = rows.iter().map(|row| vertex_row(row)).collect::<PyResult<Vec<_>>>()?;
+        Ok(PointArrayXYZ(points))
+    }
+}
+
+/// Values per point: an (n,) array of scalars or an (n, c) array with c
+/// channels per point, read like `PointArray`. Stored row by row.
+pub(crate) struct ValueArray {
+    pub values: Vec<f64>,
+    /// Channels per point, `None` for scalars
+    pub channels: Option<usize>,
+}
+
+impl ValueArray {
+    pub fn len(&self) -> usize {
+        self.values.len() / self.channels.unwrap_or(1).max(1)
+    }
+}
+
+impl<'py> FromPyObject<'py> for ValueArray {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(buffer) = PyBuffer::<f64>::get_bound(ob) {
+            let values = buffer.to_vec(ob.py())?;
+            match buffer.dimensions() {
+                1 => return Ok(ValueArray { values, channels: None }),
+                2 => return Ok(ValueArray { values, channels: Some(buffer.shape()[1]) }),
+                _ => {}
+            }
+        }
+
+        if let Ok(values) = ob.extract::<Vec<f64>>() {
+            return Ok(ValueArray { values, channels: None });
+        }
+        let rows: Vec<Vec<f64>> = ob.extract()?;
+        let channels = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != channels) {
+            return Err(PyValueError::new_err("all value rows must have the same number of channels"));
+        }
+        Ok(ValueArray {
+            values: rows.concat(),
+            channels: Some(channels),
+        })
+    }
+}
+
+/// A float64 NumPy array of the given `shape` holding `values` row by row.
+pub(crate) fn float64_array(py: Python<'_>, values: &[f64], shape: &[usize]) -> PyResult<PyObject> {
+    let bytes: Vec<u8> = values.iter().flat_map(|value| value.to_ne_bytes()).collect();
+    ndarray(py, &bytes, "float64", shape)
+}
+
+/// An int64 NumPy array of the given `shape` holding `values` row by row.
+pub(crate) fn int64_array(py: Python<'_>, values: &[i64], shape: &[usize]) -> PyResult<PyObject> {
+    let bytes: Vec<u8> = values.iter().flat_map(|value| value.to_ne_bytes()).collect();
+    ndarray(py, &bytes, "int64", shape)
+}
+
+/// Wrap native-endian `bytes` as a writable NumPy array without another copy.
+fn ndarray(py: Python<'_>, bytes: &[u8], dtype: &str, shape: &[usize]) -> PyResult<PyObject> {
+    let buffer = PyByteArray::new_bound(py, bytes);
+    let array = py
+        .import_bound("numpy")?
+        .call_method1("frombuffer", (buffer, dtype))?
+        .call_method1("reshape", (shape.to_vec(),))?;
+    Ok(array.unbind())
+}
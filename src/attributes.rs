@@ -0,0 +1,56 @@
+//! Named per-vertex attribute arrays carried from input points to mesh vertices.
+
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::collections::HashMap;
+
+use crate::regions::Cdt;
+use crate::terrain::interpolate_linear;
+
+/// Attribute name to one value per vertex.
+pub(crate) type Attributes = HashMap<String, Vec<f64>>;
+
+/// Check that every attribute has one value per input vertex.
+pub(crate) fn check_lengths(attributes: &Attributes, count: usize) -> Result<(), Box<dyn std::error::Error>> {
+    for (name, values) in attributes {
+        if values.len() != count {
+            return Err(format!(
+                "vertex attribute '{}' has {} values, expected one per input vertex ({})",
+                name,
+                values.len(),
+                count
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Move attributes given per input vertex onto the triangulation vertices
+/// `handles[i]` was inserted as. Vertices without an input vertex get NaN;
+/// duplicate input vertices keep the first value.
+pub(crate) fn transfer(attributes: &Attributes, handles: &[FixedVertexHandle], num_vertices: usize) -> Attributes {
+    attributes
+        .iter()
+        .map(|(name, values)| {
+            let mut output = vec![f64::NAN; num_vertices];
+            for (handle, &value) in handles.iter().zip(values).rev() {
+                output[handle.index()] = value;
+            }
+            (name.clone(), output)
+        })
+        .collect()
+}
+
+/// Fill in the values of vertices added after `base` was copied (constraint
+/// subdivision, size seeding and refinement points) by linear interpolation
+/// in `base`. `positions` holds every vertex of the final triangulation by
+/// index; the first `base.num_vertices()` already have their values.
+pub(crate) fn interpolate_new_vertices(attributes: &mut Attributes, base: &Cdt, positions: &[Point2<f64>]) {
+    let known = base.num_vertices();
+    for values in attributes.values_mut() {
+        let (base_values, new_values) = values.split_at_mut(known);
+        for (value, &position) in new_values.iter_mut().zip(&positions[known..]) {
+            *value = interpolate_linear(base, base_values, position);
+        }
+    }
+}
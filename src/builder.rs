@@ -0,0 +1,93 @@
+//! Incremental Delaunay triangulation fed with point chunks.
+
+use pyo3::prelude::*;
+use spade::{DelaunayTriangulation, Point2, Triangulation};
+use std::collections::HashMap;
+
+use crate::arrays::PointArrayXYZ;
+
+/// Builds a Delaunay triangulation from points that arrive in chunks.
+///
+/// Each `add_points()` call inserts its chunk straight into the
+/// triangulation with the GIL released, so point sets larger than
+/// comfortably fit in one array (e.g. LiDAR tiles read chunk by chunk) never
+/// need to be concatenated. Points at an already inserted position are
+/// skipped; the first one wins.
+///
+/// Example:
+///     >>> import pyspade
+///     >>> builder = pyspade.TriangulationBuilder()
+///     >>> for chunk in reader.chunk_iterator(1_000_000):
+///     ...     builder.add_points(np.column_stack([chunk.x, chunk.y, chunk.z]))
+///     >>> mesh = builder.finish()
+#[pyclass]
+pub struct TriangulationBuilder {
+    triangulation: DelaunayTriangulation<Point2<f64>>,
+    heights: Vec<f64>,
+}
+
+#[pymethods]
+impl TriangulationBuilder {
+    #[new]
+    fn new() -> Self {
+        TriangulationBuilder {
+            triangulation: DelaunayTriangulation::new(),
+            heights: Vec::new(),
+        }
+    }
+
+    /// Insert a chunk of points.
+    ///
+    /// Args:
+    ///     points (array): (n, 2) or (n, 3) NumPy array or list of (x, y[, z]) tuples
+    fn add_points(&mut self, py: Python<'_>, points: PointArrayXYZ) -> PyResult<()> {
+        let (triangulation, heights) = (&mut self.triangulation, &mut self.heights);
+        py.allow_threads(|| -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            for (x, y, z) in points.0 {
+                let handle = triangulation.insert(Point2::new(x, y))?;
+                if handle.index() == heights.len() {
+                    heights.push(z);
+                }
+            }
+            Ok(())
+        })
+        .map_err(|e| crate::to_py_err(e))
+    }
+
+    /// Number of distinct points inserted so far.
+    fn __len__(&self) -> usize {
+        self.triangulation.num_vertices()
+    }
+
+    /// The triangulation of all points inserted so far. The builder stays
+    /// usable, so more chunks can be added afterwards.
+    ///
+    /// Returns:
+    ///     dict: Dictionary with keys:
+    ///         - 'vertices': List of (x, y, z) vertex coordinates
+    ///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+    fn finish(&self, py: Python<'_>) -> HashMap<String, PyObject> {
+        let vertices: Vec<(f64, f64, f64)> = self
+            .triangulation
+            .vertices()
+            .zip(&self.heights)
+            .map(|(v, &z)| {
+                let p = v.position();
+                (p.x, p.y, z)
+            })
+            .collect();
+        let triangles: Vec<(usize, usize, usize)> = self
+            .triangulation
+            .inner_faces()
+            .map(|face| {
+                let [a, b, c] = face.vertices().map(|v| v.fix().index());
+                (a, b, c)
+            })
+            .collect();
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), vertices.into_py(py));
+        output.insert("triangles".to_string(), triangles.into_py(py));
+        output
+    }
+}
@@ -0,0 +1,131 @@
+use pyo3::prelude::*;
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::collections::HashMap;
+
+use crate::geometry;
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::regions::Cdt;
+use crate::terrain::barycentric_weights;
+
+/// Cut a mesh along a polygon boundary.
+///
+/// Triangles crossed by the polygon are split along it and re-triangulated;
+/// all other triangles are kept unchanged. z values of new vertices are
+/// interpolated linearly from the triangle they fall in.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' ((x, y) or (x, y, z) tuples) and 'triangles'
+///     polygon (list): Clip polygon as list of (x, y) tuples
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'inside': Mesh dict ('vertices', 'triangles') of the part inside the polygon
+///         - 'outside': Mesh dict of the remaining part
+///         - 'inside_parents': Index of the input triangle each inside triangle came from
+///         - 'outside_parents': Same for the outside triangles
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (100, 0), (100, 100), (0, 100)], max_edge_length=5.0)
+///     >>> parts = pyspade.clip(mesh, [(0, 0), (50, 0), (50, 50), (0, 50)])
+///     >>> tile = parts['inside']
+#[pyfunction]
+pub fn clip(mesh: Mesh, polygon: Vec<(f64, f64)>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = clip_impl(&mesh, &polygon).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("inside".to_string(), result.inside.into_dict(py).into_py(py));
+        output.insert("outside".to_string(), result.outside.into_dict(py).into_py(py));
+        output.insert("inside_parents".to_string(), result.inside_parents.into_py(py));
+        output.insert("outside_parents".to_string(), result.outside_parents.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct ClipResult {
+    pub inside: Mesh,
+    pub outside: Mesh,
+    pub inside_parents: Vec<usize>,
+    pub outside_parents: Vec<usize>,
+}
+
+pub(crate) fn clip_impl(mesh: &Mesh, polygon: &[(f64, f64)]) -> Result<ClipResult, Box<dyn std::error::Error>> {
+    if polygon.len() < 3 {
+        return Err("polygon must have at least 3 vertices".into());
+    }
+
+    // Every mesh edge and every polygon edge becomes a constraint, so each
+    // face of the CDT lies inside exactly one input triangle and on exactly
+    // one side of the polygon.
+    let mut cdt = Cdt::default();
+    let handles = mesh
+        .vertices
+        .iter()
+        .map(|&(x, y, _)| cdt.insert(Point2::new(x, y)))
+        .collect::<Result<Vec<FixedVertexHandle>, _>>()?;
+    for &(a, b, c) in &mesh.triangles {
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            if handles[from] != handles[to] {
+                cdt.add_constraint_and_split(handles[from], handles[to], |p| p);
+            }
+        }
+    }
+    let mut ring = Vec::with_capacity(polygon.len());
+    for &(x, y) in polygon {
+        ring.push(cdt.insert(Point2::new(x, y))?);
+    }
+    for (i, &from) in ring.iter().enumerate() {
+        let to = ring[(i + 1) % ring.len()];
+        if from != to {
+            cdt.add_constraint_and_split(from, to, |p| p);
+        }
+    }
+
+    let grid = TriangleGrid::new(mesh);
+    let mut z: Vec<f64> = vec![f64::NAN; cdt.num_vertices()];
+    for (i, handle) in handles.iter().enumerate() {
+        z[handle.index()] = mesh.vertices[i].2;
+    }
+    for vertex in cdt.vertices() {
+        let index = vertex.fix().index();
+        if z[index].is_nan() {
+            let p = vertex.position();
+            if let Some(t) = grid.locate(mesh, p.x, p.y) {
+                let corners = mesh.corners(t);
+                let [a, b, c] = corners.map(|(x, y, _)| Point2::new(x, y));
+                let weights = barycentric_weights(a, b, c, p);
+                z[index] = weights[0] * corners[0].2 + weights[1] * corners[1].2 + weights[2] * corners[2].2;
+            }
+        }
+    }
+    let vertices: Vec<(f64, f64, f64)> = cdt
+        .vertices()
+        .map(|v| (v.position().x, v.position().y, z[v.fix().index()]))
+        .collect();
+
+    let (mut inside, mut outside) = (Vec::new(), Vec::new());
+    let (mut inside_parents, mut outside_parents) = (Vec::new(), Vec::new());
+    for face in cdt.inner_faces() {
+        let center = face.center();
+        let Some(parent) = grid.locate(mesh, center.x, center.y) else {
+            continue;
+        };
+        let [a, b, c] = face.vertices().map(|v| v.fix().index());
+        if geometry::point_in_polygon((center.x, center.y), polygon) {
+            inside.push((a, b, c));
+            inside_parents.push(parent);
+        } else {
+            outside.push((a, b, c));
+            outside_parents.push(parent);
+        }
+    }
+
+    Ok(ClipResult {
+        inside: Mesh::compact(&vertices, &inside),
+        outside: Mesh::compact(&vertices, &outside),
+        inside_parents,
+        outside_parents,
+    })
+}
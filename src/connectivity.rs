@@ -0,0 +1,440 @@
+//! Connectivity structures derived from a mesh's triangle list.
+
+use pyo3::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+use crate::arrays::{float64_array, int64_array};
+use crate::mesh::Mesh;
+
+/// Half-edge (DCEL) connectivity of a triangle mesh.
+///
+/// Triangle `t` owns the half-edges `3t`, `3t + 1` and `3t + 2`, running from
+/// its first to second, second to third and third to first vertex. Boundary
+/// half-edges have no twin and get -1.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles', e.g. from `triangulate()`
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'origin': Start vertex of each half-edge
+///         - 'twin': Opposite half-edge, or -1 on the boundary
+///         - 'next': Next half-edge around the same triangle
+///         - 'face': Triangle of each half-edge
+///         - 'vertex_half_edge': One outgoing half-edge per vertex (a boundary one where
+///           possible), or -1 for unused vertices
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)])
+///     >>> he = pyspade.half_edges(mesh)
+///     >>> boundary = [h for h, t in enumerate(he['twin']) if t == -1]
+#[pyfunction]
+pub fn half_edges(mesh: Mesh) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = half_edges_impl(&mesh).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("origin".to_string(), result.origin.into_py(py));
+        output.insert("twin".to_string(), result.twin.into_py(py));
+        output.insert("next".to_string(), result.next.into_py(py));
+        output.insert("face".to_string(), result.face.into_py(py));
+        output.insert("vertex_half_edge".to_string(), result.vertex_half_edge.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct HalfEdges {
+    pub origin: Vec<usize>,
+    pub twin: Vec<i64>,
+    pub next: Vec<usize>,
+    pub face: Vec<usize>,
+    pub vertex_half_edge: Vec<i64>,
+}
+
+pub(crate) fn half_edges_impl(mesh: &Mesh) -> Result<HalfEdges, Box<dyn std::error::Error>> {
+    let n = 3 * mesh.triangles.len();
+    let mut origin = Vec::with_capacity(n);
+    let mut next = Vec::with_capacity(n);
+    let mut face = Vec::with_capacity(n);
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        origin.extend([a, b, c]);
+        next.extend([3 * t + 1, 3 * t + 2, 3 * t]);
+        face.extend([t; 3]);
+    }
+
+    let mut by_endpoints: HashMap<(usize, usize), usize> = HashMap::with_capacity(n);
+    for (h, (&from, &following)) in origin.iter().zip(&next).enumerate() {
+        let key = (from, origin[following]);
+        if by_endpoints.insert(key, h).is_some() {
+            return Err(format!("edge ({}, {}) is used twice in the same direction", key.0, key.1).into());
+        }
+    }
+    let twin: Vec<i64> = (0..n)
+        .map(|h| match by_endpoints.get(&(origin[next[h]], origin[h])) {
+            Some(&twin) => twin as i64,
+            None => -1,
+        })
+        .collect();
+
+    let mut vertex_half_edge = vec![-1i64; mesh.vertices.len()];
+    for (h, (&from, &opposite)) in origin.iter().zip(&twin).enumerate() {
+        let slot = &mut vertex_half_edge[from];
+        if *slot == -1 || opposite == -1 {
+            *slot = h as i64;
+        }
+    }
+
+    Ok(HalfEdges {
+        origin,
+        twin,
+        next,
+        face,
+        vertex_half_edge,
+    })
+}
+
+/// Vertex-to-vertex adjacency of a mesh in compressed sparse row form.
+///
+/// The neighbors of vertex `v` are `indices[offsets[v]:offsets[v + 1]]`,
+/// sorted ascending.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles', e.g. from `triangulate()`
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'offsets': len(vertices) + 1 row offsets as an int64 NumPy array
+///         - 'indices': Concatenated neighbor lists as an int64 NumPy array
+///
+/// Example:
+///     >>> import numpy as np
+///     >>> import scipy.sparse as sp
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], max_edge_length=2.0)
+///     >>> adj = pyspade.vertex_adjacency(mesh)
+///     >>> n = len(mesh['vertices'])
+///     >>> A = sp.csr_matrix((np.ones(len(adj['indices'])), adj['indices'], adj['offsets']), shape=(n, n))
+#[pyfunction]
+pub fn vertex_adjacency(mesh: Mesh) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (offsets, indices) = vertex_adjacency_impl(&mesh);
+
+        let offsets: Vec<i64> = offsets.into_iter().map(|offset| offset as i64).collect();
+        let indices: Vec<i64> = indices.into_iter().map(|index| index as i64).collect();
+
+        let mut output = HashMap::new();
+        output.insert("offsets".to_string(), int64_array(py, &offsets, &[offsets.len()])?);
+        output.insert("indices".to_string(), int64_array(py, &indices, &[indices.len()])?);
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn vertex_adjacency_impl(mesh: &Mesh) -> (Vec<usize>, Vec<usize>) {
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); mesh.vertices.len()];
+    for &(a, b, c) in &mesh.triangles {
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            neighbors[from].push(to);
+            neighbors[to].push(from);
+        }
+    }
+
+    let mut offsets = Vec::with_capacity(neighbors.len() + 1);
+    let mut indices = Vec::new();
+    offsets.push(0);
+    for mut ring in neighbors {
+        ring.sort_unstable();
+        ring.dedup();
+        indices.extend(ring);
+        offsets.push(indices.len());
+    }
+    (offsets, indices)
+}
+
+/// Hop distance of every triangle from the boundary of the mesh.
+///
+/// Triangles with an edge on the boundary (an edge no other triangle shares)
+/// are level 0; every other triangle is one more than its lowest neighbor
+/// across an edge. Schemes that stabilize or damp near the boundary can pick
+/// their layers from these levels.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles', e.g. from `triangulate()`
+///     max_level (int, optional): Stop counting here; deeper triangles get this level.
+///         Default: None (no limit)
+///
+/// Returns:
+///     list: Level of each triangle, or -1 where no boundary can be reached (closed surfaces)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], max_edge_length=1.0)
+///     >>> levels = pyspade.boundary_levels(mesh, max_level=2)
+///     >>> interior = [t for t, level in enumerate(levels) if level == 2]
+#[pyfunction]
+#[pyo3(signature = (mesh, max_level=None))]
+pub fn boundary_levels(mesh: Mesh, max_level: Option<usize>) -> PyResult<Vec<i64>> {
+    Ok(boundary_levels_impl(&mesh, max_level))
+}
+
+/// Neighbors of each triangle across its edges, in edge order.
+pub(crate) fn triangle_neighbors(mesh: &Mesh) -> Vec<Vec<usize>> {
+    let mut by_edge: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            by_edge.entry((u.min(v), u.max(v))).or_default().push(t);
+        }
+    }
+    mesh.triangles
+        .iter()
+        .enumerate()
+        .map(|(t, &(a, b, c))| {
+            [(a, b), (b, c), (c, a)]
+                .iter()
+                .flat_map(|&(u, v)| by_edge[&(u.min(v), u.max(v))].iter().copied().filter(|&other| other != t))
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn boundary_levels_impl(mesh: &Mesh, max_level: Option<usize>) -> Vec<i64> {
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((u.min(v), u.max(v))).or_default() += 1;
+        }
+    }
+    let neighbors = triangle_neighbors(mesh);
+    let mut levels = vec![usize::MAX; mesh.triangles.len()];
+    let mut queue = VecDeque::new();
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        if [(a, b), (b, c), (c, a)].iter().any(|&(u, v)| uses[&(u.min(v), u.max(v))] == 1) {
+            levels[t] = 0;
+            queue.push_back(t);
+        }
+    }
+    while let Some(t) = queue.pop_front() {
+        for &other in &neighbors[t] {
+            if levels[other] == usize::MAX {
+                levels[other] = levels[t] + 1;
+                queue.push_back(other);
+            }
+        }
+    }
+    let max_level = max_level.unwrap_or(usize::MAX);
+    levels
+        .into_iter()
+        .map(|level| match level {
+            usize::MAX => -1,
+            level => level.min(max_level) as i64,
+        })
+        .collect()
+}
+
+/// Label the connected components of a mesh.
+///
+/// Excluding holes or meshing several polygons at once can leave a mesh in
+/// pieces. Components are numbered in order of their first triangle.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles', e.g. from `triangulate()`
+///     through_vertices (bool, optional): If True, triangles sharing only a vertex are
+///         connected too; otherwise they must share an edge. Default: False
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'labels': Component of each triangle
+///         - 'count': Number of components
+///         - 'sizes': Number of triangles in each component
+///
+/// Example:
+///     >>> import pyspade
+///     >>> components = pyspade.connected_components(mesh)
+///     >>> for c in range(components['count']):
+///     ...     part = [t for t, label in zip(mesh['triangles'], components['labels']) if label == c]
+#[pyfunction]
+#[pyo3(signature = (mesh, through_vertices=false))]
+pub fn connected_components(mesh: Mesh, through_vertices: bool) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let labels = connected_components_impl(&mesh, through_vertices);
+        let count = labels.iter().max().map_or(0, |&label| label + 1);
+        let mut sizes = vec![0usize; count];
+        for &label in &labels {
+            sizes[label] += 1;
+        }
+
+        let mut output = HashMap::new();
+        output.insert("labels".to_string(), labels.into_py(py));
+        output.insert("count".to_string(), count.into_py(py));
+        output.insert("sizes".to_string(), sizes.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Triangles sharing a vertex with each triangle. The lists may repeat
+/// triangles and include the triangle itself.
+pub(crate) fn triangle_vertex_neighbors(mesh: &Mesh) -> Vec<Vec<usize>> {
+    let mut by_vertex: Vec<Vec<usize>> = vec![Vec::new(); mesh.vertices.len()];
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        for v in [a, b, c] {
+            by_vertex[v].push(t);
+        }
+    }
+    mesh.triangles
+        .iter()
+        .map(|&(a, b, c)| [a, b, c].iter().flat_map(|&v| by_vertex[v].iter().copied()).collect())
+        .collect()
+}
+
+/// Component of each triangle, numbered in order of first triangle.
+pub(crate) fn connected_components_impl(mesh: &Mesh, through_vertices: bool) -> Vec<usize> {
+    let neighbors = if through_vertices { triangle_vertex_neighbors(mesh) } else { triangle_neighbors(mesh) };
+
+    let mut labels = vec![usize::MAX; mesh.triangles.len()];
+    let mut count = 0;
+    for start in 0..mesh.triangles.len() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+        labels[start] = count;
+        let mut stack = vec![start];
+        while let Some(t) = stack.pop() {
+            for &other in &neighbors[t] {
+                if labels[other] == usize::MAX {
+                    labels[other] = count;
+                    stack.push(other);
+                }
+            }
+        }
+        count += 1;
+    }
+    labels
+}
+
+/// Vertex connectivity graph of a mesh as compressed sparse row arrays.
+///
+/// The arrays match SciPy's `csr_matrix((data, indices, indptr), shape)`
+/// constructor, so graph algorithms (shortest paths, spectral methods) run on
+/// the mesh without edge loops in Python. Every edge appears in both
+/// directions, with neighbors sorted ascending.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles', e.g. from `triangulate()`
+///     weighted (bool, optional): If True, each entry is the edge's length (in 3D for
+///         (x, y, z) vertices); otherwise 1.0. Default: False
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'data': Entry values as a float64 NumPy array
+///         - 'indices': Column (neighbor) index of each entry as an int64 NumPy array
+///         - 'indptr': len(vertices) + 1 row offsets as an int64 NumPy array
+///         - 'shape': (len(vertices), len(vertices))
+///
+/// Example:
+///     >>> import pyspade
+///     >>> from scipy.sparse import csr_matrix
+///     >>> from scipy.sparse.csgraph import dijkstra
+///     >>> csr = pyspade.to_csr(mesh, weighted=True)
+///     >>> graph = csr_matrix((csr['data'], csr['indices'], csr['indptr']), shape=csr['shape'])
+///     >>> distances = dijkstra(graph, indices=0)
+#[pyfunction]
+#[pyo3(signature = (mesh, weighted=false))]
+pub fn to_csr(mesh: Mesh, weighted: bool) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (indptr, indices) = vertex_adjacency_impl(&mesh);
+        let data: Vec<f64> = if weighted {
+            (0..mesh.vertices.len())
+                .flat_map(|v| indices[indptr[v]..indptr[v + 1]].iter().map(move |&w| (v, w)))
+                .map(|(v, w)| {
+                    let (p, q) = (mesh.vertices[v], mesh.vertices[w]);
+                    (q.0 - p.0).hypot(q.1 - p.1).hypot(q.2 - p.2)
+                })
+                .collect()
+        } else {
+            vec![1.0; indices.len()]
+        };
+        let n = mesh.vertices.len();
+        let indices: Vec<i64> = indices.into_iter().map(|index| index as i64).collect();
+        let indptr: Vec<i64> = indptr.into_iter().map(|offset| offset as i64).collect();
+
+        let mut output = HashMap::new();
+        output.insert("data".to_string(), float64_array(py, &data, &[data.len()])?);
+        output.insert("indices".to_string(), int64_array(py, &indices, &[indices.len()])?);
+        output.insert("indptr".to_string(), int64_array(py, &indptr, &[indptr.len()])?);
+        output.insert("shape".to_string(), (n, n).into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Dual graph of a mesh: triangles joined where they share an edge.
+///
+/// Used for region growing and segmentation over triangles. Each pair is
+/// listed once as (s, t) with s < t, sorted; an edge shared by more than two
+/// triangles joins every pair of them.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles', e.g. from `triangulate()`
+///     weighted (bool, optional): If True, each weight is the length of the shared edge (in
+///         3D for (x, y, z) vertices); otherwise 1.0. Default: False
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'edges': List of (s, t) triangle index pairs
+///         - 'weights': Weight of each pair
+///
+/// Example:
+///     >>> import pyspade
+///     >>> dual = pyspade.dual_graph(mesh, weighted=True)
+///     >>> for (s, t), length in zip(dual['edges'], dual['weights']):
+///     ...     print(s, t, length)
+#[pyfunction]
+#[pyo3(signature = (mesh, weighted=false))]
+pub fn dual_graph(mesh: Mesh, weighted: bool) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (edges, shared) = dual_graph_impl(&mesh);
+        let weights: Vec<f64> = if weighted {
+            shared
+                .iter()
+                .map(|&(u, v)| {
+                    let (p, q) = (mesh.vertices[u], mesh.vertices[v]);
+                    (q.0 - p.0).hypot(q.1 - p.1).hypot(q.2 - p.2)
+                })
+                .collect()
+        } else {
+            vec![1.0; edges.len()]
+        };
+
+        let mut output = HashMap::new();
+        output.insert("edges".to_string(), edges.into_py(py));
+        output.insert("weights".to_string(), weights.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Sorted (s, t) triangle pairs sharing an edge, with the shared edge of each.
+pub(crate) fn dual_graph_impl(mesh: &Mesh) -> (Vec<(usize, usize)>, Vec<(usize, usize)>) {
+    let mut by_edge: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            by_edge.entry((u.min(v), u.max(v))).or_default().push(t);
+        }
+    }
+    let mut pairs: Vec<((usize, usize), (usize, usize))> = Vec::new();
+    for (&edge, triangles) in &by_edge {
+        for (i, &s) in triangles.iter().enumerate() {
+            for &t in &triangles[i + 1..] {
+                if s != t {
+                    pairs.push(((s.min(t), s.max(t)), edge));
+                }
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs.dedup_by_key(|&mut (pair, _)| pair);
+    pairs.into_iter().unzip()
+}
@@ -0,0 +1,289 @@
+//! Queries on constraint segments: distances to the constrained edges of a
+//! mesh, and conflicts between input segments before meshing.
+
+use pyo3::prelude::*;
+use spade::Point2;
+use std::collections::HashMap;
+
+use crate::arrays::PointArray;
+use crate::geometry;
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::sizing::{orient, segment_distance_2};
+use crate::threads::{self, parallel_map};
+
+/// Signed distance from query points to the nearest constrained edge.
+///
+/// Distances are negative for points inside the mesh and positive outside,
+/// so `abs(distances) < buffer` selects a buffer zone on both sides of the
+/// meshed boundaries. Edges are bucketed in a grid and searched outward from
+/// each point, so large query arrays stay fast.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and 'edges', e.g. from `triangulate()`
+///     points (array): Query points as an (n, 2) NumPy array or list of (x, y) tuples
+///     num_threads (int, optional): Worker threads. Default: the PYSPADE_NUM_THREADS
+///         environment variable, or all available cores
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'distances': Signed distance per point (negative inside the mesh)
+///         - 'edges': Index into mesh['edges'] of the nearest constrained edge
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate(outer, holes, max_edge_length=5.0)
+///     >>> result = pyspade.distance_to_constraints(mesh, sample_xy)
+///     >>> near_walls = [abs(d) < 2.0 for d in result['distances']]
+#[pyfunction]
+#[pyo3(signature = (mesh, points, num_threads=None))]
+pub fn distance_to_constraints(
+    mesh: Mesh,
+    points: PointArray,
+    num_threads: Option<usize>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (distances, edges) = py
+            .allow_threads(|| distance_to_constraints_impl(&mesh, &points.0, num_threads).map_err(|e| e.to_string()))
+            .map_err(|e| crate::to_py_err(e.into()))?;
+
+        let mut output = HashMap::new();
+        output.insert("distances".to_string(), distances.into_py(py));
+        output.insert("edges".to_string(), edges.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Find input segments that intersect or overlap, without triangulating.
+///
+/// Constrained triangulation needs segments that meet only at shared end
+/// points. This reports every pair that doesn't, so bad data can be fixed
+/// upstream. Segments sharing an end point are fine and not reported.
+///
+/// Args:
+///     outer (list): Exterior boundary vertices as list of (x, y) tuples
+///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples. Default: []
+///     segments (list, optional): Additional segments as ((x0, y0), (x1, y1)) tuples. Default: []
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'pairs': (i, j) indices of conflicting segments, i < j. Segments are numbered
+///           as for `triangulate()`'s protected_segments (outer ring, then each hole),
+///           followed by the additional segments
+///         - 'kinds': Per pair, 'cross' for a proper crossing, 'touch' where an end point
+///           of one segment lies inside the other, or 'overlap' for collinear segments
+///           sharing a stretch
+///         - 'points': Per pair, the (x, y) crossing or touching point, or the middle of
+///           the shared stretch
+///
+/// Example:
+///     >>> import pyspade
+///     >>> report = pyspade.check_constraints(outer, holes)
+///     >>> for (i, j), kind, point in zip(report['pairs'], report['kinds'], report['points']):
+///     ...     print(f"segments {i} and {j}: {kind} at {point}")
+#[pyfunction]
+#[pyo3(signature = (outer, holes=None, segments=None))]
+pub fn check_constraints(
+    outer: Vec<(f64, f64)>,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    segments: Option<Vec<((f64, f64), (f64, f64))>>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let conflicts = check_constraints_impl(&outer, &holes.unwrap_or_default(), &segments.unwrap_or_default());
+
+        let mut output = HashMap::new();
+        output.insert("pairs".to_string(), conflicts.iter().map(|c| c.pair).collect::<Vec<_>>().into_py(py));
+        output.insert("kinds".to_string(), conflicts.iter().map(|c| c.kind).collect::<Vec<_>>().into_py(py));
+        output.insert("points".to_string(), conflicts.iter().map(|c| c.point).collect::<Vec<_>>().into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct Conflict {
+    pub pair: (usize, usize),
+    pub kind: &'static str,
+    pub point: (f64, f64),
+}
+
+/// Conflicts between all ring and extra segments, ordered by segment pair.
+pub(crate) fn check_constraints_impl(
+    outer: &[(f64, f64)],
+    holes: &[Vec<(f64, f64)>],
+    extra: &[((f64, f64), (f64, f64))],
+) -> Vec<Conflict> {
+    let point = |(x, y): (f64, f64)| Point2::new(x, y);
+    let segments: Vec<(Point2<f64>, Point2<f64>)> = std::iter::once(outer)
+        .chain(holes.iter().map(Vec::as_slice))
+        .flat_map(geometry::ring_edges)
+        .chain(extra.iter().copied())
+        .map(|(p, q)| (point(p), point(q)))
+        .collect();
+
+    // Only segments sharing a grid cell can conflict
+    let grid = SegmentGrid::new(&segments);
+    let mut candidates: Vec<(usize, usize)> = Vec::new();
+    for bucket in &grid.buckets {
+        for (k, &i) in bucket.iter().enumerate() {
+            candidates.extend(bucket[k + 1..].iter().map(|&j| (i.min(j), i.max(j))));
+        }
+    }
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .filter_map(|(i, j)| {
+            let ((a, b), (c, d)) = (segments[i], segments[j]);
+            // Zero-length segments are dropped by triangulation anyway
+            if a == b || c == d {
+                return None;
+            }
+            segment_conflict(a, b, c, d).map(|(kind, p)| Conflict {
+                pair: (i, j),
+                kind,
+                point: (p.x, p.y),
+            })
+        })
+        .collect()
+}
+
+/// How segments `a`-`b` and `c`-`d` conflict, if they do, and where.
+fn segment_conflict(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, d: Point2<f64>) -> Option<(&'static str, Point2<f64>)> {
+    let (d1, d2) = (orient(c, d, a), orient(c, d, b));
+    let (d3, d4) = (orient(a, b, c), orient(a, b, d));
+
+    if d1 == 0.0 && d2 == 0.0 {
+        // Collinear: compare positions along a-b
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let along = |p: Point2<f64>| ((p.x - a.x) * dx + (p.y - a.y) * dy) / (dx * dx + dy * dy);
+        let (s, t) = (along(c), along(d));
+        let (lo, hi) = (s.min(t).max(0.0), s.max(t).min(1.0));
+        let at = |u: f64| Point2::new(a.x + u * dx, a.y + u * dy);
+        return if lo < hi {
+            Some(("overlap", at(0.5 * (lo + hi))))
+        } else if lo == hi && !shares_end(a, b, c, d) {
+            Some(("touch", at(lo)))
+        } else {
+            None
+        };
+    }
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        let t = d1 / (d1 - d2);
+        return Some(("cross", Point2::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y))));
+    }
+    // An end point of one segment on the other, other than a shared end point
+    [(d1, a, c, d), (d2, b, c, d), (d3, c, a, b), (d4, d, a, b)]
+        .into_iter()
+        .find(|&(side, p, s, t)| side == 0.0 && p != s && p != t && within(p, s, t))
+        .map(|(_, p, _, _)| ("touch", p))
+}
+
+fn shares_end(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, d: Point2<f64>) -> bool {
+    a == c || a == d || b == c || b == d
+}
+
+/// Whether `p`, collinear with `s`-`t`, lies between them.
+fn within(p: Point2<f64>, s: Point2<f64>, t: Point2<f64>) -> bool {
+    p.x >= s.x.min(t.x) && p.x <= s.x.max(t.x) && p.y >= s.y.min(t.y) && p.y <= s.y.max(t.y)
+}
+
+/// Uniform bucket grid over line segments for nearest-segment queries.
+pub(crate) struct SegmentGrid {
+    origin: (f64, f64),
+    cell: f64,
+    cols: usize,
+    rows: usize,
+    buckets: Vec<Vec<usize>>,
+}
+
+impl SegmentGrid {
+    pub fn new(segments: &[(Point2<f64>, Point2<f64>)]) -> Self {
+        let ends: Vec<(f64, f64)> = segments.iter().flat_map(|(p, q)| [(p.x, p.y), (q.x, q.y)]).collect();
+        let (xmin, ymin, xmax, ymax) = geometry::bounding_box(&ends).unwrap_or((0.0, 0.0, 0.0, 0.0));
+        let (width, height) = (xmax - xmin, ymax - ymin);
+        // About one segment per cell along the longer side
+        let cell = (width.max(height) / segments.len().max(1) as f64)
+            .max(width.max(height) / 1024.0)
+            .max(f64::MIN_POSITIVE);
+        let cols = (width / cell).floor() as usize + 1;
+        let rows = (height / cell).floor() as usize + 1;
+
+        let mut grid = SegmentGrid {
+            origin: (xmin, ymin),
+            cell,
+            cols,
+            rows,
+            buckets: vec![Vec::new(); cols * rows],
+        };
+        for (s, (p, q)) in segments.iter().enumerate() {
+            let (c0, r0) = grid.cell_of(p.x.min(q.x), p.y.min(q.y));
+            let (c1, r1) = grid.cell_of(p.x.max(q.x), p.y.max(q.y));
+            for row in r0..=r1 {
+                for col in c0..=c1 {
+                    grid.buckets[row * cols + col].push(s);
+                }
+            }
+        }
+        grid
+    }
+
+    fn cell_of(&self, x: f64, y: f64) -> (usize, usize) {
+        let col = ((x - self.origin.0) / self.cell).floor().clamp(0.0, (self.cols - 1) as f64);
+        let row = ((y - self.origin.1) / self.cell).floor().clamp(0.0, (self.rows - 1) as f64);
+        (col as usize, row as usize)
+    }
+
+    /// Nearest segment to `p` and its distance, searching rings of cells
+    /// until no closer segment can remain.
+    pub fn nearest(&self, segments: &[(Point2<f64>, Point2<f64>)], p: Point2<f64>) -> (usize, f64) {
+        let (col, row) = self.cell_of(p.x, p.y);
+        let mut best = (0, f64::INFINITY);
+        for ring in 0..self.cols.max(self.rows) {
+            let (c0, c1) = (col.saturating_sub(ring), (col + ring).min(self.cols - 1));
+            let (r0, r1) = (row.saturating_sub(ring), (row + ring).min(self.rows - 1));
+            for r in r0..=r1 {
+                for c in c0..=c1 {
+                    if r.abs_diff(row) != ring && c.abs_diff(col) != ring {
+                        continue;
+                    }
+                    for &s in &self.buckets[r * self.cols + c] {
+                        let (from, to) = segments[s];
+                        let d = segment_distance_2(p, from, to).sqrt();
+                        if d < best.1 {
+                            best = (s, d);
+                        }
+                    }
+                }
+            }
+            if best.1 <= ring as f64 * self.cell {
+                break;
+            }
+        }
+        best
+    }
+}
+
+type ConstraintDistances = (Vec<f64>, Vec<usize>);
+
+pub(crate) fn distance_to_constraints_impl(
+    mesh: &Mesh,
+    points: &[(f64, f64)],
+    num_threads: Option<usize>,
+) -> Result<ConstraintDistances, Box<dyn std::error::Error>> {
+    if mesh.edges.is_empty() {
+        return Err("mesh has no constrained 'edges'".into());
+    }
+    let position = |v: usize| Point2::new(mesh.vertices[v].0, mesh.vertices[v].1);
+    let segments: Vec<(Point2<f64>, Point2<f64>)> = mesh.edges.iter().map(|&(a, b)| (position(a), position(b))).collect();
+    let grid = SegmentGrid::new(&segments);
+    let triangles = TriangleGrid::new(mesh);
+
+    let threads = threads::num_threads(num_threads)?;
+    let nearest = parallel_map(points, threads, |&(x, y)| {
+        let (edge, distance) = grid.nearest(&segments, Point2::new(x, y));
+        let inside = triangles.locate(mesh, x, y).is_some();
+        (if inside { -distance } else { distance }, edge)
+    });
+    Ok(nearest.into_iter().unzip())
+}
@@ -0,0 +1,228 @@
+//! Filled contours and isobands (bands between levels) of scalar fields on
+//! triangle meshes.
+
+use pyo3::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::geometry;
+use crate::mesh::Mesh;
+
+/// A polygon as its exterior ring followed by its hole rings, each an open
+/// list of (x, y) points.
+pub(crate) type Polygon = Vec<Vec<(f64, f64)>>;
+
+/// Filled contour polygons of the terrain between consecutive levels.
+///
+/// Band `i` is the region where `levels[i] <= z < levels[i + 1]`, traced
+/// exactly on the piecewise linear surface and returned as polygons with
+/// holes. Exterior rings are counter-clockwise and holes clockwise, as in
+/// GeoJSON. Pass -inf and inf as the first and last level to cover the
+/// whole mesh.
+///
+/// Args:
+///     mesh (dict): Terrain mesh with (x, y, z) 'vertices' and 'triangles'
+///     levels (list): Increasing contour levels
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'bands': (lower, upper) levels of each band
+///         - 'polygons': Per band, a list of polygons, each a list of rings (exterior first,
+///           then holes) of (x, y) tuples, without repeating the first point
+///
+/// Example:
+///     >>> import pyspade
+///     >>> terrain = pyspade.tin(points_xyz)
+///     >>> result = pyspade.filled_contours(terrain, levels=[0.0, 1.0, 2.0, 5.0])
+///     >>> flooded = result['polygons'][0]
+#[pyfunction]
+pub fn filled_contours(mesh: Mesh, levels: Vec<f64>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let heights: Vec<f64> = mesh.vertices.iter().map(|&(_, _, z)| z).collect();
+        let bands = consecutive_bands(&levels).map_err(crate::to_py_err)?;
+        let polygons: Vec<Vec<Polygon>> =
+            bands.iter().map(|&(lower, upper)| band_polygons(&mesh, &heights, lower, upper)).collect();
+
+        let mut output = HashMap::new();
+        output.insert("bands".to_string(), bands.into_py(py));
+        output.insert("polygons".to_string(), polygons.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Band polygons of a per-vertex scalar field between consecutive breaks.
+///
+/// Works like `filled_contours()` on any field given at the mesh vertices,
+/// such as noise levels or pollutant concentrations from a simulation on the
+/// mesh, and returns a flat list of polygons labeled with their band, ready
+/// to be written as GIS features. Band `i` is where
+/// `breaks[i] <= value < breaks[i + 1]`; vertices with NaN values are in no
+/// band.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles'
+///     values (list): One value per vertex
+///     breaks (list): Increasing band limits
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'polygons': List of polygons, each a list of rings (exterior first, then holes)
+///           of (x, y) tuples, without repeating the first point
+///         - 'labels': Band index of each polygon
+///         - 'bands': (lower, upper) limits of each band
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.isobands(mesh, noise_db, breaks=[45.0, 50.0, 55.0, 60.0, 65.0])
+///     >>> loud = [p for p, label in zip(result['polygons'], result['labels']) if label >= 2]
+#[pyfunction]
+pub fn isobands(mesh: Mesh, values: Vec<f64>, breaks: Vec<f64>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        if values.len() != mesh.vertices.len() {
+            let message = format!("expected {} values (one per vertex), got {}", mesh.vertices.len(), values.len());
+            return Err(crate::to_py_err(message.into()));
+        }
+        let bands = consecutive_bands(&breaks).map_err(crate::to_py_err)?;
+        let mut polygons: Vec<Polygon> = Vec::new();
+        let mut labels: Vec<usize> = Vec::new();
+        for (label, &(lower, upper)) in bands.iter().enumerate() {
+            let band = band_polygons(&mesh, &values, lower, upper);
+            labels.resize(labels.len() + band.len(), label);
+            polygons.extend(band);
+        }
+
+        let mut output = HashMap::new();
+        output.insert("polygons".to_string(), polygons.into_py(py));
+        output.insert("labels".to_string(), labels.into_py(py));
+        output.insert("bands".to_string(), bands.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Bands between consecutive `levels`, which must be increasing.
+pub(crate) fn consecutive_bands(levels: &[f64]) -> Result<Vec<(f64, f64)>, Box<dyn std::error::Error>> {
+    if levels.len() < 2 {
+        return Err("need at least 2 levels".into());
+    }
+    if levels.windows(2).any(|pair| pair[0].is_nan() || !(pair[0] < pair[1])) {
+        return Err("levels must be increasing".into());
+    }
+    Ok(levels.windows(2).map(|pair| (pair[0], pair[1])).collect())
+}
+
+/// A point on a band boundary: a mesh vertex, or where the field crosses a
+/// level on a mesh edge `(u, v)` with `u < v`. Keys identify points exactly,
+/// so neighboring triangles agree on them, and order the ring chaining.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Key {
+    Vertex(usize),
+    Crossing(usize, usize, u64),
+}
+
+/// Polygons covering the part of `mesh` where `lower <= values < upper`.
+pub(crate) fn band_polygons(mesh: &Mesh, values: &[f64], lower: f64, upper: f64) -> Vec<Polygon> {
+    let inside = |v: usize| values[v] >= lower && values[v] < upper;
+    let mut positions: HashMap<Key, (f64, f64)> = HashMap::new();
+    let mut position = |key: Key| {
+        *positions.entry(key).or_insert_with(|| match key {
+            Key::Vertex(v) => (mesh.vertices[v].0, mesh.vertices[v].1),
+            Key::Crossing(u, v, level) => {
+                let t = (f64::from_bits(level) - values[u]) / (values[v] - values[u]);
+                let (p, q) = (mesh.vertices[u], mesh.vertices[v]);
+                (p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1))
+            }
+        })
+    };
+
+    // Each triangle's piece of the band, counter-clockwise; interior edges
+    // of the band appear once in each direction. Ordered, so that rings come
+    // out in the same order and from the same start on every run
+    let mut edges: BTreeMap<Key, Vec<Key>> = BTreeMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        if [a, b, c].iter().any(|&v| values[v].is_nan()) {
+            continue;
+        }
+        let corners = [a, b, c].map(|v| (mesh.vertices[v].0, mesh.vertices[v].1));
+        let [a, b, c] = if geometry::signed_area(&corners) < 0.0 { [a, c, b] } else { [a, b, c] };
+        let mut piece: Vec<Key> = Vec::with_capacity(5);
+        for (p, q) in [(a, b), (b, c), (c, a)] {
+            if inside(p) {
+                piece.push(Key::Vertex(p));
+            }
+            let mut crossings: Vec<(f64, Key)> = [lower, upper]
+                .into_iter()
+                .filter(|&level| (values[p] >= level) != (values[q] >= level))
+                .map(|level| {
+                    let t = (level - values[p]) / (values[q] - values[p]);
+                    let key = if t == 0.0 {
+                        Key::Vertex(p)
+                    } else if t == 1.0 {
+                        Key::Vertex(q)
+                    } else {
+                        Key::Crossing(p.min(q), p.max(q), level.to_bits())
+                    };
+                    (t, key)
+                })
+                .collect();
+            crossings.sort_by(|x, y| x.0.total_cmp(&y.0));
+            piece.extend(crossings.into_iter().map(|(_, key)| key));
+        }
+        piece.dedup();
+        while piece.len() > 1 && piece.first() == piece.last() {
+            piece.pop();
+        }
+        if piece.len() < 3 {
+            continue;
+        }
+        for (i, &from) in piece.iter().enumerate() {
+            let to = piece[(i + 1) % piece.len()];
+            let reverse = edges.get_mut(&to).and_then(|targets| {
+                let k = targets.iter().position(|&target| target == from)?;
+                Some(targets.swap_remove(k))
+            });
+            if reverse.is_none() {
+                edges.entry(from).or_default().push(to);
+            }
+        }
+    }
+
+    // Chain the remaining boundary edges into rings
+    let mut rings: Vec<Vec<(f64, f64)>> = Vec::new();
+    let starts: Vec<Key> = edges.keys().copied().collect();
+    for start in starts {
+        while let Some(mut next) = edges.get_mut(&start).and_then(Vec::pop) {
+            let mut ring = vec![position(start)];
+            while next != start {
+                ring.push(position(next));
+                let Some(after) = edges.get_mut(&next).and_then(Vec::pop) else {
+                    break;
+                };
+                next = after;
+            }
+            if ring.len() >= 3 {
+                rings.push(ring);
+            }
+        }
+    }
+    assemble_polygons(rings)
+}
+
+/// Group counter-clockwise exterior rings with the clockwise holes inside
+/// them.
+fn assemble_polygons(rings: Vec<Vec<(f64, f64)>>) -> Vec<Polygon> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings.into_iter().partition(|ring| geometry::signed_area(ring) > 0.0);
+    let areas: Vec<f64> = exteriors.iter().map(|ring| geometry::signed_area(ring)).collect();
+    let mut polygons: Vec<Polygon> = exteriors.into_iter().map(|ring| vec![ring]).collect();
+    for hole in holes {
+        // A hole vertex may touch its exterior, so test the middle of an edge
+        let probe = ((hole[0].0 + hole[1].0) / 2.0, (hole[0].1 + hole[1].1) / 2.0);
+        let container = (0..polygons.len())
+            .filter(|&i| geometry::point_in_polygon(probe, &polygons[i][0]))
+            .min_by(|&i, &j| areas[i].total_cmp(&areas[j]));
+        if let Some(i) = container {
+            polygons[i].push(hole);
+        }
+    }
+    polygons
+}
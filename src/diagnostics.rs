@@ -0,0 +1,52 @@
+//! Report of what `triangulate()` did to its input, for `diagnostics=True`.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+/// Why refinement ended, if it ran at all.
+#[derive(Clone, Copy, Default)]
+pub(crate) enum Refinement {
+    #[default]
+    NotRun,
+    Complete,
+    VertexLimit,
+    Timeout,
+}
+
+#[derive(Default)]
+pub(crate) struct Diagnostics {
+    /// Input vertices dropped because an earlier vertex has the same position
+    pub duplicate_vertices: Vec<usize>,
+    /// Rings given clockwise (0 is the outer ring, k the hole k - 1); they
+    /// are meshed as given, since the constraints don't depend on orientation
+    pub clockwise_rings: Vec<usize>,
+    /// Input segments skipped because their end points coincide
+    pub degenerate_segments: Vec<usize>,
+    /// Input segments split into several constraint edges
+    pub split_segments: Vec<usize>,
+    /// Faces left out of the output as holes or outside the outer ring
+    pub excluded_faces: usize,
+    pub refinement: Refinement,
+    /// Vertices added by refinement (and boundary layers and region sizes)
+    pub added_vertices: usize,
+}
+
+impl Diagnostics {
+    pub fn into_dict(self, py: Python) -> HashMap<String, PyObject> {
+        let refinement = match self.refinement {
+            Refinement::NotRun => "not_run",
+            Refinement::Complete => "complete",
+            Refinement::VertexLimit => "vertex_limit",
+            Refinement::Timeout => "timeout",
+        };
+        let mut output = HashMap::new();
+        output.insert("duplicate_vertices".to_string(), self.duplicate_vertices.into_py(py));
+        output.insert("clockwise_rings".to_string(), self.clockwise_rings.into_py(py));
+        output.insert("degenerate_segments".to_string(), self.degenerate_segments.into_py(py));
+        output.insert("split_segments".to_string(), self.split_segments.into_py(py));
+        output.insert("excluded_faces".to_string(), self.excluded_faces.into_py(py));
+        output.insert("refinement".to_string(), refinement.into_py(py));
+        output.insert("added_vertices".to_string(), self.added_vertices.into_py(py));
+        output
+    }
+}
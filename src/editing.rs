@@ -0,0 +1,239 @@
+//! Local mesh edits (edge split, collapse and flip) with validity checks.
+
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::mesh::Mesh;
+
+/// Split an edge at a point along it.
+///
+/// A new vertex is placed at `(1 - t) * edge[0] + t * edge[1]` (z
+/// interpolated too) and each triangle on the edge is split in two. A
+/// constrained edge is replaced by its two constrained halves.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges'
+///     edge (tuple): (i, j) vertex indices of an edge of the mesh
+///     t (float, optional): Position of the new vertex along the edge, in (0, 1). Default: 0.5
+///
+/// Returns:
+///     dict: The edited mesh ('vertices', 'triangles', 'edges' if any) plus
+///         'vertex', the index of the new vertex (always the last one)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)])
+///     >>> mesh = pyspade.split_edge(mesh, mesh['triangles'][0][:2])
+///     >>> print(mesh['vertex'])
+#[pyfunction]
+#[pyo3(signature = (mesh, edge, t=0.5))]
+pub fn split_edge(mut mesh: Mesh, edge: (usize, usize), t: f64) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let vertex = split_edge_impl(&mut mesh, edge, t).map_err(crate::to_py_err)?;
+
+        let mut output = mesh.into_dict(py);
+        output.insert("vertex".to_string(), vertex.into_py(py));
+        Ok(output)
+    })
+}
+
+/// Collapse an edge by merging one endpoint into the other.
+///
+/// The removed endpoint must be free: not on the mesh boundary and not on a
+/// constrained edge. `edge[1]` is removed if it is free, otherwise `edge[0]`.
+/// The collapse is refused if it would invert or flatten a triangle or make
+/// the mesh non-manifold. Vertex indices above the removed one shift down by
+/// one.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges'
+///     edge (tuple): (i, j) vertex indices of an edge of the mesh
+///
+/// Returns:
+///     dict: The edited mesh ('vertices', 'triangles', 'edges' if any) plus
+///         'removed', the index of the removed vertex in the input mesh
+///
+/// Example:
+///     >>> import pyspade
+///     >>> fan = {'vertices': [(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)],
+///     ...        'triangles': [(0, 1, 4), (1, 2, 4), (2, 3, 4), (3, 0, 4)]}
+///     >>> print(pyspade.collapse_edge(fan, (0, 4))['triangles'])
+#[pyfunction]
+pub fn collapse_edge(mut mesh: Mesh, edge: (usize, usize)) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let removed = collapse_edge_impl(&mut mesh, edge).map_err(crate::to_py_err)?;
+
+        let mut output = mesh.into_dict(py);
+        output.insert("removed".to_string(), removed.into_py(py));
+        Ok(output)
+    })
+}
+
+/// Flip an interior edge to the other diagonal of its two triangles.
+///
+/// The flip is refused for boundary and constrained edges and when the two
+/// triangles do not form a strictly convex quad.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges'
+///     edge (tuple): (i, j) vertex indices of an interior edge of the mesh
+///
+/// Returns:
+///     dict: The edited mesh ('vertices', 'triangles', 'edges' if any)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = {'vertices': [(0, 0), (1, 0), (1, 1), (0, 1)], 'triangles': [(0, 1, 2), (0, 2, 3)]}
+///     >>> print(pyspade.flip_edge(mesh, (0, 2))['triangles'])
+#[pyfunction]
+pub fn flip_edge(mut mesh: Mesh, edge: (usize, usize)) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        flip_edge_impl(&mut mesh, edge).map_err(crate::to_py_err)?;
+        Ok(mesh.into_dict(py))
+    })
+}
+
+type Triangle = (usize, usize, usize);
+
+/// Twice the signed area of a triangle (positive when counter-clockwise).
+fn orientation(mesh: &Mesh, (a, b, c): Triangle) -> f64 {
+    let [p, q, r] = [a, b, c].map(|v| mesh.vertices[v]);
+    (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+}
+
+/// Rotate a triangle so the edge `u`-`v` (in either direction) comes first,
+/// returning the rotated triangle.
+fn rotate_to(triangle: Triangle, u: usize, v: usize) -> Option<Triangle> {
+    let (a, b, c) = triangle;
+    [(a, b, c), (b, c, a), (c, a, b)]
+        .into_iter()
+        .find(|&(x, y, _)| (x, y) == (u, v) || (x, y) == (v, u))
+}
+
+fn is_constrained(mesh: &Mesh, (u, v): (usize, usize)) -> bool {
+    mesh.edges.iter().any(|&(a, b)| (a, b) == (u, v) || (a, b) == (v, u))
+}
+
+/// Indices of the triangles containing the edge, after checking it exists.
+fn edge_triangles(mesh: &Mesh, (u, v): (usize, usize)) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+    if u >= mesh.vertices.len() || v >= mesh.vertices.len() || u == v {
+        return Err(format!("invalid edge ({}, {})", u, v).into());
+    }
+    let triangles: Vec<usize> = (0..mesh.triangles.len())
+        .filter(|&t| rotate_to(mesh.triangles[t], u, v).is_some())
+        .collect();
+    if triangles.is_empty() {
+        return Err(format!("edge ({}, {}) is not in the mesh", u, v).into());
+    }
+    Ok(triangles)
+}
+
+pub(crate) fn split_edge_impl(mesh: &mut Mesh, (u, v): (usize, usize), t: f64) -> Result<usize, Box<dyn std::error::Error>> {
+    if t.is_nan() || t <= 0.0 || t >= 1.0 {
+        return Err(format!("t must be in (0, 1), got {}", t).into());
+    }
+    let triangles = edge_triangles(mesh, (u, v))?;
+
+    let (p, q) = (mesh.vertices[u], mesh.vertices[v]);
+    let w = mesh.vertices.len();
+    mesh.vertices.push((p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1), p.2 + t * (q.2 - p.2)));
+    for k in triangles {
+        let (x, y, o) = rotate_to(mesh.triangles[k], u, v).expect("triangle contains the edge");
+        mesh.triangles[k] = (x, w, o);
+        mesh.triangles.push((w, y, o));
+    }
+    if is_constrained(mesh, (u, v)) {
+        mesh.edges.retain(|&(a, b)| (a, b) != (u, v) && (a, b) != (v, u));
+        mesh.edges.extend([(u, w), (w, v)]);
+    }
+    Ok(w)
+}
+
+pub(crate) fn collapse_edge_impl(mesh: &mut Mesh, (u, v): (usize, usize)) -> Result<usize, Box<dyn std::error::Error>> {
+    let on_edge = edge_triangles(mesh, (u, v))?;
+    if is_constrained(mesh, (u, v)) {
+        return Err(format!("edge ({}, {}) is constrained", u, v).into());
+    }
+
+    // Vertices on the boundary or on constrained edges stay where they are
+    let mut fixed: HashSet<usize> = mesh.edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        for (x, y) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((x.min(y), x.max(y))).or_default() += 1;
+        }
+    }
+    fixed.extend(uses.iter().filter(|&(_, &count)| count == 1).flat_map(|(&(a, b), _)| [a, b]));
+    let (keep, remove) = match (fixed.contains(&u), fixed.contains(&v)) {
+        (_, false) => (u, v),
+        (false, true) => (v, u),
+        (true, true) => {
+            return Err(format!("both endpoints of edge ({}, {}) lie on the boundary or a constrained edge", u, v).into());
+        }
+    };
+
+    // Link condition: the endpoints may only share the neighbors opposite the edge
+    let neighbors = |w: usize| -> HashSet<usize> {
+        mesh.triangles
+            .iter()
+            .filter(|&&(a, b, c)| a == w || b == w || c == w)
+            .flat_map(|&(a, b, c)| [a, b, c])
+            .filter(|&x| x != w)
+            .collect()
+    };
+    let shared: HashSet<usize> = neighbors(u).intersection(&neighbors(v)).copied().collect();
+    let opposite: HashSet<usize> = on_edge
+        .iter()
+        .map(|&t| rotate_to(mesh.triangles[t], u, v).expect("triangle contains the edge").2)
+        .collect();
+    if shared != opposite {
+        return Err(format!("collapsing edge ({}, {}) would make the mesh non-manifold", u, v).into());
+    }
+
+    let mut triangles = Vec::with_capacity(mesh.triangles.len());
+    for (t, &triangle) in mesh.triangles.iter().enumerate() {
+        if on_edge.contains(&t) {
+            continue;
+        }
+        let (a, b, c) = triangle;
+        let moved = [a, b, c].map(|x| if x == remove { keep } else { x });
+        let moved = (moved[0], moved[1], moved[2]);
+        if moved != triangle && orientation(mesh, moved) * orientation(mesh, triangle) <= 0.0 {
+            return Err(format!("collapsing edge ({}, {}) would invert or flatten a triangle", u, v).into());
+        }
+        triangles.push(moved);
+    }
+
+    let shift = |x: usize| if x > remove { x - 1 } else { x };
+    mesh.vertices.remove(remove);
+    mesh.triangles = triangles.into_iter().map(|(a, b, c)| (shift(a), shift(b), shift(c))).collect();
+    mesh.edges = mesh.edges.iter().map(|&(a, b)| (shift(a), shift(b))).collect();
+    Ok(remove)
+}
+
+pub(crate) fn flip_edge_impl(mesh: &mut Mesh, (u, v): (usize, usize)) -> Result<(), Box<dyn std::error::Error>> {
+    let on_edge = edge_triangles(mesh, (u, v))?;
+    if on_edge.len() != 2 {
+        return Err(format!("edge ({}, {}) is not an interior edge", u, v).into());
+    }
+    if is_constrained(mesh, (u, v)) {
+        return Err(format!("edge ({}, {}) is constrained", u, v).into());
+    }
+    let (x, y, o1) = rotate_to(mesh.triangles[on_edge[0]], u, v).expect("triangle contains the edge");
+    let (x2, y2, o2) = rotate_to(mesh.triangles[on_edge[1]], u, v).expect("triangle contains the edge");
+    if (x2, y2) != (y, x) {
+        return Err(format!("triangles on edge ({}, {}) are inconsistently oriented", u, v).into());
+    }
+    if edge_triangles(mesh, (o1, o2)).is_ok() {
+        return Err(format!("flipped edge ({}, {}) already exists", o1, o2).into());
+    }
+
+    let sign = orientation(mesh, (x, y, o1));
+    let flipped = [(o1, x, o2), (o2, y, o1)];
+    if flipped.iter().any(|&triangle| orientation(mesh, triangle) * sign <= 0.0) {
+        return Err(format!("edge ({}, {}) is not the diagonal of a convex quad", u, v).into());
+    }
+    mesh.triangles[on_edge[0]] = flipped[0];
+    mesh.triangles[on_edge[1]] = flipped[1];
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Higher-order finite elements built from a linear triangle mesh.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::mesh::Mesh;
+
+/// Convert a mesh of 3-node triangles into 6-node quadratic (P2) triangles.
+///
+/// A node is added at the midpoint of every edge, shared by the triangles on
+/// either side. Nodes are numbered after the corner vertices, in the order
+/// their edges are first met walking the triangles. Each element lists its
+/// corners and then the midpoints of edges (0, 1), (1, 2) and (2, 0), the
+/// ordering used by Gmsh and VTK.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges', e.g. from
+///         `triangulate()`
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': Corner vertices followed by the midpoint nodes
+///         - 'triangles': List of (a, b, c, ab, bc, ca) node indices
+///         - 'edges': The mesh's constrained edges as (i, j, midpoint) 3-node edges, if it has any
+///         - 'mesh_edges': Every edge of the mesh as an (i, j) pair with i < j, in node order
+///         - 'midpoints': Midpoint node of each edge in 'mesh_edges'
+///         - 'num_corners': Number of corner vertices; nodes from this index on are midpoints
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (1, 0), (1, 1), (0, 1)], max_edge_length=0.2)
+///     >>> p2 = pyspade.quadratic_elements(mesh)
+///     >>> print(len(p2['vertices']) - p2['num_corners'], "midpoint nodes")
+#[pyfunction]
+pub fn quadratic_elements(mesh: Mesh) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let num_corners = mesh.vertices.len();
+        let elements = quadratic_elements_impl(&mesh).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), elements.vertices.into_py(py));
+        output.insert("triangles".to_string(), elements.triangles.into_py(py));
+        if !mesh.edges.is_empty() {
+            output.insert("edges".to_string(), elements.edges.into_py(py));
+        }
+        output.insert("mesh_edges".to_string(), elements.mesh_edges.into_py(py));
+        output.insert("midpoints".to_string(), elements.midpoints.into_py(py));
+        output.insert("num_corners".to_string(), num_corners.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct QuadraticElements {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize, usize, usize, usize)>,
+    pub edges: Vec<(usize, usize, usize)>,
+    pub mesh_edges: Vec<(usize, usize)>,
+    pub midpoints: Vec<usize>,
+}
+
+pub(crate) fn quadratic_elements_impl(mesh: &Mesh) -> Result<QuadraticElements, Box<dyn std::error::Error>> {
+    let mut elements = QuadraticElements {
+        vertices: mesh.vertices.clone(),
+        triangles: Vec::with_capacity(mesh.triangles.len()),
+        edges: Vec::with_capacity(mesh.edges.len()),
+        mesh_edges: Vec::new(),
+        midpoints: Vec::new(),
+    };
+    let mut node: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut midpoint = |u: usize, v: usize, elements: &mut QuadraticElements| {
+        let key = (u.min(v), u.max(v));
+        *node.entry(key).or_insert_with(|| {
+            let (p, q) = (elements.vertices[u], elements.vertices[v]);
+            elements.vertices.push(((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0, (p.2 + q.2) / 2.0));
+            elements.mesh_edges.push(key);
+            elements.midpoints.push(elements.vertices.len() - 1);
+            elements.vertices.len() - 1
+        })
+    };
+
+    for &(a, b, c) in &mesh.triangles {
+        if a == b || b == c || c == a {
+            return Err(format!("triangle ({}, {}, {}) repeats a vertex", a, b, c).into());
+        }
+        let (ab, bc, ca) = (midpoint(a, b, &mut elements), midpoint(b, c, &mut elements), midpoint(c, a, &mut elements));
+        elements.triangles.push((a, b, c, ab, bc, ca));
+    }
+    for &(u, v) in &mesh.edges {
+        let m = midpoint(u, v, &mut elements);
+        elements.edges.push((u, v, m));
+    }
+    Ok(elements)
+}
@@ -0,0 +1,207 @@
+//! Differential quantities of scalar fields on triangle meshes.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::connectivity::vertex_adjacency_impl;
+use crate::mesh::Mesh;
+use crate::rendering::vertex_normals_impl;
+
+/// Gradient of a piecewise linear scalar field at every vertex.
+///
+/// With `method="area"` each vertex gets the average of the (constant)
+/// gradients of its triangles, weighted by triangle area. With
+/// `method="least_squares"` a plane through the vertex value is fitted to the
+/// values of its edge neighbors, which is exact for linear fields on any
+/// mesh; vertices whose neighbors do not span the plane fall back to the area
+/// average. Vertices used by no triangle get (NaN, NaN).
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles'
+///     values (list, optional): One value per vertex. Default: the z coordinates
+///     method (str, optional): "area" or "least_squares". Default: "area"
+///
+/// Returns:
+///     list: (df/dx, df/dy) per vertex
+///
+/// Example:
+///     >>> import numpy as np
+///     >>> import pyspade
+///     >>> terrain = pyspade.tin(points_xyz)
+///     >>> gradients = np.array(pyspade.vertex_gradients(terrain))
+///     >>> slope_deg = np.degrees(np.arctan(np.hypot(gradients[:, 0], gradients[:, 1])))
+#[pyfunction]
+#[pyo3(signature = (mesh, values=None, method="area"))]
+pub fn vertex_gradients(mesh: Mesh, values: Option<Vec<f64>>, method: &str) -> PyResult<Vec<(f64, f64)>> {
+    let values = values.unwrap_or_else(|| mesh.vertices.iter().map(|&(_, _, z)| z).collect());
+    vertex_gradients_impl(&mesh, &values, method).map_err(crate::to_py_err)
+}
+
+pub(crate) fn vertex_gradients_impl(
+    mesh: &Mesh,
+    values: &[f64],
+    method: &str,
+) -> Result<Vec<(f64, f64)>, Box<dyn std::error::Error>> {
+    let least_squares = match method {
+        "area" => false,
+        "least_squares" => true,
+        _ => return Err(format!("unknown method '{}', expected 'area' or 'least_squares'", method).into()),
+    };
+    if values.len() != mesh.vertices.len() {
+        return Err(format!("expected {} values (one per vertex), got {}", mesh.vertices.len(), values.len()).into());
+    }
+
+    let mut sums = vec![(0.0, 0.0, 0.0); mesh.vertices.len()];
+    for &(a, b, c) in &mesh.triangles {
+        let Some((gradient, area)) = triangle_gradient(mesh, values, [a, b, c]) else {
+            continue;
+        };
+        for v in [a, b, c] {
+            let sum: &mut (f64, f64, f64) = &mut sums[v];
+            *sum = (sum.0 + area * gradient.0, sum.1 + area * gradient.1, sum.2 + area);
+        }
+    }
+    let mut gradients: Vec<(f64, f64)> = sums
+        .into_iter()
+        .map(|(gx, gy, area)| if area > 0.0 { (gx / area, gy / area) } else { (f64::NAN, f64::NAN) })
+        .collect();
+
+    if least_squares {
+        let (offsets, indices) = vertex_adjacency_impl(mesh);
+        for (v, gradient) in gradients.iter_mut().enumerate() {
+            let (x, y, _) = mesh.vertices[v];
+            // Normal equations of min sum (g . d - df)^2 over the neighbors
+            let (mut sxx, mut sxy, mut syy, mut sxf, mut syf) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for &n in &indices[offsets[v]..offsets[v + 1]] {
+                let (dx, dy) = (mesh.vertices[n].0 - x, mesh.vertices[n].1 - y);
+                let df = values[n] - values[v];
+                sxx += dx * dx;
+                sxy += dx * dy;
+                syy += dy * dy;
+                sxf += dx * df;
+                syf += dy * df;
+            }
+            let det = sxx * syy - sxy * sxy;
+            if det > 1e-12 * (sxx + syy) * (sxx + syy) {
+                *gradient = ((syy * sxf - sxy * syf) / det, (sxx * syf - sxy * sxf) / det);
+            }
+        }
+    }
+    Ok(gradients)
+}
+
+/// Mean and Gaussian curvature at every vertex of a 2.5D mesh.
+///
+/// Uses the discrete operators over each vertex's 1-ring: Gaussian curvature
+/// is the angle defect (2π minus the corner angles) and mean curvature half
+/// the cotangent Laplacian of the position along the vertex normal, both per
+/// unit of the vertex's area (a third of its triangles' area). Mean curvature
+/// is positive on ridges and hilltops and negative in valleys and pits for
+/// counter-clockwise triangles. Sharp breaklines show up as large |mean|.
+/// Boundary vertices and vertices used by no triangle get NaN, as their
+/// 1-ring is incomplete.
+///
+/// Args:
+///     mesh (dict): Mesh with (x, y, z) 'vertices' and 'triangles'
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'mean': Mean curvature per vertex (1/length)
+///         - 'gaussian': Gaussian curvature per vertex (1/length²)
+///
+/// Example:
+///     >>> import numpy as np
+///     >>> import pyspade
+///     >>> terrain = pyspade.tin(points_xyz)
+///     >>> curvature = pyspade.vertex_curvature(terrain)
+///     >>> breaklines = np.abs(curvature['mean']) > 0.5
+#[pyfunction]
+pub fn vertex_curvature(mesh: Mesh) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (mean, gaussian) = vertex_curvature_impl(&mesh);
+
+        let mut output = HashMap::new();
+        output.insert("mean".to_string(), mean.into_py(py));
+        output.insert("gaussian".to_string(), gaussian.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn vertex_curvature_impl(mesh: &Mesh) -> (Vec<f64>, Vec<f64>) {
+    let n = mesh.vertices.len();
+    let sub = |a: usize, b: usize| {
+        let (p, q) = (mesh.vertices[a], mesh.vertices[b]);
+        [p.0 - q.0, p.1 - q.1, p.2 - q.2]
+    };
+
+    let mut angles = vec![0.0; n];
+    let mut areas = vec![0.0; n];
+    let mut laplacian = vec![[0.0; 3]; n];
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        let area = 0.5 * norm(cross(sub(b, a), sub(c, a)));
+        for (k, i, j) in [(a, b, c), (b, c, a), (c, a, b)] {
+            *uses.entry((i.min(j), i.max(j))).or_default() += 1;
+            // Angle at corner k, opposite the edge i-j
+            let (u, v) = (sub(i, k), sub(j, k));
+            let (sin, cos) = (norm(cross(u, v)), dot(u, v));
+            angles[k] += sin.atan2(cos);
+            areas[k] += area / 3.0;
+            if sin > 0.0 {
+                let weight = cos / sin;
+                let d = sub(j, i);
+                for (sum, delta) in laplacian[i].iter_mut().zip(d) {
+                    *sum += weight * delta;
+                }
+                for (sum, delta) in laplacian[j].iter_mut().zip(d) {
+                    *sum -= weight * delta;
+                }
+            }
+        }
+    }
+
+    let mut interior: Vec<bool> = areas.iter().map(|&area| area > 0.0).collect();
+    for ((i, j), count) in uses {
+        if count == 1 {
+            interior[i] = false;
+            interior[j] = false;
+        }
+    }
+
+    let normals = vertex_normals_impl(&mesh.vertices, &mesh.triangles);
+    let mut mean = vec![f64::NAN; n];
+    let mut gaussian = vec![f64::NAN; n];
+    for v in (0..n).filter(|&v| interior[v]) {
+        let normal = [normals[v].0, normals[v].1, normals[v].2];
+        // Laplace-Beltrami of the position is -2 H n
+        mean[v] = -0.25 * dot(laplacian[v], normal) / areas[v];
+        gaussian[v] = (2.0 * std::f64::consts::PI - angles[v]) / areas[v];
+    }
+    (mean, gaussian)
+}
+
+fn cross(u: [f64; 3], v: [f64; 3]) -> [f64; 3] {
+    [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]]
+}
+
+fn dot(u: [f64; 3], v: [f64; 3]) -> f64 {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+fn norm(u: [f64; 3]) -> f64 {
+    dot(u, u).sqrt()
+}
+
+/// Gradient of the linear interpolant of `values` over a triangle, with the
+/// triangle's area; `None` for a degenerate triangle.
+pub(crate) fn triangle_gradient(mesh: &Mesh, values: &[f64], [a, b, c]: [usize; 3]) -> Option<((f64, f64), f64)> {
+    let (pa, pb, pc) = (mesh.vertices[a], mesh.vertices[b], mesh.vertices[c]);
+    let (ux, uy, vx, vy) = (pb.0 - pa.0, pb.1 - pa.1, pc.0 - pa.0, pc.1 - pa.1);
+    let det = ux * vy - uy * vx;
+    if det == 0.0 {
+        return None;
+    }
+    let (df1, df2) = (values[b] - values[a], values[c] - values[a]);
+    Some((((df1 * vy - df2 * uy) / det, (df2 * ux - df1 * vx) / det), 0.5 * det.abs()))
+}
@@ -0,0 +1,89 @@
+//! Small planar geometry helpers shared by the analysis functions.
+
+/// Iterate over the edges `(p, q)` of a closed ring, including the closing edge.
+pub(crate) fn ring_edges(ring: &[(f64, f64)]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+    ring.iter()
+        .copied()
+        .zip(ring.iter().copied().cycle().skip(1))
+}
+
+/// Signed area of a simple polygon (positive for counter-clockwise rings).
+pub(crate) fn signed_area(polygon: &[(f64, f64)]) -> f64 {
+    let n = polygon.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let sum: f64 = ring_edges(polygon)
+        .map(|((x0, y0), (x1, y1))| x0 * y1 - x1 * y0)
+        .sum();
+    0.5 * sum
+}
+
+/// Area centroid of a simple polygon.
+///
+/// Falls back to the vertex average for degenerate (zero-area) polygons.
+pub(crate) fn centroid(polygon: &[(f64, f64)]) -> (f64, f64) {
+    let n = polygon.len();
+    if n == 0 {
+        return (f64::NAN, f64::NAN);
+    }
+    let area = signed_area(polygon);
+    if area.abs() <= f64::EPSILON {
+        let (sx, sy) = polygon
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+        return (sx / n as f64, sy / n as f64);
+    }
+    let mut cx = 0.0;
+    let mut cy = 0.0;
+    for ((x0, y0), (x1, y1)) in ring_edges(polygon) {
+        let cross = x0 * y1 - x1 * y0;
+        cx += (x0 + x1) * cross;
+        cy += (y0 + y1) * cross;
+    }
+    (cx / (6.0 * area), cy / (6.0 * area))
+}
+
+/// Clip a polygon against the half-plane `a * x + b * y <= c`
+/// (one Sutherland-Hodgman step).
+pub(crate) fn clip_half_plane(polygon: &[(f64, f64)], a: f64, b: f64, c: f64) -> Vec<(f64, f64)> {
+    let mut output = Vec::with_capacity(polygon.len() + 1);
+    for (p, q) in ring_edges(polygon) {
+        let dp = a * p.0 + b * p.1 - c;
+        let dq = a * q.0 + b * q.1 - c;
+        if dp <= 0.0 {
+            output.push(p);
+        }
+        if (dp < 0.0 && dq > 0.0) || (dp > 0.0 && dq < 0.0) {
+            let t = dp / (dp - dq);
+            output.push((p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1)));
+        }
+    }
+    output
+}
+
+/// Round `point` to the nearest node of the uniform grid with spacing `grid`.
+pub(crate) fn snap((x, y): (f64, f64), grid: f64) -> (f64, f64) {
+    ((x / grid).round() * grid, (y / grid).round() * grid)
+}
+
+/// Axis-aligned bounding box `(xmin, ymin, xmax, ymax)` of a point set.
+pub(crate) fn bounding_box(points: &[(f64, f64)]) -> Option<(f64, f64, f64, f64)> {
+    let (&(x0, y0), rest) = points.split_first()?;
+    Some(rest.iter().fold((x0, y0, x0, y0), |(xmin, ymin, xmax, ymax), &(x, y)| {
+        (xmin.min(x), ymin.min(y), xmax.max(x), ymax.max(y))
+    }))
+}
+
+/// Even-odd point-in-polygon test. Points exactly on the boundary may be
+/// classified either way.
+pub(crate) fn point_in_polygon(point: (f64, f64), polygon: &[(f64, f64)]) -> bool {
+    let (px, py) = point;
+    let mut inside = false;
+    for ((xi, yi), (xj, yj)) in ring_edges(polygon) {
+        if (yi > py) != (yj > py) && px < (xj - xi) * (py - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+    }
+    inside
+}
@@ -0,0 +1,403 @@
+use pyo3::prelude::*;
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::arrays::{float64_array, int64_array};
+use crate::voronoi::{delaunay, Delaunay};
+
+/// Compute the Euclidean minimum spanning tree of a point set.
+///
+/// The EMST is a subgraph of the Delaunay triangulation, so Kruskal's
+/// algorithm only has to consider the O(n) Delaunay edges.
+///
+/// Args:
+///     points (list): Input points as list of (x, y) tuples
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'edges': List of (i, j) input point indices, one per tree edge
+///         - 'lengths': List of edge lengths
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.emst([(0, 0), (1, 0), (5, 0), (5, 1)])
+///     >>> print(result['edges'], sum(result['lengths']))
+#[pyfunction]
+pub fn emst(points: Vec<(f64, f64)>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = emst_impl(&points).map_err(crate::to_py_err)?;
+        Ok(result.into_dict(py))
+    })
+}
+
+#[derive(Default)]
+pub(crate) struct EdgeList {
+    pub edges: Vec<(usize, usize)>,
+    pub lengths: Vec<f64>,
+}
+
+impl EdgeList {
+    pub fn push(&mut self, a: usize, b: usize, length: f64) {
+        self.edges.push((a, b));
+        self.lengths.push(length);
+    }
+
+    pub fn into_dict(self, py: Python<'_>) -> HashMap<String, PyObject> {
+        let mut output = HashMap::new();
+        output.insert("edges".to_string(), self.edges.into_py(py));
+        output.insert("lengths".to_string(), self.lengths.into_py(py));
+        output
+    }
+}
+
+pub(crate) fn emst_impl(points: &[(f64, f64)]) -> Result<EdgeList, Box<dyn std::error::Error>> {
+    let (dt, handles) = delaunay(points)?;
+    let input_index = input_indices(&dt, &handles);
+
+    let mut candidates: Vec<(f64, usize, usize)> = dt
+        .undirected_edges()
+        .map(|edge| {
+            let [a, b] = edge.vertices();
+            (distance(a.position(), b.position()), a.fix().index(), b.fix().index())
+        })
+        .collect();
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut components = DisjointSet::new(dt.num_vertices());
+    let mut result = EdgeList::default();
+    for (length, a, b) in candidates {
+        if components.union(a, b) {
+            result.push(input_index[a], input_index[b], length);
+        }
+    }
+    Ok(result)
+}
+
+/// Compute the k nearest neighbors of every input point.
+///
+/// Neighbors are found by a best-first walk over the Delaunay graph: the
+/// i-th nearest neighbor of a point is always adjacent to the point itself or
+/// to one of its i-1 nearer neighbors, so no separate spatial index is needed.
+///
+/// Args:
+///     points (list): Input points as list of (x, y) tuples
+///     k (int): Number of neighbors per point. Every point has N - 1 others, so k must be
+///         at most N - 1; a larger k raises a RuntimeError rather than padding the rows
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'indices': (N, k) int64 NumPy array of neighbor indices, nearest first
+///         - 'distances': (N, k) float64 NumPy array of the corresponding distances
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.knn_graph([(0, 0), (1, 0), (0, 1), (3, 3)], k=2)
+///     >>> print(result['indices'][0])
+#[pyfunction]
+pub fn knn_graph(points: Vec<(f64, f64)>, k: usize) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (indices, distances) = knn_graph_impl(&points, k).map_err(crate::to_py_err)?;
+
+        let indices: Vec<i64> = indices.into_iter().flatten().map(|index| index as i64).collect();
+        let shape = [points.len(), k];
+
+        let mut output = HashMap::new();
+        output.insert("indices".to_string(), int64_array(py, &indices, &shape)?);
+        output.insert("distances".to_string(), float64_array(py, &distances.concat(), &shape)?);
+
+        Ok(output)
+    })
+}
+
+pub(crate) type Neighbors = (Vec<Vec<usize>>, Vec<Vec<f64>>);
+
+pub(crate) fn knn_graph_impl(points: &[(f64, f64)], k: usize) -> Result<Neighbors, Box<dyn std::error::Error>> {
+    if k >= points.len() {
+        return Err(format!("k must be less than the number of points ({})", points.len()).into());
+    }
+    let (dt, handles) = delaunay(points)?;
+
+    // Input points sharing a vertex are each other's neighbors at distance 0
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); dt.num_vertices()];
+    for (i, handle) in handles.iter().enumerate() {
+        members[handle.index()].push(i);
+    }
+
+    let mut indices = Vec::with_capacity(points.len());
+    let mut distances = Vec::with_capacity(points.len());
+    let mut visited = vec![usize::MAX; dt.num_vertices()];
+
+    for (i, &handle) in handles.iter().enumerate() {
+        let mut row_indices = Vec::with_capacity(k);
+        let mut row_distances = Vec::with_capacity(k);
+        walk_by_distance(&dt, handle, &mut visited, i, |dist, v| {
+            for &j in &members[v] {
+                if j != i && row_indices.len() < k {
+                    row_indices.push(j);
+                    row_distances.push(dist);
+                }
+            }
+            row_indices.len() < k
+        });
+        indices.push(row_indices);
+        distances.push(row_distances);
+    }
+    Ok((indices, distances))
+}
+
+/// Extract the Gabriel graph of a point set.
+///
+/// A Delaunay edge belongs to the Gabriel graph if the circle having the edge
+/// as diameter contains no other input point.
+///
+/// Args:
+///     points (list): Input points as list of (x, y) tuples
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'edges': List of (i, j) input point indices
+///         - 'lengths': List of edge lengths
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.gabriel_graph([(0, 0), (2, 0), (1, 0.2), (1, 3)])
+///     >>> print(result['edges'])
+#[pyfunction]
+pub fn gabriel_graph(points: Vec<(f64, f64)>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = gabriel_graph_impl(&points).map_err(crate::to_py_err)?;
+        Ok(result.into_dict(py))
+    })
+}
+
+/// Extract the relative neighborhood graph (RNG) of a point set.
+///
+/// An edge (a, b) belongs to the RNG if no other point is closer to both a
+/// and b than they are to each other. The RNG is a subgraph of the Gabriel
+/// graph and contains the Euclidean minimum spanning tree.
+///
+/// Args:
+///     points (list): Input points as list of (x, y) tuples
+///     method (str, optional): "exact" for the true RNG, or "urquhart" for the
+///         Urquhart graph (Delaunay minus the longest edge of every triangle),
+///         a cheaper superset of the RNG. Default: "exact"
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'edges': List of (i, j) input point indices
+///         - 'lengths': List of edge lengths
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.relative_neighborhood_graph([(0, 0), (2, 0), (1, 0.5), (1, 3)])
+///     >>> print(result['edges'])
+#[pyfunction]
+#[pyo3(signature = (points, method="exact"))]
+pub fn relative_neighborhood_graph(points: Vec<(f64, f64)>, method: &str) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = match method {
+            "exact" => relative_neighborhood_graph_impl(&points),
+            "urquhart" => urquhart_graph_impl(&points),
+            _ => Err(format!("unknown method '{}', expected 'exact' or 'urquhart'", method).into()),
+        }
+        .map_err(crate::to_py_err)?;
+        Ok(result.into_dict(py))
+    })
+}
+
+pub(crate) fn gabriel_graph_impl(points: &[(f64, f64)]) -> Result<EdgeList, Box<dyn std::error::Error>> {
+    let (dt, handles) = delaunay(points)?;
+    let input_index = input_indices(&dt, &handles);
+
+    let mut result = EdgeList::default();
+    for edge in dt.undirected_edges() {
+        let directed = edge.as_directed();
+        let [a, b] = edge.vertices();
+        let (pa, pb) = (a.position(), b.position());
+        // Only the two opposite vertices can lie inside the diametral circle
+        let blocked = [directed.opposite_vertex(), directed.rev().opposite_vertex()]
+            .into_iter()
+            .flatten()
+            .any(|c| {
+                let pc = c.position();
+                (pa.x - pc.x) * (pb.x - pc.x) + (pa.y - pc.y) * (pb.y - pc.y) < 0.0
+            });
+        if !blocked {
+            result.push(input_index[a.fix().index()], input_index[b.fix().index()], distance(pa, pb));
+        }
+    }
+    Ok(result)
+}
+
+pub(crate) fn relative_neighborhood_graph_impl(
+    points: &[(f64, f64)],
+) -> Result<EdgeList, Box<dyn std::error::Error>> {
+    let (dt, handles) = delaunay(points)?;
+    let input_index = input_indices(&dt, &handles);
+    let mut visited = vec![usize::MAX; dt.num_vertices()];
+
+    let mut result = EdgeList::default();
+    for (stamp, edge) in dt.undirected_edges().enumerate() {
+        let [a, b] = edge.vertices();
+        let (pa, pb) = (a.position(), b.position());
+        let length = distance(pa, pb);
+        // Any point in the lune of (a, b) is closer to a than b is, so it is
+        // enough to walk outwards from a up to that radius
+        let mut blocked = false;
+        walk_by_distance(&dt, a.fix(), &mut visited, stamp, |dist, v| {
+            if dist >= length {
+                return false;
+            }
+            let c = dt.vertex(FixedVertexHandle::from_index(v)).position();
+            if v != a.fix().index() && distance(pb, c) < length {
+                blocked = true;
+            }
+            !blocked
+        });
+        if !blocked {
+            result.push(input_index[a.fix().index()], input_index[b.fix().index()], length);
+        }
+    }
+    Ok(result)
+}
+
+pub(crate) fn urquhart_graph_impl(points: &[(f64, f64)]) -> Result<EdgeList, Box<dyn std::error::Error>> {
+    let (dt, handles) = delaunay(points)?;
+    let input_index = input_indices(&dt, &handles);
+
+    let mut removed = HashSet::new();
+    for face in dt.inner_faces() {
+        let longest = face
+            .adjacent_edges()
+            .into_iter()
+            .max_by(|a, b| a.length_2().total_cmp(&b.length_2()));
+        if let Some(edge) = longest {
+            removed.insert(edge.as_undirected().fix());
+        }
+    }
+
+    let mut result = EdgeList::default();
+    for edge in dt.undirected_edges() {
+        if removed.contains(&edge.fix()) {
+            continue;
+        }
+        let [a, b] = edge.vertices();
+        result.push(
+            input_index[a.fix().index()],
+            input_index[b.fix().index()],
+            distance(a.position(), b.position()),
+        );
+    }
+    Ok(result)
+}
+
+/// Visit vertices in order of increasing distance from `origin` (starting with
+/// `origin` itself at distance 0) until `visit` returns false.
+///
+/// `visited` is scratch space of length `num_vertices()` that may be reused
+/// across calls as long as every call passes a distinct `stamp`.
+pub(crate) fn walk_by_distance(
+    dt: &Delaunay,
+    origin: FixedVertexHandle,
+    visited: &mut [usize],
+    stamp: usize,
+    mut visit: impl FnMut(f64, usize) -> bool,
+) {
+    let position = dt.vertex(origin).position();
+    let mut queue = BinaryHeap::new();
+    queue.push(Candidate(0.0, origin.index()));
+    visited[origin.index()] = stamp;
+
+    while let Some(Candidate(dist, v)) = queue.pop() {
+        if !visit(dist, v) {
+            return;
+        }
+        for edge in dt.vertex(FixedVertexHandle::from_index(v)).out_edges() {
+            let n = edge.to();
+            let slot = n.fix().index();
+            if visited[slot] != stamp {
+                visited[slot] = stamp;
+                queue.push(Candidate(distance(position, n.position()), slot));
+            }
+        }
+    }
+}
+
+/// Min-heap entry ordered by distance.
+struct Candidate(f64, usize);
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+pub(crate) fn distance(a: Point2<f64>, b: Point2<f64>) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Map each triangulation vertex index to the first input point that
+/// produced it (duplicate input points collapse into one vertex).
+pub(crate) fn input_indices(dt: &Delaunay, handles: &[FixedVertexHandle]) -> Vec<usize> {
+    let mut input_index = vec![usize::MAX; dt.num_vertices()];
+    for (i, handle) in handles.iter().enumerate() {
+        let slot = &mut input_index[handle.index()];
+        if *slot == usize::MAX {
+            *slot = i;
+        }
+    }
+    input_index
+}
+
+/// Union-find with path halving and union by size.
+pub(crate) struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merge the sets containing `a` and `b`; returns false if they were
+    /// already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
@@ -0,0 +1,337 @@
+//! Reusable linear interpolation over a fixed triangulation.
+
+use pyo3::prelude::*;
+use spade::handles::{FixedFaceHandle, InnerTag};
+use spade::{FixedVertexHandle, Point2, PositionInTriangulation, Triangulation};
+use std::collections::HashSet;
+
+use crate::arrays::{float64_array, PointArray, PointArrayXYZ, ValueArray};
+use crate::mesh::Mesh;
+use crate::regions::{add_constraint_checked, Cdt};
+use crate::sizing::{distance_2, segment_distance_2};
+use crate::terrain::located_weights;
+use crate::to_py_err;
+
+/// Value given to query points outside the interpolation domain.
+#[derive(Clone, Copy)]
+pub(crate) enum Outside {
+    Nan,
+    /// The value of the closest input point
+    Nearest,
+    Constant(f64),
+}
+
+impl Outside {
+    /// Parse the `outside` and `fill_value` arguments of the interpolation
+    /// functions.
+    pub(crate) fn parse(outside: &str, fill_value: Option<f64>) -> Result<Self, Box<dyn std::error::Error>> {
+        match (outside, fill_value) {
+            ("nan", None) => Ok(Outside::Nan),
+            ("nearest", None) => Ok(Outside::Nearest),
+            ("constant", Some(value)) => Ok(Outside::Constant(value)),
+            ("constant", None) => Err("outside='constant' requires a fill_value".into()),
+            ("nan" | "nearest", Some(_)) => Err("fill_value only applies to outside='constant'".into()),
+            _ => Err(format!("unknown outside '{}', expected 'nan', 'nearest' or 'constant'", outside).into()),
+        }
+    }
+}
+
+/// Interpolates values given at scattered points or mesh vertices, built once
+/// and queried many times.
+///
+/// The triangulation is built when the interpolator is created. Each call
+/// only locates the query points, starting every search from where the
+/// previous point was found, so queries in scan order (raster cells, profile
+/// samples) are cheap. Calls can pass new values for the same points, e.g.
+/// one time step of a series at a time, without rebuilding anything. Points
+/// at an already inserted position are skipped; the first one wins.
+///
+/// Values may have several channels, given as an (n, c) array, e.g.
+/// elevation, temperature and pollutant concentration side by side. Every
+/// query point is then located once for all channels and the results are
+/// (m, c) rows.
+///
+/// Values are interpolated linearly in the triangle containing the query
+/// point. Points outside the convex hull of the input points, or outside the
+/// triangles of a mesh, get a value chosen by `outside`.
+///
+/// Args:
+///     points (array): (n, 2) or (n, 3) NumPy array or list of (x, y[, z]) tuples
+///     values (array, optional): One value per point, or an (n, c) array of c values per point.
+///         Default: the z coordinates
+///     outside (str, optional): Value outside the domain: "nan", "nearest" (the value of the
+///         closest input point) or "constant" (fill_value). Default: "nan"
+///     fill_value (float, optional): Value outside the domain for outside="constant"
+///
+/// Example:
+///     >>> import pyspade
+///     >>> interpolator = pyspade.Interpolator(stations, values=temperature[0])
+///     >>> grid = interpolator(cell_centers)
+///     >>> series = [interpolator(cell_centers, values=step) for step in temperature]
+#[pyclass]
+pub struct Interpolator {
+    cdt: Cdt,
+    /// Triangulation vertex of each input point
+    handles: Vec<FixedVertexHandle>,
+    /// Values per triangulation vertex, row by row
+    values: Vec<f64>,
+    /// Channels per value, `None` for scalars
+    channels: Option<usize>,
+    /// Faces covered by the input mesh, by face index (all faces for points)
+    inside: Option<Vec<bool>>,
+    outside: Outside,
+}
+
+#[pymethods]
+impl Interpolator {
+    #[new]
+    #[pyo3(signature = (points, values=None, outside="nan", fill_value=None))]
+    fn new(
+        py: Python<'_>,
+        points: PointArrayXYZ,
+        values: Option<ValueArray>,
+        outside: &str,
+        fill_value: Option<f64>,
+    ) -> PyResult<Self> {
+        let outside = Outside::parse(outside, fill_value).map_err(to_py_err)?;
+        let values = values.unwrap_or_else(|| heights(&points.0));
+        py.allow_threads(|| Interpolator::build(&points.0, &[], &values, false, outside).map_err(|e| e.to_string()))
+            .map_err(|e| to_py_err(e.into()))
+    }
+
+    /// Interpolator over the triangles of a mesh.
+    ///
+    /// Args:
+    ///     mesh (dict): Mesh with 'vertices' and 'triangles'
+    ///     values (array, optional): One value per vertex, or an (n, c) array of c values per
+    ///         vertex. Default: the z coordinates
+    ///     outside (str, optional): Value outside the mesh, as for `Interpolator()`. Default: "nan"
+    ///     fill_value (float, optional): Value outside the mesh for outside="constant"
+    ///
+    /// Returns:
+    ///     Interpolator: Interpolator that follows the mesh triangles exactly
+    #[staticmethod]
+    #[pyo3(signature = (mesh, values=None, outside="nan", fill_value=None))]
+    fn from_mesh(
+        py: Python<'_>,
+        mesh: Mesh,
+        values: Option<ValueArray>,
+        outside: &str,
+        fill_value: Option<f64>,
+    ) -> PyResult<Self> {
+        let outside = Outside::parse(outside, fill_value).map_err(to_py_err)?;
+        let values = values.unwrap_or_else(|| heights(&mesh.vertices));
+        py.allow_threads(|| {
+            Interpolator::build(&mesh.vertices, &mesh.triangles, &values, true, outside).map_err(|e| e.to_string())
+        })
+        .map_err(|e| to_py_err(e.into()))
+    }
+
+    /// Interpolate at query points.
+    ///
+    /// Args:
+    ///     points (array): (n, 2) NumPy array or list of (x, y) tuples
+    ///     values (array, optional): Values at the input points to use for this call
+    ///         instead of the stored ones, with any number of channels. Default: None
+    ///
+    /// Returns:
+    ///     numpy.ndarray: Interpolated float64 value per query point, as an (n, c) array for
+    ///         multi-channel values (NaN outside the domain unless `outside` says otherwise)
+    #[pyo3(signature = (points, values=None))]
+    fn __call__(&self, py: Python<'_>, points: PointArray, values: Option<ValueArray>) -> PyResult<PyObject> {
+        let (result, channels) = py
+            .allow_threads(|| {
+                let Some(values) = values else {
+                    return Ok((self.interpolate(&points.0, &self.values, self.channels), self.channels));
+                };
+                let scattered = self.scatter(&values)?;
+                Ok((self.interpolate(&points.0, &scattered, values.channels), values.channels))
+            })
+            .map_err(|e: String| to_py_err(e.into()))?;
+
+        match channels {
+            Some(channels) => float64_array(py, &result, &[points.0.len(), channels]),
+            None => float64_array(py, &result, &[points.0.len()]),
+        }
+    }
+
+    /// Number of distinct input points.
+    fn __len__(&self) -> usize {
+        self.cdt.num_vertices()
+    }
+}
+
+impl Interpolator {
+    /// Triangulate `points`, constraining the edges of `triangles` when
+    /// `from_mesh` so that the mesh triangles are reproduced.
+    fn build(
+        points: &[(f64, f64, f64)],
+        triangles: &[(usize, usize, usize)],
+        values: &ValueArray,
+        from_mesh: bool,
+        outside: Outside,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut cdt = Cdt::default();
+        let handles = points
+            .iter()
+            .map(|&(x, y, _)| cdt.insert(Point2::new(x, y)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if cdt.num_inner_faces() == 0 {
+            return Err("need at least 3 points that are not collinear".into());
+        }
+
+        let inside = if from_mesh {
+            let mut corners = HashSet::with_capacity(triangles.len());
+            for &(a, b, c) in triangles {
+                let [a, b, c] = [a, b, c].map(|v| handles.get(v).copied().ok_or("triangle index out of range"));
+                let (a, b, c) = (a?, b?, c?);
+                for (u, v) in [(a, b), (b, c), (c, a)] {
+                    add_constraint_checked(&mut cdt, u, v)?;
+                }
+                let mut key = [a.index(), b.index(), c.index()];
+                key.sort_unstable();
+                corners.insert(key);
+            }
+            let mut inside = vec![false; cdt.num_all_faces()];
+            for face in cdt.inner_faces() {
+                let mut key = face.vertices().map(|v| v.fix().index());
+                key.sort_unstable();
+                inside[face.fix().index()] = corners.contains(&key);
+            }
+            Some(inside)
+        } else {
+            None
+        };
+
+        let mut interpolator = Interpolator {
+            cdt,
+            handles,
+            values: Vec::new(),
+            channels: values.channels,
+            inside,
+            outside,
+        };
+        interpolator.values = interpolator.scatter(values)?;
+        Ok(interpolator)
+    }
+
+    /// Values per input point as values per triangulation vertex.
+    fn scatter(&self, values: &ValueArray) -> Result<Vec<f64>, String> {
+        let channels = values.channels.unwrap_or(1);
+        if channels == 0 {
+            return Err("values need at least one channel".to_string());
+        }
+        if values.len() != self.handles.len() {
+            return Err(format!("expected {} values (one per point), got {}", self.handles.len(), values.len()));
+        }
+        let mut scattered = vec![f64::NAN; self.cdt.num_vertices() * channels];
+        // The first point at a position wins
+        for (handle, row) in self.handles.iter().zip(values.values.chunks_exact(channels)).rev() {
+            scattered[handle.index() * channels..][..channels].copy_from_slice(row);
+        }
+        Ok(scattered)
+    }
+
+    /// Interpolated values at `points`, row by row.
+    fn interpolate(&self, points: &[(f64, f64)], values: &[f64], channels: Option<usize>) -> Vec<f64> {
+        let channels = channels.unwrap_or(1);
+        let mut output = Vec::with_capacity(points.len() * channels);
+        let mut hint = FixedVertexHandle::from_index(0);
+        for &(x, y) in points {
+            let point = Point2::new(x, y);
+            let location = self.cdt.locate_with_hint(point, hint);
+            let covered = match location {
+                PositionInTriangulation::OnFace(face) => {
+                    hint = self.cdt.face(face).vertices()[0].fix();
+                    self.covers(face.index())
+                }
+                PositionInTriangulation::OnEdge(edge) => {
+                    let edge = self.cdt.directed_edge(edge);
+                    hint = edge.from().fix();
+                    [edge.face(), edge.rev().face()]
+                        .iter()
+                        .any(|face| face.as_inner().is_some_and(|face| self.covers(face.fix().index())))
+                }
+                PositionInTriangulation::OnVertex(vertex) => {
+                    hint = vertex;
+                    true
+                }
+                PositionInTriangulation::OutsideOfConvexHull(edge) => {
+                    hint = self.cdt.directed_edge(edge).from().fix();
+                    false
+                }
+                PositionInTriangulation::NoTriangulation => false,
+            };
+            match (self.outside, located_weights(&self.cdt, point, location)) {
+                (_, Some(weights)) if covered => output.extend(
+                    (0..channels).map(|k| weights.iter().map(|&(v, w)| w * values[v * channels + k]).sum::<f64>()),
+                ),
+                (Outside::Nearest, _) => {
+                    let v = self.nearest_vertex(point, location).index();
+                    output.extend_from_slice(&values[v * channels..][..channels]);
+                }
+                (Outside::Constant(value), _) => output.resize(output.len() + channels, value),
+                _ => output.resize(output.len() + channels, f64::NAN),
+            }
+        }
+        output
+    }
+
+    /// Vertex closest to `point` (the lowest index among equally close ones).
+    ///
+    /// Walking to ever closer neighbors can get stuck behind a constraint
+    /// edge, so this searches outward from the face at `location` instead,
+    /// across every edge closer to `point` than the best vertex so far. The
+    /// faces meeting that shrinking disk are connected, so none is missed.
+    fn nearest_vertex(&self, point: Point2<f64>, location: PositionInTriangulation) -> FixedVertexHandle {
+        let start = match location {
+            PositionInTriangulation::OnVertex(vertex) => return vertex,
+            PositionInTriangulation::OnFace(face) => Some(face),
+            PositionInTriangulation::OnEdge(edge) | PositionInTriangulation::OutsideOfConvexHull(edge) => {
+                let edge = self.cdt.directed_edge(edge);
+                [edge.face(), edge.rev().face()].iter().find_map(|face| face.as_inner()).map(|face| face.fix())
+            }
+            PositionInTriangulation::NoTriangulation => None,
+        };
+        let Some(start) = start else {
+            return FixedVertexHandle::from_index(0);
+        };
+
+        let mut best = (f64::INFINITY, usize::MAX);
+        let mut visited: HashSet<FixedFaceHandle<InnerTag>> = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(fixed) = stack.pop() {
+            let face = self.cdt.face(fixed);
+            for vertex in face.vertices() {
+                let candidate = (distance_2(vertex.position(), point), vertex.fix().index());
+                best = std::cmp::min_by(best, candidate, |a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            }
+            for edge in face.adjacent_edges() {
+                let Some(neighbor) = edge.rev().face().as_inner() else {
+                    continue;
+                };
+                if segment_distance_2(point, edge.from().position(), edge.to().position()) <= best.0
+                    && visited.insert(neighbor.fix())
+                {
+                    stack.push(neighbor.fix());
+                }
+            }
+        }
+        FixedVertexHandle::from_index(best.1)
+    }
+
+    fn covers(&self, face: usize) -> bool {
+        match &self.inside {
+            Some(inside) => inside[face],
+            None => true,
+        }
+    }
+}
+
+/// The z coordinates of `points` as scalar values.
+fn heights(points: &[(f64, f64, f64)]) -> ValueArray {
+    ValueArray {
+        values: points.iter().map(|&(_, _, z)| z).collect(),
+        channels: None,
+    }
+}
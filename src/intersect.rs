@@ -0,0 +1,336 @@
+//! Segment queries answered by walking from triangle to triangle.
+
+use pyo3::prelude::*;
+use spade::Point2;
+use std::collections::HashMap;
+
+use crate::connectivity::{half_edges_impl, HalfEdges};
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::terrain::barycentric_weights;
+use crate::threads::{self, parallel_map};
+
+/// Triangles and edge crossings along a segment over a mesh.
+///
+/// The walk starts in the triangle containing `p0` (or where the segment
+/// first enters the mesh) and steps across shared edges towards `p1`. Where
+/// the segment leaves through the boundary it continues with the next
+/// re-entry, so holes and concave outlines are handled. Crossing points get
+/// their z interpolated along the crossed edge, which makes the result a
+/// terrain profile for line-of-sight or routing checks.
+///
+/// Args:
+///     mesh (dict): Mesh with consistently oriented 'vertices' and 'triangles'
+///     p0 (tuple): (x, y) start of the segment
+///     p1 (tuple): (x, y) end of the segment
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'triangles': Triangles visited, in order from p0 to p1
+///         - 'points': (x, y, z) edge-crossing points, in order
+///         - 'parameters': Position t in [0, 1] of each crossing along the segment
+///         - 'edges': (i, j) vertex indices of each crossed edge
+///
+/// Example:
+///     >>> import pyspade
+///     >>> ground = pyspade.tin(points_xyz)
+///     >>> profile = pyspade.intersect_segment(ground, (10.0, 20.0), (480.0, 350.0))
+///     >>> heights = [z for _, _, z in profile['points']]
+#[pyfunction]
+pub fn intersect_segment(mesh: Mesh, p0: (f64, f64), p1: (f64, f64)) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = intersect_segment_impl(&mesh, p0, p1).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("points".to_string(), result.points.into_py(py));
+        output.insert("parameters".to_string(), result.parameters.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+
+        Ok(output)
+    })
+}
+
+#[derive(Default)]
+pub(crate) struct SegmentWalk {
+    pub triangles: Vec<usize>,
+    pub points: Vec<(f64, f64, f64)>,
+    pub parameters: Vec<f64>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Where half-edge `h` crosses the segment `p0 + t (p1 - p0)`: `(t, s)` with
+/// `s` the position along the half-edge, if they cross.
+fn crossing(mesh: &Mesh, he: &HalfEdges, h: usize, p0: (f64, f64), d: (f64, f64)) -> Option<(f64, f64)> {
+    let a = mesh.vertices[he.origin[h]];
+    let b = mesh.vertices[he.origin[he.next[h]]];
+    let e = (b.0 - a.0, b.1 - a.1);
+    let denom = d.0 * e.1 - d.1 * e.0;
+    if denom == 0.0 {
+        return None;
+    }
+    let w = (a.0 - p0.0, a.1 - p0.1);
+    let t = (w.0 * e.1 - w.1 * e.0) / denom;
+    let s = (w.0 * d.1 - w.1 * d.0) / denom;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&s)).then_some((t, s))
+}
+
+pub(crate) fn intersect_segment_impl(
+    mesh: &Mesh,
+    p0: (f64, f64),
+    p1: (f64, f64),
+) -> Result<SegmentWalk, Box<dyn std::error::Error>> {
+    let he = half_edges_impl(mesh)?;
+    let grid = TriangleGrid::new(mesh);
+    Ok(walk_segment(mesh, &he, &grid, p0, p1))
+}
+
+/// [`intersect_segment_impl`] with the half-edges and triangle grid of
+/// `mesh` already built, for many segments over the same mesh.
+pub(crate) fn walk_segment(
+    mesh: &Mesh,
+    he: &HalfEdges,
+    grid: &TriangleGrid,
+    p0: (f64, f64),
+    p1: (f64, f64),
+) -> SegmentWalk {
+    let d = (p1.0 - p0.0, p1.1 - p0.1);
+    let mut walk = SegmentWalk::default();
+    let record = |walk: &mut SegmentWalk, h: usize, (t, s): (f64, f64)| {
+        let (a, b) = (he.origin[h], he.origin[he.next[h]]);
+        let (pa, pb) = (mesh.vertices[a], mesh.vertices[b]);
+        walk.points.push((p0.0 + t * d.0, p0.1 + t * d.1, pa.2 + s * (pb.2 - pa.2)));
+        walk.parameters.push(t);
+        walk.edges.push((a, b));
+    };
+    // First boundary half-edge crossed after parameter `after`
+    let boundary_entry = |after: f64| {
+        (0..he.origin.len())
+            .filter(|&h| he.twin[h] == -1)
+            .filter_map(|h| crossing(mesh, he, h, p0, d).map(|c| (h, c)))
+            .filter(|&(_, (t, _))| t > after)
+            .min_by(|x, y| x.1 .0.total_cmp(&y.1 .0))
+    };
+
+    // (current triangle, half-edge it was entered through, parameter so far)
+    let mut state = match grid.locate(mesh, p0.0, p0.1) {
+        Some(t) => Some((t, None, 0.0)),
+        None => boundary_entry(-1.0).map(|(h, c)| {
+            record(&mut walk, h, c);
+            (he.face[h], Some(h), c.0)
+        }),
+    };
+    let mut steps = 0;
+    while let Some((triangle, entered, t_current)) = state {
+        walk.triangles.push(triangle);
+        steps += 1;
+        if steps > mesh.triangles.len() + 1 {
+            break;
+        }
+        // The exit is the crossing furthest along the segment
+        let exit = (3 * triangle..3 * triangle + 3)
+            .filter(|&h| Some(h) != entered)
+            .filter_map(|h| crossing(mesh, he, h, p0, d).map(|c| (h, c)))
+            .filter(|&(_, (t, _))| t > t_current)
+            .max_by(|x, y| x.1 .0.total_cmp(&y.1 .0));
+        let Some((h, c)) = exit else {
+            // Passing exactly through a vertex can leave no usable exit
+            // edge; continue in whichever triangle lies just ahead
+            let ahead = t_current + 1e-9;
+            state = match grid.locate(mesh, p0.0 + ahead * d.0, p0.1 + ahead * d.1) {
+                Some(next) if next != triangle && ahead < 1.0 => Some((next, None, t_current)),
+                _ => None,
+            };
+            continue;
+        };
+        if c.0 >= 1.0 && grid.locate(mesh, p1.0, p1.1) == Some(triangle) {
+            break;
+        }
+        record(&mut walk, h, c);
+        state = match he.twin[h] {
+            -1 => boundary_entry(c.0).map(|(h, c)| {
+                record(&mut walk, h, c);
+                (he.face[h], Some(h), c.0)
+            }),
+            twin => Some((he.face[twin as usize], Some(twin as usize), c.0)),
+        };
+    }
+    walk
+}
+
+/// Whether two points see each other over a terrain mesh.
+///
+/// The sightline between the two points is compared with the terrain
+/// profile under it, which is linear inside each triangle, so checking the
+/// edge crossings is exact. Parts of the sightline outside the mesh (holes,
+/// concave outlines) never block it. A point given as (x, y) sits on the
+/// terrain; `observer_height` and `target_height` are added on top of the
+/// points, e.g. eye and facade heights.
+///
+/// Args:
+///     mesh (dict): Terrain mesh with consistently oriented (x, y, z) 'vertices' and 'triangles'
+///     p_from (tuple): Observer as (x, y) or (x, y, z)
+///     p_to (tuple): Target as (x, y) or (x, y, z)
+///     observer_height (float, optional): Height added to the observer. Default: 0.0
+///     target_height (float, optional): Height added to the target. Default: 0.0
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'visible': True if the terrain nowhere rises above the sightline
+///         - 'blocked_at': (x, y, z) terrain point where the sightline is first blocked,
+///           or None
+///         - 'parameter': Position t in [0, 1] of 'blocked_at' along the sightline, or None
+///         - 'clearance': Smallest height of the sightline above the terrain at the edge
+///           crossings (negative if blocked, infinite without crossings)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> ground = pyspade.tin(points_xyz)
+///     >>> sight = pyspade.line_of_sight(ground, (10.0, 20.0), (480.0, 350.0), observer_height=1.7)
+///     >>> print(sight['visible'], sight['blocked_at'])
+#[pyfunction]
+#[pyo3(signature = (mesh, p_from, p_to, observer_height=0.0, target_height=0.0))]
+pub fn line_of_sight(
+    mesh: Mesh,
+    p_from: Vec<f64>,
+    p_to: Vec<f64>,
+    observer_height: f64,
+    target_height: f64,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = lines_of_sight_impl(&mesh, &[p_from], &[p_to], (observer_height, target_height), Some(1))
+            .map_err(crate::to_py_err)?
+            .remove(0);
+
+        let mut output = HashMap::new();
+        output.insert("visible".to_string(), result.blocked.is_none().into_py(py));
+        output.insert("blocked_at".to_string(), result.blocked.map(|(point, _)| point).into_py(py));
+        output.insert("parameter".to_string(), result.blocked.map(|(_, t)| t).into_py(py));
+        output.insert("clearance".to_string(), result.clearance.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Line-of-sight checks for many pairs of points over one terrain mesh.
+///
+/// The same test as `line_of_sight()`, with the mesh connectivity built once
+/// and the pairs checked in parallel, for viewsheds and visibility studies.
+///
+/// Args:
+///     mesh (dict): Terrain mesh with consistently oriented (x, y, z) 'vertices' and 'triangles'
+///     p_from (list): Observers as (x, y) or (x, y, z); a single observer is paired with
+///         every target
+///     p_to (list): Targets as (x, y) or (x, y, z), one per observer
+///     observer_height (float, optional): Height added to every observer. Default: 0.0
+///     target_height (float, optional): Height added to every target. Default: 0.0
+///     num_threads (int, optional): Worker threads. Default: the PYSPADE_NUM_THREADS
+///         environment variable, or all available cores
+///
+/// Returns:
+///     dict: Dictionary with keys, one entry per pair:
+///         - 'visible': True if the terrain nowhere rises above the sightline
+///         - 'blocked_at': (x, y, z) first blocking terrain point, or None
+///         - 'parameter': Position t in [0, 1] of 'blocked_at', or None
+///         - 'clearance': Smallest height of the sightline above the terrain
+///
+/// Example:
+///     >>> import pyspade
+///     >>> ground = pyspade.tin(points_xyz)
+///     >>> sight = pyspade.lines_of_sight(ground, [tower], facade_points, observer_height=30.0)
+///     >>> seen = sum(sight['visible'])
+#[pyfunction]
+#[pyo3(signature = (mesh, p_from, p_to, observer_height=0.0, target_height=0.0, num_threads=None))]
+pub fn lines_of_sight(
+    mesh: Mesh,
+    p_from: Vec<Vec<f64>>,
+    p_to: Vec<Vec<f64>>,
+    observer_height: f64,
+    target_height: f64,
+    num_threads: Option<usize>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let results = py
+            .allow_threads(|| {
+                lines_of_sight_impl(&mesh, &p_from, &p_to, (observer_height, target_height), num_threads)
+                    .map_err(|e| e.to_string())
+            })
+            .map_err(|e| crate::to_py_err(e.into()))?;
+
+        let visible: Vec<bool> = results.iter().map(|r| r.blocked.is_none()).collect();
+        let blocked_at: Vec<Option<(f64, f64, f64)>> = results.iter().map(|r| r.blocked.map(|(p, _)| p)).collect();
+        let parameter: Vec<Option<f64>> = results.iter().map(|r| r.blocked.map(|(_, t)| t)).collect();
+        let clearance: Vec<f64> = results.iter().map(|r| r.clearance).collect();
+
+        let mut output = HashMap::new();
+        output.insert("visible".to_string(), visible.into_py(py));
+        output.insert("blocked_at".to_string(), blocked_at.into_py(py));
+        output.insert("parameter".to_string(), parameter.into_py(py));
+        output.insert("clearance".to_string(), clearance.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct Sightline {
+    /// First blocking terrain point and its parameter along the sightline
+    pub blocked: Option<((f64, f64, f64), f64)>,
+    pub clearance: f64,
+}
+
+pub(crate) fn lines_of_sight_impl(
+    mesh: &Mesh,
+    p_from: &[Vec<f64>],
+    p_to: &[Vec<f64>],
+    (observer_height, target_height): (f64, f64),
+    num_threads: Option<usize>,
+) -> Result<Vec<Sightline>, Box<dyn std::error::Error>> {
+    if p_from.len() != p_to.len() && p_from.len() != 1 {
+        return Err(format!("expected 1 or {} observers (one per target), got {}", p_to.len(), p_from.len()).into());
+    }
+    let threads = threads::num_threads(num_threads)?;
+    let he = half_edges_impl(mesh)?;
+    let grid = TriangleGrid::new(mesh);
+
+    // Endpoints as (x, y, z) with z on the terrain where not given
+    let endpoint = |row: &[f64], height: f64| -> Result<(f64, f64, f64), String> {
+        let (x, y, z) = match *row {
+            [x, y] => (x, y, None),
+            [x, y, z] => (x, y, Some(z)),
+            _ => return Err("points must be (x, y) or (x, y, z)".to_string()),
+        };
+        let z = z
+            .or_else(|| surface_height(mesh, &grid, x, y))
+            .ok_or_else(|| format!("point ({}, {}) is outside the mesh; give its z", x, y))?;
+        Ok((x, y, z + height))
+    };
+
+    let pairs: Vec<(&[f64], &[f64])> =
+        p_to.iter().enumerate().map(|(i, to)| (p_from[i.min(p_from.len() - 1)].as_slice(), to.as_slice())).collect();
+    parallel_map(&pairs, threads, |&(from, to)| -> Result<Sightline, String> {
+        let (from, to) = (endpoint(from, observer_height)?, endpoint(to, target_height)?);
+        let walk = walk_segment(mesh, &he, &grid, (from.0, from.1), (to.0, to.1));
+        let mut sightline = Sightline {
+            blocked: None,
+            clearance: f64::INFINITY,
+        };
+        for (&point, &t) in walk.points.iter().zip(&walk.parameters) {
+            let clearance = from.2 + t * (to.2 - from.2) - point.2;
+            sightline.clearance = sightline.clearance.min(clearance);
+            if clearance < 0.0 && sightline.blocked.is_none() {
+                sightline.blocked = Some((point, t));
+            }
+        }
+        Ok(sightline)
+    })
+    .into_iter()
+    .collect::<Result<Vec<_>, String>>()
+    .map_err(Into::into)
+}
+
+/// z of the mesh surface at `(x, y)`, if a triangle covers it.
+fn surface_height(mesh: &Mesh, grid: &TriangleGrid, x: f64, y: f64) -> Option<f64> {
+    let corners = mesh.corners(grid.locate(mesh, x, y)?);
+    let [a, b, c] = corners.map(|(x, y, _)| Point2::new(x, y));
+    let w = barycentric_weights(a, b, c, Point2::new(x, y));
+    Some(w[0] * corners[0].2 + w[1] * corners[1].2 + w[2] * corners[2].2)
+}
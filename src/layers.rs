@@ -0,0 +1,215 @@
+//! Graded boundary-layer points along selected boundary segments.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use spade::{FixedVertexHandle, Point2};
+
+use crate::geometry;
+use crate::regions::{add_constraint_checked, Cdt};
+
+/// Boundary-layer settings of `triangulate()`, given as a dict with
+/// 'segments', 'first_height', 'num_layers' and optionally 'growth_rate'.
+pub(crate) struct BoundaryLayers {
+    pub segments: Vec<usize>,
+    pub first_height: f64,
+    pub growth_rate: f64,
+    pub num_layers: usize,
+}
+
+impl<'py> FromPyObject<'py> for BoundaryLayers {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+        let required = |key: &str| {
+            dict.get_item(key)?
+                .ok_or_else(|| PyValueError::new_err(format!("boundary_layers is missing '{}'", key)))
+        };
+        Ok(BoundaryLayers {
+            segments: required("segments")?.extract()?,
+            first_height: required("first_height")?.extract()?,
+            growth_rate: match dict.get_item("growth_rate")? {
+                Some(value) => value.extract()?,
+                None => 1.2,
+            },
+            num_layers: required("num_layers")?.extract()?,
+        })
+    }
+}
+
+impl BoundaryLayers {
+    /// Offset of each layer line from the boundary, first layer first.
+    fn offsets(&self) -> Vec<f64> {
+        (1..=self.num_layers as i32)
+            .map(|l| match self.growth_rate {
+                g if g == 1.0 => self.first_height * l as f64,
+                g => self.first_height * (g.powi(l) - 1.0) / (g - 1.0),
+            })
+            .collect()
+    }
+}
+
+/// The region covered by one chain of boundary layers, as rings combined
+/// with the even-odd rule.
+pub(crate) type Strip = Vec<Vec<(f64, f64)>>;
+
+/// Whether `point` lies inside any of the strips.
+pub(crate) fn in_strips(point: (f64, f64), strips: &[Strip]) -> bool {
+    strips.iter().any(|rings| {
+        rings.iter().filter(|ring| geometry::point_in_polygon(point, ring)).count() % 2 == 1
+    })
+}
+
+/// Insert the layer points for the selected segments of `rings` (outer ring
+/// first, segments numbered in ring order) and close every chain of layers
+/// off with constraint edges. The selected segments must be subdivided to
+/// `max_edge_length` exactly like `sizing::add_subdivided_constraint` does,
+/// so every boundary vertex gets a column of layer points.
+pub(crate) fn insert_boundary_layers(
+    cdt: &mut Cdt,
+    rings: &[&[(f64, f64)]],
+    layers: &BoundaryLayers,
+    max_edge_length: f64,
+) -> Result<Vec<Strip>, Box<dyn std::error::Error>> {
+    if layers.first_height.is_nan() || layers.first_height <= 0.0 {
+        return Err(format!("first_height must be positive, got {}", layers.first_height).into());
+    }
+    if layers.growth_rate.is_nan() || layers.growth_rate < 1.0 {
+        return Err(format!("growth_rate must be at least 1, got {}", layers.growth_rate).into());
+    }
+    if layers.num_layers == 0 {
+        return Err("num_layers must be at least 1".into());
+    }
+    let total: usize = rings.iter().map(|ring| ring.len()).sum();
+    if let Some(&segment) = layers.segments.iter().find(|&&segment| segment >= total) {
+        return Err(format!("boundary layer segment {} out of range ({} input segments)", segment, total).into());
+    }
+    let offsets = layers.offsets();
+    let thickness = offsets[offsets.len() - 1];
+
+    let mut strips = Vec::new();
+    let mut first_segment = 0;
+    for (r, ring) in rings.iter().enumerate() {
+        let n = ring.len();
+        let selected: Vec<bool> = (0..n).map(|k| layers.segments.contains(&(first_segment + k))).collect();
+        first_segment += n;
+
+        // The domain lies left of a counter-clockwise outer ring and right of
+        // a counter-clockwise hole
+        let ccw = geometry::signed_area(ring) > 0.0;
+        let side = if (r == 0) == ccw { 1.0 } else { -1.0 };
+        let normal = |k: usize| {
+            let (p, q) = (ring[k], ring[(k + 1) % n]);
+            let length = (q.0 - p.0).hypot(q.1 - p.1);
+            (side * -(q.1 - p.1) / length, side * (q.0 - p.0) / length)
+        };
+
+        for chain in chains(&selected) {
+            let closed = chain.len() == n;
+            let stations = chain_stations(ring, &chain, closed, &normal, max_edge_length);
+
+            let mut columns: Vec<Vec<FixedVertexHandle>> = Vec::with_capacity(stations.len());
+            for &(p, (nx, ny)) in &stations {
+                let mut column = vec![cdt.insert(Point2::new(p.0, p.1))?];
+                for &offset in &offsets {
+                    column.push(cdt.insert(Point2::new(p.0 + offset * nx, p.1 + offset * ny))?);
+                }
+                columns.push(column);
+            }
+
+            let top: Vec<FixedVertexHandle> = columns.iter().map(|column| column[layers.num_layers]).collect();
+            for pair in top.windows(2) {
+                add_constraint_checked(cdt, pair[0], pair[1])?;
+            }
+            let top_ring: Vec<(f64, f64)> = stations
+                .iter()
+                .map(|&(p, (nx, ny))| (p.0 + thickness * nx, p.1 + thickness * ny))
+                .collect();
+            if closed {
+                add_constraint_checked(cdt, top[top.len() - 1], top[0])?;
+                let boundary: Vec<(f64, f64)> = stations.iter().map(|&(p, _)| p).collect();
+                strips.push(vec![boundary, top_ring]);
+            } else {
+                for column in [&columns[0], &columns[columns.len() - 1]] {
+                    for pair in column.windows(2) {
+                        add_constraint_checked(cdt, pair[0], pair[1])?;
+                    }
+                }
+                let mut strip: Vec<(f64, f64)> = stations.iter().map(|&(p, _)| p).collect();
+                strip.extend(top_ring.into_iter().rev());
+                strips.push(vec![strip]);
+            }
+        }
+    }
+    Ok(strips)
+}
+
+/// Maximal runs of consecutive selected segments of a ring. A fully selected
+/// ring is one run starting at segment 0.
+fn chains(selected: &[bool]) -> Vec<Vec<usize>> {
+    let n = selected.len();
+    if selected.iter().all(|&s| s) {
+        return vec![(0..n).collect()];
+    }
+    let mut chains = Vec::new();
+    for start in (0..n).filter(|&k| selected[k] && !selected[(k + n - 1) % n]) {
+        let mut chain = vec![start];
+        let mut k = (start + 1) % n;
+        while selected[k] {
+            chain.push(k);
+            k = (k + 1) % n;
+        }
+        chains.push(chain);
+    }
+    chains
+}
+
+/// Boundary points along a chain with the (scaled) inward direction of
+/// their layer column. Directions at interior chain vertices bisect the two
+/// segment normals and are lengthened so layers keep their thickness.
+fn chain_stations(
+    ring: &[(f64, f64)],
+    chain: &[usize],
+    closed: bool,
+    normal: &dyn Fn(usize) -> (f64, f64),
+    max_edge_length: f64,
+) -> Vec<((f64, f64), (f64, f64))> {
+    let n = ring.len();
+    let m = chain.len();
+    // Direction at the start vertex of chain[i]; index m is the chain's end
+    let vertex_direction = |i: usize| {
+        let after = (i < m).then(|| normal(chain[i]));
+        let before = if i > 0 { Some(normal(chain[i - 1])) } else if closed { Some(normal(chain[m - 1])) } else { None };
+        match (before, after.or_else(|| closed.then(|| normal(chain[0])))) {
+            (Some(a), Some(b)) => {
+                let (sx, sy) = (a.0 + b.0, a.1 + b.1);
+                let length = sx.hypot(sy);
+                if length < 1e-12 {
+                    return b;
+                }
+                let (bx, by) = (sx / length, sy / length);
+                let scale = 1.0 / (bx * b.0 + by * b.1).max(0.5);
+                (bx * scale, by * scale)
+            }
+            (Some(a), None) => a,
+            (None, Some(b)) => b,
+            (None, None) => (0.0, 0.0),
+        }
+    };
+
+    let mut stations = Vec::new();
+    for (i, &k) in chain.iter().enumerate() {
+        let (p, q) = (ring[k], ring[(k + 1) % n]);
+        let (dp, dq) = (vertex_direction(i), vertex_direction(i + 1));
+        // Same pieces and positions as sizing::add_subdivided_constraint
+        let length = ((p.0 - q.0).powi(2) + (p.1 - q.1).powi(2)).sqrt();
+        let pieces = (length / max_edge_length).ceil().max(1.0) as usize;
+        let last = !closed && i == m - 1;
+        for j in 0..pieces + usize::from(last) {
+            let t = j as f64 / pieces as f64;
+            let position = (p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1));
+            let direction = (dp.0 + t * (dq.0 - dp.0), dp.1 + t * (dq.1 - dp.1));
+            stations.push((position, direction));
+        }
+    }
+    stations
+}
@@ -1,21 +1,209 @@
 use pyo3::prelude::*;
-use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation, RefinementParameters, AngleLimit};
+use spade::handles::{FixedFaceHandle, InnerTag};
+use spade::{Point2, Triangulation, RefinementParameters, AngleLimit};
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use arcs::RingItem;
+use arrays::PointArray;
+use attributes::Attributes;
+use diagnostics::{Diagnostics, Refinement};
+use layers::BoundaryLayers;
+use mesh::Mesh;
+use regions::Cdt;
+use sizing::{BackgroundSizes, BoundaryGrading, DensitySizes, SizeField};
+
+mod adaptive;
+mod anisotropic;
+mod arcs;
+mod arrays;
+mod attributes;
+mod builder;
+mod clip;
+mod connectivity;
+mod constraints;
+mod contours;
+mod diagnostics;
+mod distance;
+mod drainage;
+mod editing;
+mod elements;
+mod fields;
+mod geometry;
+mod graphs;
+mod interpolator;
+mod intersect;
+mod layers;
+mod logging;
+mod merge;
+mod mesh;
+mod parameters;
+mod partition;
+mod polygons;
+mod power;
+mod quads;
+mod random;
+mod raster;
+mod regions;
+mod remesh;
+mod repair;
+mod rendering;
+mod roads;
+mod sampling;
+mod segments;
+mod sizing;
+mod slivers;
+mod smoothing;
+mod statistics;
+mod terrain;
+mod thinning;
+mod threads;
+mod tiling;
+mod triangulator;
+mod validation;
+mod voronoi;
 
 /// Triangulate a polygon with optional holes using constrained Delaunay triangulation.
 ///
+/// Rings may contain circular arcs in place of vertices, given as dicts with
+/// 'center', 'radius', 'start' and 'end' (degrees, counter-clockwise from
+/// start to end). Arcs are discretized to max_edge_length (and at most 22.5°
+/// per piece) before meshing; segment and vertex numbering below refers to
+/// the discretized rings.
+///
 /// Args:
-///     outer (list): Exterior boundary vertices as list of (x, y) tuples
-///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples. Default: []
-///     max_edge_length (float, optional): Target maximum edge length for mesh refinement
+///     outer (list): Exterior boundary vertices as list of (x, y) tuples or arc dicts
+///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples or arc
+///         dicts. A hole made of one full arc (start=0, end=360) is a circle. Holes may share
+///         vertices or edges with the outer ring or each other. Default: []
+///     max_edge_length (float, optional): Target maximum edge length for mesh refinement.
+///         Must be positive and finite; None means no limit
 ///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
 ///     triangulate_holes (bool, optional): If True, mesh inside holes; if False, exclude them. Default: False
+///     region_sizes (list, optional): Local size limits as (x, y, max_edge_length) tuples. The
+///         region around each seed point, bounded by the constraint edges, is meshed with
+///         edges of roughly that length; elsewhere max_edge_length applies. As max_edge_length
+///         caps every region, a region size above it raises a ValueError. Default: []
+///     protected_segments (list, optional): Indices of input segments that refinement must not
+///         split. Segments are numbered in input order: segment k of the outer ring joins
+///         vertex k to vertex k+1, followed by the segments of each hole. When given, all other
+///         segments are subdivided up front to max_edge_length instead. Default: []
+///     segment_markers (list, optional): Integer boundary marker per input segment (numbered as
+///         for protected_segments), or one marker per ring (outer ring first, then each hole).
+///         Markers are carried over to every piece a segment is split into. Default: None
+///     vertex_attributes (dict, optional): Named per-vertex value arrays (e.g. temperature,
+///         classification), one value per input vertex in input order (outer ring, then each
+///         hole). Default: None
+///     boundary_layers (dict, optional): Graded layers of thin triangles along selected input
+///         segments, as a dict with 'segments' (segment indices, numbered as for
+///         protected_segments), 'first_height' (thickness of the first layer), 'num_layers' and
+///         'growth_rate' (thickness ratio of consecutive layers, default 1.2). The layers are
+///         built before refinement and kept out of it; requires max_edge_length and cannot be
+///         combined with triangulate_holes. Default: None
+///     diagnostics (bool, optional): If True, also return a report of what happened to the
+///         input during meshing. Default: False
+///     grid_size (float, optional): Snap all input coordinates to a uniform grid with this
+///         spacing before meshing, e.g. 0.001 to absorb sub-millimeter jitter in survey data.
+///         Vertices snapped together are merged and the segments between them dropped;
+///         vertex and segment numbering refers to the input as given. Default: None
+///     sliver_tolerance (float, optional): Remove slivers, triangles whose height over their
+///         longest edge is below this length, by collapsing the vertex opposite that edge.
+///         Nearly straight boundary runs are straightened within the tolerance; constrained
+///         edges are kept (merged where a run is straightened) and segment markers must
+///         agree across a merge. Default: None
+///     relax_min_angle (bool, optional): If refinement stops at its vertex limit before meeting
+///         min_angle, retry with the angle lowered in steps of 5° until refinement completes
+///         (down to 5°). Default: False
+///     max_edge_length_rel (float, optional): max_edge_length as a fraction of the diagonal of
+///         the input's bounding box, e.g. 0.02 for 2%, so one setting suits inputs of any
+///         scale. Cannot be combined with max_edge_length. Default: None
+///     timeout (float, optional): Wall-clock limit in seconds for refinement. When it passes,
+///         refinement stops and the partially refined mesh is returned with 'timed_out' set.
+///         The clock is checked between refinement rounds, so a call can overrun it by one
+///         round; refinement in rounds has no vertex limit, so the timeout is what bounds
+///         it. Default: None
+///     return_hole_triangles (bool, optional): If True, return the triangles meshed inside
+///         excluded holes as 'hole_triangles' instead of discarding them, e.g. to give
+///         courtyards a different material. Default: False
+///     return_hull_triangles (bool, optional): If True, return the triangles between the outer
+///         ring and the convex hull of the input as 'hull_triangles', and leave them out of
+///         'triangles' (where they otherwise appear unless holes are excluded). Default: False
+///     winding (str, optional): Orientation of every output triangle's (i, j, k) in the xy
+///         plane, "ccw" (counter-clockwise) or "cw". Default: "ccw"
+///     classify_edges (bool, optional): If True, also return every edge of 'triangles' with
+///         its origin. Default: False
+///     no_refine_zones (list, optional): Polygons, each as list of (x, y) tuples, inside which
+///         refinement inserts no vertices, e.g. under building slabs whose mesh is replaced
+///         later. A zone's outline is added as constrained edges (split to max_edge_length)
+///         and its inside keeps the triangulation of those edges. Zones must lie inside the
+///         domain without overlapping holes or each other; cannot be combined with
+///         triangulate_holes. Default: []
+///     point_sizes (list, optional): Local sizes around point features (tower bases, inlets)
+///         as (x, y, size, radius) tuples. Edges are about size long at the point, growing
+///         linearly into max_edge_length at distance radius (without max_edge_length, size
+///         applies up to radius). Input segments are split to follow the sizes. Default: []
+///     line_sizes (list, optional): Local sizes along line features (roads, rivers) as
+///         (polyline, size, width) tuples, the polyline as list of (x, y) tuples. Sizes grow
+///         from the line as they do from point_sizes, with width in place of radius. The
+///         polylines only steer the size; they are not inserted as edges. Default: []
+///     boundary_grading (tuple, optional): (h0, slope) for edges that grow with the distance d
+///         from the outer and hole rings as h0 + slope * d, e.g. (1.0, 0.2) for 1 m edges at
+///         the boundary growing by 0.2 m per metre (capped by max_edge_length if given).
+///         Default: None
+///     background_mesh (dict, optional): Mesh with 'vertices' and 'triangles' carrying a size
+///         field, e.g. a coarse mesh from a previous adaptive step. Sizes are interpolated
+///         linearly over its triangles and do not limit the mesh outside it. Requires
+///         background_sizes. Default: None
+///     background_sizes (list, optional): Target edge length at each vertex of
+///         background_mesh, e.g. from an error estimate of the last solution. Default: None
+///     density_points (array, optional): Point cloud as an (n, 2) NumPy array or list of
+///         (x, y) tuples, e.g. survey points, whose local spacing sets the size: at each point,
+///         density_scale times the distance to its density_neighbors-th nearest neighbor,
+///         interpolated linearly between the points. Default: None
+///     density_neighbors (int, optional): Which nearest neighbor measures the spacing; 4 gives
+///         the grid spacing on a square grid. Default: 4
+///     density_scale (float, optional): Factor from point spacing to edge length. Default: 1.0
 ///
 /// Returns:
 ///     dict: Dictionary with keys:
-///         - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
-///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'vertices': List of (x, y, z) vertex coordinates (z=0.0). If every input vertex
+///           is given as a pair of integers (and grid_size is not set), the input vertices
+///           keep integer x and y; only vertices added by meshing are floats
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based), all oriented as
+///           given by winding
 ///         - 'edges': List of (i, j) constrained edge indices
+///         - 'edge_markers': Marker of each constrained edge, 0 where no input segment
+///           covers it (only if segment_markers is given)
+///         - 'vertex_attributes': The vertex_attributes arrays aligned with 'vertices', linearly
+///           interpolated at vertices inserted by refinement (only if vertex_attributes is given)
+///         - 'achieved_min_angle': Smallest angle of the output triangles in degrees, None if
+///           there are none (only if min_angle is given)
+///         - 'min_angle_used': The angle limit of the final attempt (only if relax_min_angle)
+///         - 'mesh_edges': Every (i, j) edge of 'triangles' (only if classify_edges is given)
+///         - 'edge_origins': Origin of each mesh edge (only if classify_edges is given):
+///           'outer' or 'hole' for pieces of an input ring, 'constraint' for other constrained
+///           edges (closing off boundary layers or no-refine zones) and 'delaunay' for
+///           unconstrained edges
+///         - 'edge_rings': Ring of each mesh edge, 0 for the outer ring and k for hole k - 1,
+///           or -1 if it is not on a ring (only if classify_edges is given)
+///         - 'hole_triangles': Triangles inside holes as (i, j, k) indices into 'vertices'
+///           (only if return_hole_triangles is given; empty with triangulate_holes=True)
+///         - 'hole_ids': Index into holes of the hole containing each hole triangle (only if
+///           return_hole_triangles is given)
+///         - 'hull_triangles': Triangles outside the outer ring within the convex hull (only if
+///           return_hull_triangles is given)
+///         - 'timed_out': True if refinement stopped at the timeout (only if timeout is given)
+///         - 'removed_slivers': Number of slivers removed (only if sliver_tolerance is given)
+///         - 'diagnostics': Dict (only if diagnostics=True) with keys:
+///             - 'duplicate_vertices': Input vertices dropped as duplicates of an earlier one
+///             - 'clockwise_rings': Rings given clockwise (0 is the outer ring, k hole k - 1),
+///               meshed as given
+///             - 'degenerate_segments': Input segments skipped as their end points coincide
+///             - 'split_segments': Input segments split into several constrained edges
+///             - 'excluded_faces': Number of triangles left out as holes or exterior
+///             - 'refinement': 'not_run', 'complete', 'vertex_limit' when refinement
+///               stopped before meeting every size and angle target, or 'timeout'
+///             - 'added_vertices': Number of vertices inserted after the input vertices
 ///
 /// Example:
 ///     >>> import pyspade
@@ -27,41 +215,406 @@ use std::collections::{HashMap, HashSet};
 ///     ... )
 ///     >>> print(f"Generated {len(result['triangles'])} triangles")
 #[pyfunction]
-#[pyo3(signature = (outer, holes=None, max_edge_length=None, min_angle=None, triangulate_holes=false))]
+#[pyo3(signature = (
+    outer,
+    holes=None,
+    max_edge_length=None,
+    min_angle=None,
+    triangulate_holes=false,
+    region_sizes=None,
+    protected_segments=None,
+    segment_markers=None,
+    vertex_attributes=None,
+    boundary_layers=None,
+    diagnostics=false,
+    grid_size=None,
+    sliver_tolerance=None,
+    relax_min_angle=false,
+    max_edge_length_rel=None,
+    timeout=None,
+    return_hole_triangles=false,
+    return_hull_triangles=false,
+    winding="ccw",
+    classify_edges=false,
+    no_refine_zones=None,
+    point_sizes=None,
+    line_sizes=None,
+    boundary_grading=None,
+    background_mesh=None,
+    background_sizes=None,
+    density_points=None,
+    density_neighbors=4,
+    density_scale=1.0,
+))]
+#[allow(clippy::too_many_arguments)]
 fn triangulate(
-    outer: Vec<(f64, f64)>,
-    holes: Option<Vec<Vec<(f64, f64)>>>,
+    outer: Vec<RingItem>,
+    holes: Option<Vec<Vec<RingItem>>>,
     max_edge_length: Option<f64>,
     min_angle: Option<f64>,
     triangulate_holes: bool,
+    region_sizes: Option<Vec<(f64, f64, f64)>>,
+    protected_segments: Option<Vec<usize>>,
+    segment_markers: Option<Vec<i64>>,
+    vertex_attributes: Option<Attributes>,
+    boundary_layers: Option<BoundaryLayers>,
+    diagnostics: bool,
+    grid_size: Option<f64>,
+    sliver_tolerance: Option<f64>,
+    relax_min_angle: bool,
+    max_edge_length_rel: Option<f64>,
+    timeout: Option<f64>,
+    return_hole_triangles: bool,
+    return_hull_triangles: bool,
+    winding: &str,
+    classify_edges: bool,
+    no_refine_zones: Option<Vec<Vec<(f64, f64)>>>,
+    point_sizes: Option<Vec<(f64, f64, f64, f64)>>,
+    line_sizes: Option<Vec<(Vec<(f64, f64)>, f64, f64)>>,
+    boundary_grading: Option<(f64, f64)>,
+    background_mesh: Option<Mesh>,
+    background_sizes: Option<Vec<f64>>,
+    density_points: Option<PointArray>,
+    density_neighbors: usize,
+    density_scale: f64,
 ) -> PyResult<HashMap<String, PyObject>> {
     Python::with_gil(|py| {
-        let result = triangulate_impl(outer, holes, max_edge_length, min_angle, triangulate_holes)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e)))?;
+        let clockwise = parse_winding(winding).map_err(to_py_err)?;
+        let max_edge_length = arcs::resolve_edge_length(
+            std::iter::once(outer.as_slice()).chain(holes.iter().flatten().map(Vec::as_slice)),
+            max_edge_length,
+            max_edge_length_rel,
+        )
+        .map_err(to_py_err)?;
+        let region_sizes = region_sizes.unwrap_or_default();
+        sizing::check_region_sizes(&region_sizes, max_edge_length)?;
+        // Integer input comes back as integers unless snapping may move it
+        let integer = grid_size.is_none()
+            && arcs::all_integer(std::iter::once(outer.as_slice()).chain(holes.iter().flatten().map(Vec::as_slice)));
+        let outer = arcs::expand_ring(&outer, max_edge_length).map_err(to_py_err)?;
+        let holes = holes
+            .map(|holes| {
+                holes
+                    .iter()
+                    .map(|hole| arcs::expand_ring(hole, max_edge_length))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+            .map_err(to_py_err)?;
+        let options = TriangulateOptions {
+            max_edge_length,
+            min_angle,
+            triangulate_holes,
+            region_sizes,
+            protected_segments: protected_segments.unwrap_or_default(),
+            segment_markers,
+            vertex_attributes,
+            boundary_layers,
+            grid_size,
+            sliver_tolerance,
+            relax_min_angle,
+            timeout,
+            return_hole_triangles,
+            return_hull_triangles,
+            clockwise,
+            classify_edges,
+            no_refine_zones: no_refine_zones.unwrap_or_default(),
+            point_sizes: point_sizes.unwrap_or_default(),
+            line_sizes: line_sizes.unwrap_or_default(),
+            boundary_grading,
+            background_mesh,
+            background_sizes,
+            density_sizing: density_points.map(|points| (points.0, density_neighbors, density_scale)),
+        };
+        // Meshing runs without the GIL, so other Python threads (and
+        // `triangulate_async()`'s event loop) keep going meanwhile
+        let result = py
+            .allow_threads(|| triangulate_impl(outer, holes, &options).map_err(|e| e.to_string()))
+            .map_err(|e| to_py_err(e.into()))?;
 
-        let mut output = HashMap::new();
-        output.insert("vertices".to_string(), result.vertices.into_py(py));
-        output.insert("triangles".to_string(), result.triangles.into_py(py));
-        output.insert("edges".to_string(), result.edges.into_py(py));
-
-        Ok(output)
+        Ok(result.into_dict(py, diagnostics, integer))
     })
 }
 
-struct TriangulationResult {
+/// Convert an internal error into the `RuntimeError` raised to Python.
+pub(crate) fn to_py_err(e: Box<dyn std::error::Error>) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{}", e))
+}
+
+/// Whether `winding` asks for clockwise triangles.
+pub(crate) fn parse_winding(winding: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    match winding {
+        "ccw" => Ok(false),
+        "cw" => Ok(true),
+        _ => Err(format!("unknown winding '{}', expected 'ccw' or 'cw'", winding).into()),
+    }
+}
+
+/// Check a maximum edge length. Given values must be finite and positive;
+/// `None`, not infinity, means no limit.
+pub(crate) fn validate_edge_length(max_edge_length: Option<f64>) -> Result<(), Box<dyn std::error::Error>> {
+    match max_edge_length {
+        Some(h) if !(h.is_finite() && h > 0.0) => {
+            Err(format!("max_edge_length must be positive and finite, got {}", h).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Optional settings of `triangulate()` beyond the input geometry.
+#[derive(Default)]
+pub(crate) struct TriangulateOptions {
+    pub max_edge_length: Option<f64>,
+    pub min_angle: Option<f64>,
+    pub triangulate_holes: bool,
+    pub region_sizes: Vec<(f64, f64, f64)>,
+    pub protected_segments: Vec<usize>,
+    pub segment_markers: Option<Vec<i64>>,
+    pub vertex_attributes: Option<Attributes>,
+    pub boundary_layers: Option<BoundaryLayers>,
+    pub grid_size: Option<f64>,
+    pub sliver_tolerance: Option<f64>,
+    pub relax_min_angle: bool,
+    /// Refinement time limit in seconds
+    pub timeout: Option<f64>,
+    pub return_hole_triangles: bool,
+    pub return_hull_triangles: bool,
+    /// Output triangles clockwise instead of spade's counter-clockwise
+    pub clockwise: bool,
+    pub classify_edges: bool,
+    /// Polygons kept free of refinement vertices
+    pub no_refine_zones: Vec<Vec<(f64, f64)>>,
+    /// (x, y, size, radius) point features of the size field
+    pub point_sizes: Vec<(f64, f64, f64, f64)>,
+    /// (polyline, size, width) line features of the size field
+    pub line_sizes: Vec<(Vec<(f64, f64)>, f64, f64)>,
+    /// (h0, slope) of the size growing away from the rings
+    pub boundary_grading: Option<(f64, f64)>,
+    /// Mesh carrying per-vertex sizes of the size field
+    pub background_mesh: Option<Mesh>,
+    pub background_sizes: Option<Vec<f64>>,
+    /// (points, k, scale) of the size field following a point cloud's spacing
+    pub density_sizing: Option<(Vec<(f64, f64)>, usize, f64)>,
+}
+
+pub(crate) struct TriangulationResult {
     vertices: Vec<(f64, f64, f64)>,
     triangles: Vec<(usize, usize, usize)>,
     edges: Vec<(usize, usize)>,
+    edge_markers: Option<Vec<i64>>,
+    /// Input ring of each constrained edge, -1 for none (for classify_edges)
+    edge_rings: Option<Vec<i64>>,
+    vertex_attributes: Option<Attributes>,
+    diagnostics: Diagnostics,
+    /// Number of distinct input vertices, which come first in `vertices`
+    input_vertices: usize,
+    removed_slivers: Option<usize>,
+    /// Smallest output angle in degrees, reported when min_angle is given
+    achieved_min_angle: Option<Option<f64>>,
+    min_angle_used: Option<f64>,
+    timed_out: Option<bool>,
+    /// Triangles inside holes with the index of their hole
+    hole_triangles: Option<Vec<((usize, usize, usize), usize)>>,
+    /// Triangles between the outer ring and the convex hull
+    hull_triangles: Option<Vec<(usize, usize, usize)>>,
+    /// Mesh edges with their origin and ring
+    edge_origins: Option<Vec<((usize, usize), &'static str, i64)>>,
+}
+
+impl TriangulationResult {
+    /// The dict returned by `triangulate()`, with 'diagnostics' only if asked
+    /// for. With `integer`, input vertices get integer x and y.
+    pub fn into_dict(self, py: Python, diagnostics: bool, integer: bool) -> HashMap<String, PyObject> {
+        let mut output = HashMap::new();
+        let vertices: PyObject = if integer {
+            self.vertices
+                .iter()
+                .enumerate()
+                .map(|(i, &(x, y, z))| {
+                    if i < self.input_vertices {
+                        (x as i64, y as i64, z).into_py(py)
+                    } else {
+                        (x, y, z).into_py(py)
+                    }
+                })
+                .collect::<Vec<PyObject>>()
+                .into_py(py)
+        } else {
+            self.vertices.into_py(py)
+        };
+        output.insert("vertices".to_string(), vertices);
+        output.insert("triangles".to_string(), self.triangles.into_py(py));
+        output.insert("edges".to_string(), self.edges.into_py(py));
+        if let Some(edge_markers) = self.edge_markers {
+            output.insert("edge_markers".to_string(), edge_markers.into_py(py));
+        }
+        if let Some(vertex_attributes) = self.vertex_attributes {
+            output.insert("vertex_attributes".to_string(), vertex_attributes.into_py(py));
+        }
+        if let Some(achieved_min_angle) = self.achieved_min_angle {
+            output.insert("achieved_min_angle".to_string(), achieved_min_angle.into_py(py));
+        }
+        if let Some(min_angle_used) = self.min_angle_used {
+            output.insert("min_angle_used".to_string(), min_angle_used.into_py(py));
+        }
+        if let Some(edge_origins) = self.edge_origins {
+            let (edges, origins): (Vec<_>, Vec<_>) = edge_origins.iter().map(|&(edge, origin, _)| (edge, origin)).unzip();
+            let rings: Vec<i64> = edge_origins.iter().map(|&(_, _, ring)| ring).collect();
+            output.insert("mesh_edges".to_string(), edges.into_py(py));
+            output.insert("edge_origins".to_string(), origins.into_py(py));
+            output.insert("edge_rings".to_string(), rings.into_py(py));
+        }
+        if let Some(hole_triangles) = self.hole_triangles {
+            let (triangles, ids): (Vec<_>, Vec<_>) = hole_triangles.into_iter().unzip();
+            output.insert("hole_triangles".to_string(), triangles.into_py(py));
+            output.insert("hole_ids".to_string(), ids.into_py(py));
+        }
+        if let Some(hull_triangles) = self.hull_triangles {
+            output.insert("hull_triangles".to_string(), hull_triangles.into_py(py));
+        }
+        if let Some(timed_out) = self.timed_out {
+            output.insert("timed_out".to_string(), timed_out.into_py(py));
+        }
+        if let Some(removed_slivers) = self.removed_slivers {
+            output.insert("removed_slivers".to_string(), removed_slivers.into_py(py));
+        }
+        if diagnostics {
+            output.insert("diagnostics".to_string(), self.diagnostics.into_dict(py).into_py(py));
+        }
+        output
+    }
 }
 
 fn triangulate_impl(
     outer: Vec<(f64, f64)>,
     holes: Option<Vec<Vec<(f64, f64)>>>,
-    max_edge_length: Option<f64>,
+    options: &TriangulateOptions,
+) -> Result<TriangulationResult, Box<dyn std::error::Error>> {
+    triangulate_into(&mut Cdt::default(), outer, holes, options)
+}
+
+/// Step by which `relax_min_angle` lowers the angle limit between attempts
+const ANGLE_STEP: f64 = 5.0;
+
+/// Smallest vertex budget of one refinement round under a timeout
+const ROUND_VERTICES: usize = 1000;
+
+/// `triangulate_impl()` reusing the allocations of `cdt`, whose previous
+/// contents are cleared.
+pub(crate) fn triangulate_into(
+    cdt: &mut Cdt,
+    outer: Vec<(f64, f64)>,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    options: &TriangulateOptions,
+) -> Result<TriangulationResult, Box<dyn std::error::Error>> {
+    let deadline = match options.timeout {
+        Some(timeout) if timeout.is_nan() || timeout <= 0.0 => {
+            return Err(format!("timeout must be positive, got {}", timeout).into());
+        }
+        Some(timeout) => Some(Instant::now() + Duration::from_secs_f64(timeout)),
+        None => None,
+    };
+    validate_edge_length(options.max_edge_length)?;
+    let Some(mut angle) = options.min_angle.filter(|_| options.relax_min_angle) else {
+        return triangulate_attempt(cdt, outer, holes, options, options.min_angle, deadline);
+    };
+
+    // Ruppert refinement may not terminate for angle limits that small input
+    // angles make unreachable; spade then stops at its vertex limit
+    loop {
+        let mut result = triangulate_attempt(cdt, outer.clone(), holes.clone(), options, Some(angle), deadline)?;
+        if !matches!(result.diagnostics.refinement, Refinement::VertexLimit) || angle <= ANGLE_STEP {
+            result.min_angle_used = Some(angle);
+            return Ok(result);
+        }
+        let relaxed = (angle - ANGLE_STEP).max(ANGLE_STEP);
+        logging::warning(|| format!("refinement stalled with min_angle={}°, retrying with {}°", angle, relaxed));
+        angle = relaxed;
+    }
+}
+
+/// One triangulation of the input with the angle limit `min_angle` in place
+/// of the one in `options`, refining until `deadline` at most.
+fn triangulate_attempt(
+    cdt: &mut Cdt,
+    outer: Vec<(f64, f64)>,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    options: &TriangulateOptions,
     min_angle: Option<f64>,
-    triangulate_holes: bool,
+    deadline: Option<Instant>,
 ) -> Result<TriangulationResult, Box<dyn std::error::Error>> {
-    let holes = holes.unwrap_or_default();
+    let (mut outer, mut holes) = (outer, holes.unwrap_or_default());
+    let TriangulateOptions {
+        max_edge_length,
+        min_angle: _,
+        triangulate_holes,
+        ref region_sizes,
+        ref protected_segments,
+        ref segment_markers,
+        ref vertex_attributes,
+        ref boundary_layers,
+        grid_size,
+        sliver_tolerance,
+        relax_min_angle: _,
+        timeout,
+        return_hole_triangles,
+        return_hull_triangles,
+        clockwise,
+        classify_edges,
+        ref no_refine_zones,
+        ref point_sizes,
+        ref line_sizes,
+        boundary_grading,
+        ref background_mesh,
+        ref background_sizes,
+        ref density_sizing,
+    } = *options;
+    let mut zones = no_refine_zones.clone();
+    let mut size_field = SizeField {
+        global: max_edge_length,
+        points: point_sizes.clone(),
+        lines: line_sizes.clone(),
+        boundary: None,
+        background: match (background_mesh, background_sizes) {
+            (Some(mesh), Some(sizes)) => Some(BackgroundSizes::new(mesh, sizes)?),
+            (None, None) => None,
+            _ => return Err("background_mesh and background_sizes must be given together".into()),
+        },
+        density: density_sizing
+            .as_ref()
+            .map(|(points, k, scale)| DensitySizes::new(points, *k, *scale))
+            .transpose()?,
+    };
+    size_field.validate()?;
+
+    // Snap rounding: vertices snapped together are merged on insertion and
+    // the zero-length segments between them skipped, so numbering is kept
+    if let Some(grid) = grid_size {
+        if grid.is_nan() || grid <= 0.0 {
+            return Err(format!("grid_size must be positive, got {}", grid).into());
+        }
+        for point in outer.iter_mut().chain(holes.iter_mut().flatten()).chain(zones.iter_mut().flatten()) {
+            *point = geometry::snap(*point, grid);
+        }
+    }
+    if let Some((h0, slope)) = boundary_grading {
+        let rings: Vec<&[(f64, f64)]> = std::iter::once(&outer).chain(&holes).map(|ring| ring.as_slice()).collect();
+        size_field.boundary = Some(BoundaryGrading::new(h0, slope, &rings)?);
+    }
+    let layer_edge_length = match (boundary_layers, max_edge_length) {
+        (None, _) => None,
+        (Some(_), None) => return Err("boundary_layers requires max_edge_length".into()),
+        (Some(_), Some(_)) if triangulate_holes => {
+            return Err("boundary_layers cannot be combined with triangulate_holes".into());
+        }
+        (Some(layers), Some(max_edge)) => Some((layers, max_edge)),
+    };
+    if !zones.is_empty() && triangulate_holes && !holes.is_empty() {
+        return Err("no_refine_zones cannot be combined with triangulate_holes".into());
+    }
+    if let Some(zone) = zones.iter().find(|zone| zone.len() < 3) {
+        return Err(format!("no-refine zones need at least 3 vertices, got {}", zone.len()).into());
+    }
 
     // Build vertex list and constraint edges
     let mut vertices = Vec::new();
@@ -98,52 +651,277 @@ fn triangulate_impl(
         }
     }
 
+    if let Some(attributes) = vertex_attributes {
+        attributes::check_lengths(attributes, vertices.len())?;
+    }
+
+    let mut diagnostics = Diagnostics {
+        clockwise_rings: std::iter::once(&outer)
+            .chain(&holes)
+            .enumerate()
+            .filter(|(_, ring)| geometry::signed_area(ring) < 0.0)
+            .map(|(k, _)| k)
+            .collect(),
+        ..Diagnostics::default()
+    };
+
     // Create CDT using incremental insertion
-    let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::default();
+    cdt.clear();
     let mut vertex_handles = Vec::new();
 
-    for vertex in vertices {
+    for (i, vertex) in vertices.into_iter().enumerate() {
+        let count = cdt.num_vertices();
         let handle = cdt.insert(vertex)?;
+        if cdt.num_vertices() == count {
+            diagnostics.duplicate_vertices.push(i);
+        }
         vertex_handles.push(handle);
     }
+    if !diagnostics.duplicate_vertices.is_empty() {
+        logging::info(|| format!("merged {} duplicate input vertices", diagnostics.duplicate_vertices.len()));
+    }
+
+    // Expand per-ring markers to one marker per segment
+    let segment_markers = match segment_markers {
+        Some(markers) if markers.len() == edges.len() => Some(markers.clone()),
+        Some(markers) if markers.len() == holes.len() + 1 => Some(
+            std::iter::once(outer.len())
+                .chain(holes.iter().map(|hole| hole.len()))
+                .zip(markers)
+                .flat_map(|(count, &marker)| vec![marker; count])
+                .collect::<Vec<i64>>(),
+        ),
+        Some(markers) => {
+            return Err(format!(
+                "expected {} segment markers or {} ring markers, got {}",
+                edges.len(),
+                holes.len() + 1,
+                markers.len()
+            )
+            .into());
+        }
+        None => None,
+    };
+
+    let protected: HashSet<usize> = protected_segments.iter().copied().collect();
+    if let Some(&segment) = protected.iter().find(|&&segment| segment >= edges.len()) {
+        return Err(format!("protected segment {} out of range ({} input segments)", segment, edges.len()).into());
+    }
+
+    // Attribute values of vertices added from here on are interpolated in this
+    // copy, which gets the input segments as plain constraints
+    let mut attribute_base = vertex_attributes.as_ref().map(|_| cdt.clone());
 
     // Add constraint edges
     let has_constraints = !edges.is_empty();
     if has_constraints {
-        for [i, j] in &edges {
+        for (segment, [i, j]) in edges.iter().enumerate() {
             if *i != *j && *i < vertex_handles.len() && *j < vertex_handles.len() {
                 let vi = vertex_handles[*i];
                 let vj = vertex_handles[*j];
                 if vi != vj {
-                    cdt.add_constraint(vi, vj);
+                    if let Some(base) = &mut attribute_base {
+                        if base.can_add_constraint(vi, vj) {
+                            base.add_constraint(vi, vj);
+                        }
+                    }
+                    match max_edge_length {
+                        Some(max_edge)
+                            if layer_edge_length.is_some_and(|(layers, _)| layers.segments.contains(&segment)) =>
+                        {
+                            sizing::add_subdivided_constraint(cdt, vi, vj, max_edge)?;
+                        }
+                        _ if !size_field.is_empty() && !protected.contains(&segment) => {
+                            sizing::add_graded_constraint(cdt, vi, vj, &size_field)?;
+                        }
+                        // Protected segments are kept whole by the refinement below,
+                        // so the splittable ones are subdivided here instead
+                        Some(max_edge) if !protected.is_empty() && !protected.contains(&segment) => {
+                            sizing::add_subdivided_constraint(cdt, vi, vj, max_edge)?;
+                        }
+                        _ => {
+                            cdt.add_constraint(vi, vj);
+                        }
+                    }
+                } else {
+                    diagnostics.degenerate_segments.push(segment);
                 }
+            } else {
+                diagnostics.degenerate_segments.push(segment);
             }
         }
     }
 
-    // Apply refinement if needed
-    let should_exclude_holes = !triangulate_holes && !holes.is_empty();
-    let excluded_faces = if has_constraints && (max_edge_length.is_some() || min_angle.is_some() || should_exclude_holes) {
-        let mut params = RefinementParameters::<f64>::new()
-            .exclude_outer_faces(should_exclude_holes);
+    logging::debug(|| format!("{} input segments became {} constraint edges", edges.len(), cdt.num_constraints()));
 
-        if let Some(max_edge) = max_edge_length {
-            // Convert edge length to area: area ≈ 0.433 * edge²
-            let max_area = 0.433 * max_edge * max_edge;
-            params = params.with_max_allowed_area(max_area);
+    // Boundary layers are closed off by constraints so that, as "outer" faces,
+    // they are excluded from refinement below
+    let strips = match layer_edge_length {
+        Some((layers, max_edge)) => {
+            let rings: Vec<&[(f64, f64)]> = std::iter::once(&outer).chain(&holes).map(|ring| ring.as_slice()).collect();
+            layers::insert_boundary_layers(cdt, &rings, layers, max_edge)?
         }
+        None => Vec::new(),
+    };
 
-        if let Some(angle) = min_angle {
-            params = params.with_angle_limit(AngleLimit::from_deg(angle));
+    // No-refine zones are closed off by constraints too, so that refinement
+    // treats them as outside; their faces are put back into the output below
+    for zone in &zones {
+        let handles = zone.iter().map(|&(x, y)| cdt.insert(Point2::new(x, y))).collect::<Result<Vec<_>, _>>()?;
+        for (k, &from) in handles.iter().enumerate() {
+            let to = handles[(k + 1) % handles.len()];
+            match max_edge_length {
+                Some(max_edge) if from != to && cdt.can_add_constraint(from, to) => {
+                    sizing::add_subdivided_constraint(cdt, from, to, max_edge)?
+                }
+                _ => regions::add_constraint_checked(cdt, from, to)?,
+            }
+        }
+    }
+
+    // Seed locally refined regions before the global refinement pass
+    if !region_sizes.is_empty() {
+        sizing::seed_region_sizes(cdt, region_sizes)?;
+    }
+
+    // Spade only knows a global area limit, so local sizes are met by
+    // splitting edges up front; quality refinement then grades around them
+    if !size_field.is_empty() {
+        let inside = |p: Point2<f64>| {
+            let p = (p.x, p.y);
+            geometry::point_in_polygon(p, &outer)
+                && (triangulate_holes || !holes.iter().any(|hole| geometry::point_in_polygon(p, hole)))
+                && !zones.iter().any(|zone| geometry::point_in_polygon(p, zone))
+                && !layers::in_strips(p, &strips)
+        };
+        if !sizing::refine_to_size(cdt, &size_field, inside)? {
+            logging::warning(|| format!("local sizes stopped at {} vertices before every edge met them", cdt.num_vertices()));
         }
+    }
+
+    // Apply refinement if needed
+    let should_exclude_holes = !triangulate_holes && !holes.is_empty();
+    let exclude_outer = should_exclude_holes || !strips.is_empty() || !zones.is_empty();
+    let needs_refinement =
+        max_edge_length.is_some() || min_angle.is_some() || !region_sizes.is_empty() || !size_field.is_empty();
+
+    // Spade excludes faces by the parity of constraint edges crossed from the
+    // outside. An edge shared by two rings (a courtyard open to the facade
+    // line, say) is a single constraint and breaks that count, so in this
+    // case faces are classified by location instead
+    let touching = should_exclude_holes
+        && constraints::check_constraints_impl(&outer, &holes, &[])
+            .iter()
+            .any(|conflict| conflict.kind == "overlap");
+    if touching && !zones.is_empty() {
+        return Err("no_refine_zones cannot be combined with rings that share edges".into());
+    }
+    if touching {
+        logging::info(|| "rings share edges; excluding holes by location".to_string());
+    }
 
-        let result = cdt.refine(params);
-        result.excluded_faces
+    let mut timed_out = false;
+    let excluded_faces = if has_constraints && (needs_refinement || exclude_outer) {
+        let params = || {
+            let params = refinement_parameters(max_edge_length, min_angle).exclude_outer_faces(exclude_outer && !touching);
+            if protected.is_empty() {
+                params
+            } else {
+                params.keep_constraint_edges()
+            }
+        };
+
+        let before = cdt.num_vertices();
+        let (excluded_faces, complete) = match deadline {
+            Some(deadline) => {
+                let (excluded_faces, complete, stopped) = refine_until(cdt, params, deadline);
+                timed_out = stopped;
+                (excluded_faces, complete)
+            }
+            None => {
+                let result = cdt.refine(params());
+                (result.excluded_faces, result.refinement_complete)
+            }
+        };
+        if timed_out {
+            logging::warning(|| {
+                format!("refinement timed out after {} s with {} vertices", timeout.unwrap_or_default(), cdt.num_vertices())
+            });
+        } else {
+            logging::refinement(before, cdt.num_vertices(), complete);
+        }
+        if needs_refinement {
+            diagnostics.refinement = match (complete, timed_out) {
+                (true, _) => Refinement::Complete,
+                (false, true) => Refinement::Timeout,
+                (false, false) => Refinement::VertexLimit,
+            };
+        }
+        excluded_faces
     } else {
         Vec::new()
     };
 
-    let excluded_set: HashSet<_> = excluded_faces.into_iter().collect();
+    let excluded_set: HashSet<_> = if touching {
+        cdt.inner_faces()
+            .filter(|face| {
+                let center = face.center();
+                let center = (center.x, center.y);
+                !geometry::point_in_polygon(center, &outer)
+                    || holes.iter().any(|hole| geometry::point_in_polygon(center, hole))
+            })
+            .map(|face| face.fix())
+            .collect()
+    } else {
+        excluded_faces.into_iter().collect()
+    };
+    let faces: &Cdt = cdt;
+    let keep_face = |face: FixedFaceHandle<InnerTag>| {
+        let center = faces.face(face).center();
+        !excluded_set.contains(&face)
+            || layers::in_strips((center.x, center.y), &strips)
+            || zones.iter().any(|zone| geometry::point_in_polygon((center.x, center.y), zone))
+    };
+
+    diagnostics.split_segments = edges
+        .iter()
+        .enumerate()
+        .filter(|(_, [i, j])| {
+            let (vi, vj) = (vertex_handles[*i], vertex_handles[*j]);
+            vi != vj && segments::trace_segment(cdt, vi, vj).len() > 1
+        })
+        .map(|(segment, _)| segment)
+        .collect();
+    diagnostics.added_vertices = cdt.num_vertices() + diagnostics.duplicate_vertices.len() - vertex_handles.len();
+
+    // Propagate segment markers to the constraint edges each segment was split into
+    let edge_marker_map = segment_markers.as_ref().map(|markers| {
+        let mut map = HashMap::new();
+        for ([i, j], &marker) in edges.iter().zip(markers) {
+            for edge in segments::trace_segment(cdt, vertex_handles[*i], vertex_handles[*j]) {
+                map.insert(cdt.directed_edge(edge).as_undirected().fix(), marker);
+            }
+        }
+        map
+    });
+    let edge_ring_map = classify_edges.then(|| {
+        let ring_lengths = std::iter::once(outer.len()).chain(holes.iter().map(Vec::len));
+        let segment_rings = ring_lengths.enumerate().flat_map(|(ring, count)| std::iter::repeat(ring as i64).take(count));
+        let mut map = HashMap::new();
+        for ([i, j], ring) in edges.iter().zip(segment_rings) {
+            for edge in segments::trace_segment(cdt, vertex_handles[*i], vertex_handles[*j]) {
+                map.insert(cdt.directed_edge(edge).as_undirected().fix(), ring);
+            }
+        }
+        map
+    });
+
+    let output_attributes = vertex_attributes.as_ref().zip(attribute_base.as_ref()).map(|(attributes, base)| {
+        let mut output = attributes::transfer(attributes, &vertex_handles, cdt.num_vertices());
+        let positions: Vec<Point2<f64>> = cdt.vertices().map(|v| v.position()).collect();
+        attributes::interpolate_new_vertices(&mut output, base, &positions);
+        output
+    });
 
     // Extract output vertices
     let mut point_map = HashMap::new();
@@ -157,27 +935,158 @@ fn triangulate_impl(
 
     // Extract triangles (exclude holes if requested)
     let mut output_triangles = Vec::new();
+    let mut hole_triangles = return_hole_triangles.then(Vec::new);
+    let mut hull_triangles = return_hull_triangles.then(Vec::new);
     for face in cdt.inner_faces() {
-        if !excluded_set.contains(&face.fix()) {
-            let vertices: [_; 3] = face.vertices().map(|v| point_map[&v.fix()]);
+        let vertices: [_; 3] = face.vertices().map(|v| point_map[&v.fix()]);
+        if let Some(hull_triangles) = &mut hull_triangles {
+            let center = face.center();
+            if !geometry::point_in_polygon((center.x, center.y), &outer) {
+                hull_triangles.push((vertices[0], vertices[1], vertices[2]));
+                diagnostics.excluded_faces += 1;
+                continue;
+            }
+        }
+        if keep_face(face.fix()) {
             output_triangles.push((vertices[0], vertices[1], vertices[2]));
+        } else {
+            diagnostics.excluded_faces += 1;
+            if let Some(hole_triangles) = &mut hole_triangles {
+                let center = face.center();
+                if let Some(hole) = holes.iter().position(|hole| geometry::point_in_polygon((center.x, center.y), hole)) {
+                    hole_triangles.push(((vertices[0], vertices[1], vertices[2]), hole));
+                }
+            }
         }
     }
 
     // Extract constraint edges
     let mut output_edges = Vec::new();
+    let mut output_markers = Vec::new();
+    let mut output_rings = Vec::new();
     for edge in cdt.undirected_edges() {
         if edge.is_constraint_edge() {
+            // Edges shared by touching rings may have no triangle left on either side
+            let directed = edge.as_directed();
+            if touching && ![directed, directed.rev()].iter().any(|e| e.face().as_inner().is_some_and(|face| keep_face(face.fix()))) {
+                continue;
+            }
             let [v0, v1] = edge.vertices().map(|v| point_map[&v.fix()]);
             output_edges.push((v0, v1));
+            if let Some(map) = &edge_marker_map {
+                output_markers.push(map.get(&edge.fix()).copied().unwrap_or(0));
+            }
+            if let Some(map) = &edge_ring_map {
+                output_rings.push(map.get(&edge.fix()).copied().unwrap_or(-1));
+            }
         }
     }
 
-    Ok(TriangulationResult {
+    let mut result = TriangulationResult {
         vertices: output_vertices,
         triangles: output_triangles,
         edges: output_edges,
-    })
+        edge_markers: edge_marker_map.map(|_| output_markers),
+        edge_rings: edge_ring_map.map(|_| output_rings),
+        vertex_attributes: output_attributes,
+        input_vertices: vertex_handles.len() - diagnostics.duplicate_vertices.len(),
+        diagnostics,
+        removed_slivers: None,
+        achieved_min_angle: None,
+        min_angle_used: None,
+        timed_out: timeout.map(|_| timed_out),
+        hole_triangles,
+        hull_triangles,
+        edge_origins: None,
+    };
+    if let Some(tolerance) = sliver_tolerance {
+        let removed = slivers::remove_slivers(&mut result, tolerance)?;
+        logging::debug(|| format!("removed {} slivers", removed));
+        result.removed_slivers = Some(removed);
+    }
+    if clockwise {
+        let reverse = |triangle: &mut (usize, usize, usize)| std::mem::swap(&mut triangle.1, &mut triangle.2);
+        result.triangles.iter_mut().for_each(reverse);
+        result.hole_triangles.iter_mut().flatten().for_each(|(triangle, _)| reverse(triangle));
+        result.hull_triangles.iter_mut().flatten().for_each(reverse);
+    }
+    if let Some(edge_rings) = &result.edge_rings {
+        let rings: HashMap<(usize, usize), i64> =
+            result.edges.iter().zip(edge_rings).map(|(&(u, v), &ring)| ((u.min(v), u.max(v)), ring)).collect();
+        let mut seen = HashSet::new();
+        let mut origins = Vec::new();
+        for &(a, b, c) in &result.triangles {
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                let key = (u.min(v), u.max(v));
+                if seen.insert(key) {
+                    let (origin, ring) = match rings.get(&key) {
+                        Some(&0) => ("outer", 0),
+                        Some(&ring) if ring > 0 => ("hole", ring),
+                        Some(_) => ("constraint", -1),
+                        None => ("delaunay", -1),
+                    };
+                    origins.push(((u, v), origin, ring));
+                }
+            }
+        }
+        result.edge_origins = Some(origins);
+    }
+    if min_angle.is_some() {
+        let distance = |u: usize, v: usize| {
+            let (p, q) = (result.vertices[u], result.vertices[v]);
+            (p.0 - q.0).hypot(p.1 - q.1)
+        };
+        result.achieved_min_angle = Some(
+            result
+                .triangles
+                .iter()
+                .flat_map(|&(a, b, c)| statistics::angles([distance(b, c), distance(c, a), distance(a, b)]))
+                .min_by(f64::total_cmp),
+        );
+    }
+    Ok(result)
+}
+
+/// Refine in rounds with growing vertex budgets until refinement completes,
+/// stalls or `deadline` passes. Returns the faces excluded by the last round,
+/// whether refinement completed and whether the deadline stopped it.
+fn refine_until(
+    cdt: &mut Cdt,
+    params: impl Fn() -> RefinementParameters<f64>,
+    deadline: Instant,
+) -> (Vec<FixedFaceHandle<InnerTag>>, bool, bool) {
+    loop {
+        // Each round rescans the whole triangulation, so budgets grow with
+        // its size to keep that overhead to a constant factor
+        let count = cdt.num_vertices();
+        let result = cdt.refine(params().with_max_additional_vertices(count.max(ROUND_VERTICES)));
+        if result.refinement_complete || cdt.num_vertices() == count {
+            return (result.excluded_faces, result.refinement_complete, false);
+        }
+        if Instant::now() >= deadline {
+            return (result.excluded_faces, false, true);
+        }
+    }
+}
+
+/// Build refinement parameters from the user-facing size and angle options.
+pub(crate) fn refinement_parameters(
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+) -> RefinementParameters<f64> {
+    let mut params = RefinementParameters::<f64>::new();
+
+    if let Some(max_edge) = max_edge_length {
+        // Convert edge length to area: area ≈ 0.433 * edge²
+        let max_area = 0.433 * max_edge * max_edge;
+        params = params.with_max_allowed_area(max_area);
+    }
+
+    if let Some(angle) = min_angle {
+        params = params.with_angle_limit(AngleLimit::from_deg(angle));
+    }
+
+    params
 }
 
 /// pyspade - Fast 2D Delaunay triangulation for Python
@@ -194,5 +1103,73 @@ fn triangulate_impl(
 #[pymodule]
 fn pyspade(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(triangulate, m)?)?;
+    m.add_function(wrap_pyfunction!(voronoi::voronoi, m)?)?;
+    m.add_function(wrap_pyfunction!(voronoi::largest_empty_circle, m)?)?;
+    m.add_function(wrap_pyfunction!(graphs::emst, m)?)?;
+    m.add_function(wrap_pyfunction!(graphs::knn_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(graphs::gabriel_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(graphs::relative_neighborhood_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(anisotropic::triangulate_anisotropic, m)?)?;
+    m.add_function(wrap_pyfunction!(regions::triangulate_regions, m)?)?;
+    m.add_function(wrap_pyfunction!(regions::mesh_subdomains, m)?)?;
+    m.add_function(wrap_pyfunction!(terrain::mesh_terrain, m)?)?;
+    m.add_function(wrap_pyfunction!(roads::mesh_polylines, m)?)?;
+    m.add_function(wrap_pyfunction!(terrain::tin, m)?)?;
+    m.add_function(wrap_pyfunction!(terrain::grid_to_tin, m)?)?;
+    m.add_function(wrap_pyfunction!(raster::rasterize, m)?)?;
+    m.add_function(wrap_pyfunction!(clip::clip, m)?)?;
+    m.add_function(wrap_pyfunction!(merge::merge, m)?)?;
+    m.add_function(wrap_pyfunction!(connectivity::half_edges, m)?)?;
+    m.add_function(wrap_pyfunction!(connectivity::vertex_adjacency, m)?)?;
+    m.add_function(wrap_pyfunction!(rendering::optimize_vertex_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(rendering::planar_uvs, m)?)?;
+    m.add_function(wrap_pyfunction!(rendering::vertex_normals, m)?)?;
+    m.add_function(wrap_pyfunction!(quads::quadrangulate, m)?)?;
+    m.add_function(wrap_pyfunction!(thinning::thin_points, m)?)?;
+    m.add_function(wrap_pyfunction!(remesh::remesh, m)?)?;
+    m.add_function(wrap_pyfunction!(editing::split_edge, m)?)?;
+    m.add_function(wrap_pyfunction!(editing::collapse_edge, m)?)?;
+    m.add_function(wrap_pyfunction!(editing::flip_edge, m)?)?;
+    m.add_function(wrap_pyfunction!(validation::validate_mesh, m)?)?;
+    m.add_function(wrap_pyfunction!(statistics::mesh_statistics, m)?)?;
+    m.add_function(wrap_pyfunction!(distance::mesh_distance, m)?)?;
+    m.add_function(wrap_pyfunction!(sampling::sample_points, m)?)?;
+    m.add_function(wrap_pyfunction!(polygons::polygon_area, m)?)?;
+    m.add_function(wrap_pyfunction!(polygons::polygon_centroid, m)?)?;
+    m.add_function(wrap_pyfunction!(polygons::polygon_is_ccw, m)?)?;
+    m.add_function(wrap_pyfunction!(polygons::polygon_contains_point, m)?)?;
+    m.add_function(wrap_pyfunction!(constraints::distance_to_constraints, m)?)?;
+    m.add_function(wrap_pyfunction!(intersect::intersect_segment, m)?)?;
+    m.add_class::<builder::TriangulationBuilder>()?;
+    m.add_class::<triangulator::Triangulator>()?;
+    m.add_class::<interpolator::Interpolator>()?;
+    m.add_function(wrap_pyfunction!(constraints::check_constraints, m)?)?;
+    m.add_function(wrap_pyfunction!(polygons::points_in_polygon, m)?)?;
+    m.add_function(wrap_pyfunction!(validation::validate, m)?)?;
+    m.add_function(wrap_pyfunction!(repair::repair, m)?)?;
+    m.add_function(wrap_pyfunction!(parameters::suggest_parameters, m)?)?;
+    m.add_function(wrap_pyfunction!(rendering::orient_facets, m)?)?;
+    m.add_function(wrap_pyfunction!(connectivity::boundary_levels, m)?)?;
+    m.add_function(wrap_pyfunction!(connectivity::connected_components, m)?)?;
+    m.add_function(wrap_pyfunction!(partition::partition, m)?)?;
+    m.add_function(wrap_pyfunction!(connectivity::to_csr, m)?)?;
+    m.add_function(wrap_pyfunction!(elements::quadratic_elements, m)?)?;
+    m.add_function(wrap_pyfunction!(partition::halo, m)?)?;
+    m.add_function(wrap_pyfunction!(connectivity::dual_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(power::power_diagram, m)?)?;
+    m.add_function(wrap_pyfunction!(smoothing::smooth, m)?)?;
+    m.add_function(wrap_pyfunction!(adaptive::refine_adaptive, m)?)?;
+    m.add_function(wrap_pyfunction!(terrain::adaptive_tin, m)?)?;
+    m.add_function(wrap_pyfunction!(terrain::simplify_tin, m)?)?;
+    m.add_function(wrap_pyfunction!(terrain::lods, m)?)?;
+    m.add_function(wrap_pyfunction!(tiling::triangulate_tiled, m)?)?;
+    m.add_function(wrap_pyfunction!(fields::vertex_gradients, m)?)?;
+    m.add_function(wrap_pyfunction!(fields::vertex_curvature, m)?)?;
+    m.add_function(wrap_pyfunction!(drainage::flow_accumulation, m)?)?;
+    m.add_function(wrap_pyfunction!(intersect::line_of_sight, m)?)?;
+    m.add_function(wrap_pyfunction!(intersect::lines_of_sight, m)?)?;
+    m.add_function(wrap_pyfunction!(contours::filled_contours, m)?)?;
+    m.add_function(wrap_pyfunction!(contours::isobands, m)?)?;
+    m.add_function(wrap_pyfunction!(rendering::hillshade, m)?)?;
     Ok(())
 }
@@ -0,0 +1,58 @@
+//! Messages for Python's `logging` module, under the `pyspade` logger.
+//!
+//! The functions acquire the GIL themselves, so they may be called from code
+//! running inside `allow_threads`. Failing to log never fails the caller.
+
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+
+// Levels of Python's logging module
+const DEBUG: u32 = 10;
+const INFO: u32 = 20;
+const WARNING: u32 = 30;
+
+static LOGGER: GILOnceCell<PyObject> = GILOnceCell::new();
+
+pub(crate) fn debug(message: impl FnOnce() -> String) {
+    log(DEBUG, message);
+}
+
+pub(crate) fn info(message: impl FnOnce() -> String) {
+    log(INFO, message);
+}
+
+pub(crate) fn warning(message: impl FnOnce() -> String) {
+    log(WARNING, message);
+}
+
+/// Report a refinement pass that grew the triangulation from `before` to
+/// `after` vertices; `complete` is false when spade stopped at its vertex
+/// limit before meeting every size and angle target.
+pub(crate) fn refinement(before: usize, after: usize, complete: bool) {
+    info(|| format!("refinement added {} vertices", after - before));
+    if !complete {
+        warning(|| {
+            format!(
+                "refinement stopped at the vertex limit with {} vertices; some triangles miss the size or angle targets",
+                after
+            )
+        });
+    }
+}
+
+/// `message` is only built when the logger is enabled for `level`.
+fn log(level: u32, message: impl FnOnce() -> String) {
+    Python::with_gil(|py| {
+        let _ = (|| -> PyResult<()> {
+            let logger = LOGGER
+                .get_or_try_init(py, || {
+                    Ok::<_, PyErr>(py.import_bound("logging")?.call_method1("getLogger", ("pyspade",))?.unbind())
+                })?
+                .bind(py);
+            if logger.call_method1("isEnabledFor", (level,))?.extract()? {
+                logger.call_method1("log", (level, message()))?;
+            }
+            Ok(())
+        })();
+    });
+}
@@ -0,0 +1,292 @@
+use pyo3::prelude::*;
+use spade::Point2;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::mesh::Mesh;
+use crate::sizing::segment_distance_2;
+
+/// Stitch adjacent meshes (e.g. tiles) into a single mesh.
+///
+/// Boundary vertices of the input meshes that lie within `tolerance` of each
+/// other are welded. Where neighboring tiles discretize a shared boundary
+/// differently, the triangles along it are split at the other tile's boundary
+/// vertices so the result has no T-junctions.
+///
+/// Args:
+///     meshes (list): Mesh dicts with 'vertices' and 'triangles'
+///     tolerance (float, optional): Weld distance. Default: 1e-6
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'sources': Index of the input mesh each triangle came from
+///         - 'unmatched': (i, j) boundary edges that run along another mesh's
+///           boundary but could not be stitched to it
+///
+/// Example:
+///     >>> import pyspade
+///     >>> left = pyspade.triangulate([(0, 0), (50, 0), (50, 100), (0, 100)], max_edge_length=5.0)
+///     >>> right = pyspade.triangulate([(50, 0), (100, 0), (100, 100), (50, 100)], max_edge_length=8.0)
+///     >>> merged = pyspade.merge([left, right], tolerance=1e-6)
+///     >>> assert not merged['unmatched']
+#[pyfunction]
+#[pyo3(signature = (meshes, tolerance=1e-6))]
+pub fn merge(meshes: Vec<Mesh>, tolerance: f64) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = merge_impl(&meshes, tolerance).map_err(crate::to_py_err)?;
+
+        let mut output = result.mesh.into_dict(py);
+        output.insert("sources".to_string(), result.sources.into_py(py));
+        output.insert("unmatched".to_string(), result.unmatched.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct MergeResult {
+    pub mesh: Mesh,
+    pub sources: Vec<usize>,
+    pub unmatched: Vec<(usize, usize)>,
+}
+
+pub(crate) fn merge_impl(meshes: &[Mesh], tolerance: f64) -> Result<MergeResult, Box<dyn std::error::Error>> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err(format!("tolerance must be positive, got {}", tolerance).into());
+    }
+
+    let mut vertices: Vec<(f64, f64, f64)> = Vec::new();
+    let mut triangles: Vec<(usize, usize, usize)> = Vec::new();
+    let mut sources: Vec<usize> = Vec::new();
+    let mut boundary_vertices: Vec<usize> = Vec::new();
+    for (source, mesh) in meshes.iter().enumerate() {
+        let offset = vertices.len();
+        vertices.extend_from_slice(&mesh.vertices);
+        let shifted: Vec<(usize, usize, usize)> = mesh
+            .triangles
+            .iter()
+            .map(|&(a, b, c)| (a + offset, b + offset, c + offset))
+            .collect();
+        let mut on_boundary: Vec<usize> = boundary_edges(&shifted).into_iter().flat_map(|(a, b, _)| [a, b]).collect();
+        on_boundary.sort_unstable();
+        on_boundary.dedup();
+        boundary_vertices.extend(on_boundary);
+        sources.resize(sources.len() + shifted.len(), source);
+        triangles.extend(shifted);
+    }
+
+    // Weld boundary vertices onto the first vertex within tolerance
+    let mut weld: Vec<usize> = (0..vertices.len()).collect();
+    let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    let cell_of = |(x, y, _): (f64, f64, f64)| ((x / tolerance).floor() as i64, (y / tolerance).floor() as i64);
+    for &v in &boundary_vertices {
+        let (cx, cy) = cell_of(vertices[v]);
+        let target = (cx - 1..=cx + 1)
+            .flat_map(|x| (cy - 1..=cy + 1).map(move |y| (x, y)))
+            .filter_map(|key| cells.get(&key))
+            .flatten()
+            .copied()
+            .find(|&w| distance(vertices[v], vertices[w]) <= tolerance);
+        match target {
+            Some(w) => weld[v] = w,
+            None => cells.entry((cx, cy)).or_default().push(v),
+        }
+    }
+
+    let mut welded_triangles = Vec::with_capacity(triangles.len());
+    let mut welded_sources = Vec::with_capacity(triangles.len());
+    for (&(a, b, c), &source) in triangles.iter().zip(&sources) {
+        let (a, b, c) = (weld[a], weld[b], weld[c]);
+        if a != b && b != c && c != a {
+            welded_triangles.push((a, b, c));
+            welded_sources.push(source);
+        }
+    }
+
+    // Boundary vertices of other meshes lying on a boundary edge are
+    // T-junctions; split the edge's triangle at them
+    let boundary = boundary_edges(&welded_triangles);
+    let candidates: Vec<usize> = boundary_vertices
+        .iter()
+        .map(|&v| weld[v])
+        .collect::<BTreeSet<usize>>()
+        .into_iter()
+        .collect();
+    let cell = mean_length(&vertices, &boundary).max(tolerance);
+    let mut candidate_grid = BucketGrid::new(cell);
+    for &v in &candidates {
+        let r = point(vertices[v]);
+        candidate_grid.insert(v, (r.x, r.y), (r.x, r.y));
+    }
+    let mut splits: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for &(a, b, _) in &boundary {
+        let (p, q) = (point(vertices[a]), point(vertices[b]));
+        let (min, max) = expanded_box(p, q, tolerance);
+        let mut inner: Vec<(f64, usize)> = candidate_grid
+            .query(min, max)
+            .filter(|&v| v != a && v != b)
+            .filter_map(|v| {
+                let r = point(vertices[v]);
+                let s = projection(p, q, r);
+                let on_edge = s > 0.0 && s < 1.0 && segment_distance_2(r, p, q) <= tolerance * tolerance;
+                on_edge.then_some((s, v))
+            })
+            .collect();
+        if !inner.is_empty() {
+            inner.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.1.cmp(&y.1)));
+            splits.insert((a, b), inner.into_iter().map(|(_, v)| v).collect());
+        }
+    }
+
+    let mut queue: Vec<(usize, usize, usize, usize)> = welded_triangles
+        .iter()
+        .zip(&welded_sources)
+        .map(|(&(a, b, c), &source)| (a, b, c, source))
+        .collect();
+    let mut final_triangles = Vec::with_capacity(queue.len());
+    let mut final_sources = Vec::with_capacity(queue.len());
+    while let Some((a, b, c, source)) = queue.pop() {
+        let rotations = [(a, b, c), (b, c, a), (c, a, b)];
+        match rotations.iter().find_map(|&(u, v, w)| splits.get(&(u, v)).map(|inner| (u, v, w, inner))) {
+            Some((u, v, w, inner)) => {
+                let chain: Vec<usize> = std::iter::once(u).chain(inner.iter().copied()).chain([v]).collect();
+                for pair in chain.windows(2) {
+                    queue.push((pair[0], pair[1], w, source));
+                }
+            }
+            None => {
+                final_triangles.push((a, b, c));
+                final_sources.push(source);
+            }
+        }
+    }
+
+    // Remaining boundary edges that run along another mesh's boundary
+    let remaining = boundary_edges(&final_triangles);
+    let mut edge_grid = BucketGrid::new(cell);
+    for (e, &(a, b, _)) in remaining.iter().enumerate() {
+        let (min, max) = expanded_box(point(vertices[a]), point(vertices[b]), tolerance);
+        edge_grid.insert(e, min, max);
+    }
+    let mut unmatched = Vec::new();
+    for &(a, b, t) in &remaining {
+        let (p, q) = (point(vertices[a]), point(vertices[b]));
+        let mid = Point2::new(0.5 * (p.x + q.x), 0.5 * (p.y + q.y));
+        let near_other = edge_grid.query((mid.x, mid.y), (mid.x, mid.y)).any(|e| {
+            let (c, d, u) = remaining[e];
+            final_sources[u] != final_sources[t]
+                && segment_distance_2(mid, point(vertices[c]), point(vertices[d])) <= tolerance * tolerance
+        });
+        if near_other {
+            unmatched.push((a, b));
+        }
+    }
+
+    // Compact, remapping the unmatched edges alongside the triangles
+    let mesh = Mesh::compact(&vertices, &final_triangles);
+    let mut remap: HashMap<usize, usize> = HashMap::new();
+    for (&(a, b, c), &(x, y, z)) in final_triangles.iter().zip(&mesh.triangles) {
+        remap.insert(a, x);
+        remap.insert(b, y);
+        remap.insert(c, z);
+    }
+    let unmatched = unmatched.into_iter().map(|(a, b)| (remap[&a], remap[&b])).collect();
+
+    Ok(MergeResult {
+        mesh,
+        sources: final_sources,
+        unmatched,
+    })
+}
+
+/// Directed edges `(from, to, triangle)` whose reverse is not used by any
+/// other triangle.
+fn boundary_edges(triangles: &[(usize, usize, usize)]) -> Vec<(usize, usize, usize)> {
+    let mut directed: HashSet<(usize, usize)> = HashSet::new();
+    for &(a, b, c) in triangles {
+        directed.extend([(a, b), (b, c), (c, a)]);
+    }
+    triangles
+        .iter()
+        .enumerate()
+        .flat_map(|(t, &(a, b, c))| [(a, b, t), (b, c, t), (c, a, t)])
+        .filter(|&(a, b, _)| !directed.contains(&(b, a)))
+        .collect()
+}
+
+/// Sparse uniform bucket grid over points or boxes, so that each boundary
+/// edge is only tested against the boundary vertices and edges near it.
+struct BucketGrid {
+    cell: f64,
+    buckets: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl BucketGrid {
+    fn new(cell: f64) -> Self {
+        BucketGrid {
+            cell,
+            buckets: HashMap::new(),
+        }
+    }
+
+    fn key(&self, (x, y): (f64, f64)) -> (i64, i64) {
+        ((x / self.cell).floor() as i64, (y / self.cell).floor() as i64)
+    }
+
+    /// Add `item` to every cell overlapping the box `min`-`max`.
+    fn insert(&mut self, item: usize, min: (f64, f64), max: (f64, f64)) {
+        let ((x0, y0), (x1, y1)) = (self.key(min), self.key(max));
+        for x in x0..=x1 {
+            for y in y0..=y1 {
+                self.buckets.entry((x, y)).or_default().push(item);
+            }
+        }
+    }
+
+    /// Items in the cells overlapping the box `min`-`max`; a box item may
+    /// come up more than once.
+    fn query(&self, min: (f64, f64), max: (f64, f64)) -> impl Iterator<Item = usize> + '_ {
+        let ((x0, y0), (x1, y1)) = (self.key(min), self.key(max));
+        (x0..=x1)
+            .flat_map(move |x| (y0..=y1).map(move |y| (x, y)))
+            .filter_map(|key| self.buckets.get(&key))
+            .flatten()
+            .copied()
+    }
+}
+
+/// Bounding box of the segment `p`-`q` grown by `margin` on every side.
+fn expanded_box(p: Point2<f64>, q: Point2<f64>, margin: f64) -> ((f64, f64), (f64, f64)) {
+    (
+        (p.x.min(q.x) - margin, p.y.min(q.y) - margin),
+        (p.x.max(q.x) + margin, p.y.max(q.y) + margin),
+    )
+}
+
+/// Mean planar length of `edges`, 0 for none.
+fn mean_length(vertices: &[(f64, f64, f64)], edges: &[(usize, usize, usize)]) -> f64 {
+    let total: f64 = edges.iter().map(|&(a, b, _)| point_distance(vertices[a], vertices[b])).sum();
+    total / edges.len().max(1) as f64
+}
+
+fn point_distance(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+fn point((x, y, _): (f64, f64, f64)) -> Point2<f64> {
+    Point2::new(x, y)
+}
+
+fn distance(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)).sqrt()
+}
+
+/// Unclamped parameter of the projection of `r` onto the line `p`-`q`.
+fn projection(p: Point2<f64>, q: Point2<f64>, r: Point2<f64>) -> f64 {
+    let (dx, dy) = (q.x - p.x, q.y - p.y);
+    let len_2 = dx * dx + dy * dy;
+    if len_2 == 0.0 {
+        return 0.0;
+    }
+    ((r.x - p.x) * dx + (r.y - p.y) * dy) / len_2
+}
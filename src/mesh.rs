@@ -0,0 +1,207 @@
+//! Triangle meshes passed back in from Python, e.g. the result of `triangulate()`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use spade::Point2;
+use std::collections::HashMap;
+
+use crate::geometry;
+use crate::sizing::in_triangle;
+
+/// A triangle mesh given as a dict with 'vertices' ((x, y) or (x, y, z)
+/// rows), 'triangles' ((i, j, k) rows) and optionally constrained 'edges'
+/// ((i, j) rows). Missing z values are 0.0.
+pub(crate) struct Mesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl<'py> FromPyObject<'py> for Mesh {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let mut vertices = Vec::new();
+        for item in ob.get_item("vertices")?.iter()? {
+            let row: Vec<f64> = item?.extract()?;
+            vertices.push(vertex_row(&row)?);
+        }
+
+        let mut triangles = Vec::new();
+        for item in ob.get_item("triangles")?.iter()? {
+            let row: Vec<usize> = item?.extract()?;
+            match row[..] {
+                [a, b, c] if a < vertices.len() && b < vertices.len() && c < vertices.len() => {
+                    triangles.push((a, b, c))
+                }
+                [_, _, _] => return Err(PyValueError::new_err("mesh triangle references a missing vertex")),
+                _ => return Err(PyValueError::new_err("mesh triangles must have 3 vertex indices")),
+            }
+        }
+
+        let mut edges = Vec::new();
+        if ob.downcast::<PyDict>().is_ok_and(|dict| dict.contains("edges").unwrap_or(false)) {
+            for item in ob.get_item("edges")?.iter()? {
+                let (a, b): (usize, usize) = item?.extract()?;
+                if a >= vertices.len() || b >= vertices.len() {
+                    return Err(PyValueError::new_err("mesh edge references a missing vertex"));
+                }
+                edges.push((a, b));
+            }
+        }
+
+        Ok(Mesh {
+            vertices,
+            triangles,
+            edges,
+        })
+    }
+}
+
+/// A vertex given as (x, y) or (x, y, z), with a missing z as 0.0.
+pub(crate) fn vertex_row(row: &[f64]) -> PyResult<(f64, f64, f64)> {
+    match *row {
+        [x, y] => Ok((x, y, 0.0)),
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(PyValueError::new_err("mesh vertices must have 2 or 3 coordinates")),
+    }
+}
+
+impl Mesh {
+    /// The three corner positions of triangle `t` as (x, y, z) tuples.
+    pub fn corners(&self, t: usize) -> [(f64, f64, f64); 3] {
+        let (a, b, c) = self.triangles[t];
+        [self.vertices[a], self.vertices[b], self.vertices[c]]
+    }
+}
+
+impl Mesh {
+    /// Output dict with 'vertices', 'triangles' and (if any) 'edges', the
+    /// format accepted back by the extractor.
+    pub fn into_dict(self, py: Python) -> HashMap<String, PyObject> {
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), self.vertices.into_py(py));
+        output.insert("triangles".to_string(), self.triangles.into_py(py));
+        if !self.edges.is_empty() {
+            output.insert("edges".to_string(), self.edges.into_py(py));
+        }
+        output
+    }
+
+    /// Mesh made of the given triangles over `vertices`, keeping only the
+    /// vertices they reference (in order of first use).
+    pub fn compact(vertices: &[(f64, f64, f64)], triangles: &[(usize, usize, usize)]) -> Mesh {
+        let mut remap: HashMap<usize, usize> = HashMap::new();
+        let mut mesh = Mesh {
+            vertices: Vec::new(),
+            triangles: Vec::with_capacity(triangles.len()),
+            edges: Vec::new(),
+        };
+        let mut index = |v: usize, mesh: &mut Mesh| {
+            *remap.entry(v).or_insert_with(|| {
+                mesh.vertices.push(vertices[v]);
+                mesh.vertices.len() - 1
+            })
+        };
+        for &(a, b, c) in triangles {
+            let triangle = (index(a, &mut mesh), index(b, &mut mesh), index(c, &mut mesh));
+            mesh.triangles.push(triangle);
+        }
+        mesh
+    }
+}
+
+/// Uniform bucket grid over the triangles of a mesh for point location.
+pub(crate) struct TriangleGrid {
+    origin: (f64, f64),
+    cell: f64,
+    cols: usize,
+    rows: usize,
+    buckets: Vec<Vec<usize>>,
+}
+
+impl TriangleGrid {
+    pub fn new(mesh: &Mesh) -> Self {
+        let xy: Vec<(f64, f64)> = mesh.vertices.iter().map(|&(x, y, _)| (x, y)).collect();
+        let (xmin, ymin, xmax, ymax) = geometry::bounding_box(&xy).unwrap_or((0.0, 0.0, 0.0, 0.0));
+        let (width, height) = (xmax - xmin, ymax - ymin);
+        // Roughly one triangle per cell, capped to keep the grid small
+        let cell = (width * height / mesh.triangles.len().max(1) as f64)
+            .sqrt()
+            .max(width.max(height) / 1024.0)
+            .max(f64::MIN_POSITIVE);
+        let cols = (width / cell).floor() as usize + 1;
+        let rows = (height / cell).floor() as usize + 1;
+
+        let mut grid = TriangleGrid {
+            origin: (xmin, ymin),
+            cell,
+            cols,
+            rows,
+            buckets: vec![Vec::new(); cols * rows],
+        };
+        for t in 0..mesh.triangles.len() {
+            let [a, b, c] = mesh.corners(t);
+            let (c0, r0) = grid.cell_of(a.0.min(b.0).min(c.0), a.1.min(b.1).min(c.1));
+            let (c1, r1) = grid.cell_of(a.0.max(b.0).max(c.0), a.1.max(b.1).max(c.1));
+            for row in r0..=r1 {
+                for col in c0..=c1 {
+                    grid.buckets[row * cols + col].push(t);
+                }
+            }
+        }
+        grid
+    }
+
+    fn cell_of(&self, x: f64, y: f64) -> (usize, usize) {
+        let col = ((x - self.origin.0) / self.cell).floor().clamp(0.0, (self.cols - 1) as f64);
+        let row = ((y - self.origin.1) / self.cell).floor().clamp(0.0, (self.rows - 1) as f64);
+        (col as usize, row as usize)
+    }
+
+    /// Index of a mesh triangle containing `(x, y)`, if any.
+    pub fn locate(&self, mesh: &Mesh, x: f64, y: f64) -> Option<usize> {
+        let extent = (self.cols as f64 * self.cell, self.rows as f64 * self.cell);
+        let (dx, dy) = (x - self.origin.0, y - self.origin.1);
+        if dx.is_nan() || dy.is_nan() || dx < 0.0 || dy < 0.0 || dx > extent.0 || dy > extent.1 {
+            return None;
+        }
+        let p = Point2::new(x, y);
+        let (col, row) = self.cell_of(x, y);
+        self.buckets[row * self.cols + col].iter().copied().find(|&t| {
+            let [a, b, c] = mesh.corners(t).map(|(x, y, _)| Point2::new(x, y));
+            in_triangle(p, a, b, c)
+        })
+    }
+
+    /// The triangle minimizing `distance` near `(x, y)`, with that distance.
+    /// `distance` must be at least the planar distance from `(x, y)` to the
+    /// triangle; cells are searched in growing rings until no closer
+    /// triangle can remain.
+    pub fn nearest(&self, x: f64, y: f64, distance: impl Fn(usize) -> f64) -> Option<(usize, f64)> {
+        let (col, row) = self.cell_of(x, y);
+        let mut best: Option<(usize, f64)> = None;
+        for ring in 0..self.cols.max(self.rows) {
+            let (c0, c1) = (col.saturating_sub(ring), (col + ring).min(self.cols - 1));
+            let (r0, r1) = (row.saturating_sub(ring), (row + ring).min(self.rows - 1));
+            for r in r0..=r1 {
+                for c in c0..=c1 {
+                    if r.abs_diff(row) != ring && c.abs_diff(col) != ring {
+                        continue;
+                    }
+                    for &t in &self.buckets[r * self.cols + c] {
+                        let d = distance(t);
+                        if !best.is_some_and(|(_, b)| b <= d) {
+                            best = Some((t, d));
+                        }
+                    }
+                }
+            }
+            // Unvisited cells are at least `ring` cells away from the point
+            // (or from its projection onto the grid, which is no farther)
+            if best.is_some_and(|(_, b)| b <= ring as f64 * self.cell) {
+                break;
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,208 @@
+//! Refinement settings estimated from the input polygon.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::geometry;
+use crate::logging;
+use crate::validation::open_ring;
+
+/// Estimated triangle count above which requested settings are warned about
+const TRIANGLE_WARNING: f64 = 1e6;
+/// Ratio of shortest input segment to requested edge length warned about
+const FEATURE_RATIO: f64 = 100.0;
+
+/// Propose refinement parameters for a polygon from its size and features.
+///
+/// The proposed max_edge_length gives roughly `target_triangles` triangles
+/// over the polygon's area; the proposed min_angle is 25° unless a sharper
+/// corner between input segments makes that unreachable. Requested values are
+/// checked against the same measures and a warning is returned (and logged)
+/// for each that looks likely to explode the mesh.
+///
+/// Args:
+///     outer (list): Exterior boundary vertices as list of (x, y) tuples
+///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples. Default: []
+///     max_edge_length (float, optional): Requested edge length to check. Default: None
+///     min_angle (float, optional): Requested angle limit in degrees to check. Default: None
+///     target_triangles (int, optional): Triangle count the proposed max_edge_length aims
+///         for. Default: 1000
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'max_edge_length': Proposed max_edge_length, rounded to two significant digits
+///         - 'min_angle': Proposed min_angle in degrees
+///         - 'bbox_diagonal': Diagonal of the bounding box of all rings
+///         - 'area': Area of the outer ring minus the holes
+///         - 'shortest_edge': Length of the shortest input segment (ignoring repeated points)
+///         - 'smallest_angle': Smallest angle in degrees between consecutive input segments,
+///           measured inside the domain
+///         - 'estimated_triangles': Rough triangle count for the requested max_edge_length,
+///           or the proposed one if none is given
+///         - 'warnings': Human-readable problems with the requested values
+///
+/// Example:
+///     >>> import pyspade
+///     >>> params = pyspade.suggest_parameters(outer, holes)
+///     >>> mesh = pyspade.triangulate(
+///     ...     outer, holes, max_edge_length=params['max_edge_length'], min_angle=params['min_angle']
+///     ... )
+#[pyfunction]
+#[pyo3(signature = (outer, holes=None, max_edge_length=None, min_angle=None, target_triangles=1000))]
+pub fn suggest_parameters(
+    outer: Vec<(f64, f64)>,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+    target_triangles: usize,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let suggestion = suggest_parameters_impl(&outer, &holes.unwrap_or_default(), max_edge_length, min_angle, target_triangles)
+            .map_err(crate::to_py_err)?;
+        for warning in &suggestion.warnings {
+            logging::warning(|| warning.clone());
+        }
+
+        let mut output = HashMap::new();
+        output.insert("max_edge_length".to_string(), suggestion.max_edge_length.into_py(py));
+        output.insert("min_angle".to_string(), suggestion.min_angle.into_py(py));
+        output.insert("bbox_diagonal".to_string(), suggestion.bbox_diagonal.into_py(py));
+        output.insert("area".to_string(), suggestion.area.into_py(py));
+        output.insert("shortest_edge".to_string(), suggestion.shortest_edge.into_py(py));
+        output.insert("smallest_angle".to_string(), suggestion.smallest_angle.into_py(py));
+        output.insert("estimated_triangles".to_string(), suggestion.estimated_triangles.into_py(py));
+        output.insert("warnings".to_string(), suggestion.warnings.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct Suggestion {
+    pub max_edge_length: f64,
+    pub min_angle: f64,
+    pub bbox_diagonal: f64,
+    pub area: f64,
+    pub shortest_edge: f64,
+    pub smallest_angle: f64,
+    pub estimated_triangles: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Rough number of triangles refinement makes over `area` with edges of
+/// `edge_length`, from the area limit `refinement_parameters()` sets.
+fn estimate_triangles(area: f64, edge_length: f64) -> f64 {
+    area / (0.433 * edge_length * edge_length)
+}
+
+fn round_significant(x: f64, digits: i32) -> f64 {
+    if x <= 0.0 {
+        return x;
+    }
+    let scale = 10f64.powi(digits - 1 - x.log10().floor() as i32);
+    (x * scale).round() / scale
+}
+
+/// Smallest angle between consecutive segments of `ring`, inside the domain:
+/// inside the ring if `outer`, outside it for a hole. Zero-length segments
+/// are skipped.
+fn smallest_ring_angle(ring: &[(f64, f64)], outer: bool) -> f64 {
+    let points: Vec<(f64, f64)> = open_ring(ring)
+        .iter()
+        .enumerate()
+        .filter(|&(i, &p)| i == 0 || p != ring[i - 1])
+        .map(|(_, &p)| p)
+        .collect();
+    let n = points.len();
+    if n < 3 {
+        return 180.0;
+    }
+    // Angles are measured counter-clockwise from the next segment to the
+    // previous one, which is inside for a counter-clockwise ring
+    let inside_left = (geometry::signed_area(&points) >= 0.0) == outer;
+    (0..n)
+        .map(|i| {
+            let (p, v, q) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            let (a, b) = ((p.0 - v.0, p.1 - v.1), (q.0 - v.0, q.1 - v.1));
+            let angle = (b.0 * a.1 - b.1 * a.0).atan2(b.0 * a.0 + b.1 * a.1).to_degrees().rem_euclid(360.0);
+            if inside_left {
+                angle
+            } else {
+                360.0 - angle
+            }
+        })
+        .fold(180.0, f64::min)
+}
+
+pub(crate) fn suggest_parameters_impl(
+    outer: &[(f64, f64)],
+    holes: &[Vec<(f64, f64)>],
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+    target_triangles: usize,
+) -> Result<Suggestion, Box<dyn std::error::Error>> {
+    if target_triangles == 0 {
+        return Err("target_triangles must be positive".into());
+    }
+    let rings: Vec<&[(f64, f64)]> = std::iter::once(outer).chain(holes.iter().map(Vec::as_slice)).collect();
+    let all: Vec<(f64, f64)> = rings.iter().flat_map(|ring| ring.iter().copied()).collect();
+    let (min_x, min_y, max_x, max_y) = geometry::bounding_box(&all).ok_or("outer ring is empty")?;
+    let bbox_diagonal = (max_x - min_x).hypot(max_y - min_y);
+    let area =
+        (geometry::signed_area(outer).abs() - holes.iter().map(|hole| geometry::signed_area(hole).abs()).sum::<f64>()).max(0.0);
+    if area == 0.0 {
+        return Err("polygon has zero area".into());
+    }
+    let shortest_edge = rings
+        .iter()
+        .flat_map(|ring| geometry::ring_edges(ring))
+        .map(|(p, q)| (q.0 - p.0).hypot(q.1 - p.1))
+        .filter(|&length| length > 0.0)
+        .fold(f64::INFINITY, f64::min);
+    let smallest_angle = rings
+        .iter()
+        .enumerate()
+        .map(|(k, ring)| smallest_ring_angle(ring, k == 0))
+        .fold(180.0, f64::min);
+
+    let proposed_edge = round_significant((area / (0.433 * target_triangles as f64)).sqrt(), 2);
+    let proposed_angle = (smallest_angle.min(25.0) * 10.0).floor() / 10.0;
+
+    let mut warnings = Vec::new();
+    crate::validate_edge_length(max_edge_length)?;
+    if let Some(edge) = max_edge_length {
+        let estimate = estimate_triangles(area, edge);
+        if estimate > TRIANGLE_WARNING {
+            warnings.push(format!(
+                "max_edge_length={} gives roughly {:.0} triangles; {} gives about {}",
+                edge, estimate, proposed_edge, target_triangles
+            ));
+        }
+        if edge * FEATURE_RATIO < shortest_edge {
+            warnings.push(format!(
+                "max_edge_length={} is under 1/{} of the shortest input segment ({}), far finer than any input feature",
+                edge, FEATURE_RATIO, shortest_edge
+            ));
+        }
+    }
+    if let Some(angle) = min_angle {
+        if angle > 33.9 {
+            warnings.push(format!("min_angle={}° is above 33.9°, for which refinement is not guaranteed to finish", angle));
+        } else if angle > smallest_angle {
+            warnings.push(format!(
+                "min_angle={}° exceeds the smallest input angle ({:.1}°); refinement around that corner may stop at the vertex limit",
+                angle, smallest_angle
+            ));
+        }
+    }
+
+    Ok(Suggestion {
+        max_edge_length: proposed_edge,
+        min_angle: proposed_angle,
+        bbox_diagonal,
+        area,
+        shortest_edge,
+        smallest_angle,
+        estimated_triangles: estimate_triangles(area, max_edge_length.unwrap_or(proposed_edge)).round() as usize,
+        warnings,
+    })
+}
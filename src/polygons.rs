@@ -0,0 +1,144 @@
+//! Polygon measures exposed directly, for use around triangulation calls.
+
+use pyo3::prelude::*;
+
+use crate::arrays::PointArray;
+use crate::geometry;
+use crate::threads::{self, parallel_map};
+
+/// Area of a simple polygon.
+///
+/// Args:
+///     polygon (array): Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+///     signed (bool, optional): Return the signed area, positive for
+///         counter-clockwise rings. Default: False
+///
+/// Returns:
+///     float: The polygon area
+///
+/// Example:
+///     >>> import pyspade
+///     >>> pyspade.polygon_area([(0, 0), (4, 0), (4, 3), (0, 3)])
+///     12.0
+#[pyfunction]
+#[pyo3(signature = (polygon, signed=false))]
+pub fn polygon_area(polygon: PointArray, signed: bool) -> f64 {
+    let area = geometry::signed_area(&polygon.0);
+    if signed {
+        area
+    } else {
+        area.abs()
+    }
+}
+
+/// Area centroid of a simple polygon (the vertex average for degenerate,
+/// zero-area rings).
+///
+/// Args:
+///     polygon (array): Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+///
+/// Returns:
+///     tuple: (x, y) centroid, NaN for an empty ring
+///
+/// Example:
+///     >>> import pyspade
+///     >>> pyspade.polygon_centroid([(0, 0), (4, 0), (4, 2), (0, 2)])
+///     (2.0, 1.0)
+#[pyfunction]
+pub fn polygon_centroid(polygon: PointArray) -> (f64, f64) {
+    geometry::centroid(&polygon.0)
+}
+
+/// Whether a ring runs counter-clockwise (has positive signed area).
+///
+/// Args:
+///     polygon (array): Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+///
+/// Returns:
+///     bool: True for counter-clockwise rings
+///
+/// Example:
+///     >>> import pyspade
+///     >>> pyspade.polygon_is_ccw([(0, 0), (0, 1), (1, 0)])
+///     False
+#[pyfunction]
+pub fn polygon_is_ccw(polygon: PointArray) -> bool {
+    geometry::signed_area(&polygon.0) > 0.0
+}
+
+/// Even-odd point-in-polygon test. Points exactly on the boundary may be
+/// classified either way.
+///
+/// Args:
+///     polygon (array): Ring vertices as an (n, 2) NumPy array or list of (x, y) tuples
+///     point (tuple): The (x, y) query point
+///
+/// Returns:
+///     bool: True if the point lies inside the ring
+///
+/// Example:
+///     >>> import pyspade
+///     >>> pyspade.polygon_contains_point([(0, 0), (4, 0), (4, 4), (0, 4)], (1, 2))
+///     True
+#[pyfunction]
+pub fn polygon_contains_point(polygon: PointArray, point: (f64, f64)) -> bool {
+    geometry::point_in_polygon(point, &polygon.0)
+}
+
+/// Classify many points against a polygon with holes in one call.
+///
+/// Uses the same even-odd rule as `polygon_contains_point()`: a point is
+/// inside if it lies inside the outer ring and in none of the holes. Points
+/// exactly on a ring may be classified either way.
+///
+/// Args:
+///     points (array): Query points as an (n, 2) NumPy array or list of (x, y) tuples
+///     outer (array): Exterior ring as an (m, 2) NumPy array or list of (x, y) tuples
+///     holes (list, optional): Hole rings, each as an array or list of (x, y) tuples. Default: []
+///     num_threads (int, optional): Worker threads. Default: the PYSPADE_NUM_THREADS
+///         environment variable, or all available cores
+///
+/// Returns:
+///     list: One bool per point, True if the point lies inside the polygon
+///
+/// Example:
+///     >>> import numpy as np
+///     >>> import pyspade
+///     >>> inside = np.array(pyspade.points_in_polygon(samples, outer, holes))
+///     >>> samples = samples[inside]
+#[pyfunction]
+#[pyo3(signature = (points, outer, holes=None, num_threads=None))]
+pub fn points_in_polygon(
+    py: Python<'_>,
+    points: PointArray,
+    outer: PointArray,
+    holes: Option<Vec<PointArray>>,
+    num_threads: Option<usize>,
+) -> PyResult<Vec<bool>> {
+    let holes: Vec<Vec<(f64, f64)>> = holes.unwrap_or_default().into_iter().map(|hole| hole.0).collect();
+    py.allow_threads(|| points_in_polygon_impl(&points.0, &outer.0, &holes, num_threads).map_err(|e| e.to_string()))
+        .map_err(|e| crate::to_py_err(e.into()))
+}
+
+pub(crate) fn points_in_polygon_impl(
+    points: &[(f64, f64)],
+    outer: &[(f64, f64)],
+    holes: &[Vec<(f64, f64)>],
+    num_threads: Option<usize>,
+) -> Result<Vec<bool>, Box<dyn std::error::Error>> {
+    let threads = threads::num_threads(num_threads)?;
+    // Bounding boxes reject most points without walking the rings
+    let outer_box = geometry::bounding_box(outer);
+    let hole_boxes: Vec<_> = holes.iter().map(|hole| geometry::bounding_box(hole)).collect();
+    let in_box = |(x, y): (f64, f64), bounds: Option<(f64, f64, f64, f64)>| {
+        bounds.is_some_and(|(xmin, ymin, xmax, ymax)| x >= xmin && x <= xmax && y >= ymin && y <= ymax)
+    };
+    Ok(parallel_map(points, threads, |&p| {
+        in_box(p, outer_box)
+            && geometry::point_in_polygon(p, outer)
+            && !holes
+                .iter()
+                .zip(&hole_boxes)
+                .any(|(hole, &bounds)| in_box(p, bounds) && geometry::point_in_polygon(p, hole))
+    }))
+}
@@ -0,0 +1,218 @@
+//! Power diagrams (weighted Voronoi) and their dual regular triangulations.
+
+use pyo3::prelude::*;
+use spade::{Point2, Triangulation};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::geometry;
+use crate::graphs::walk_by_distance;
+use crate::regions::Cdt;
+use crate::voronoi::delaunay;
+
+/// Label of cell edges on the clipping box rather than between two sites
+const BOX: usize = usize::MAX;
+
+/// Compute the power diagram (weighted Voronoi diagram) of weighted sites and
+/// its dual regular (weighted Delaunay) triangulation.
+///
+/// A point belongs to the cell of the site with the smallest power distance
+/// `|x - p|² - w`, so a heavier site claims more area. Sites can be
+/// redundant: a site outweighed by its neighbors has an empty cell and no
+/// triangles. Coincident sites keep only the heaviest (the first on a tie).
+///
+/// Args:
+///     points (list): Site coordinates as list of (x, y) tuples
+///     weights (list): Weight of each site, in squared distance units (a weight of r²
+///         grows a cell as a circle of radius r would)
+///     bounds (tuple, optional): Clipping box as (xmin, ymin, xmax, ymax).
+///         Default: bounding box of the sites padded by 10% of its extent
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'cells': List of power cell polygons (counter-clockwise (x, y) lists), one per
+///           site; empty for redundant sites
+///         - 'areas': List of cell areas, one per site
+///         - 'centroids': List of (x, y) cell centroids, one per site ((nan, nan) if empty)
+///         - 'edges': List of (i, j) site pairs whose cells share a boundary segment
+///         - 'triangles': Regular triangulation of the non-redundant sites as (i, j, k) site
+///           indices, counter-clockwise
+///         - 'redundant': Indices of the sites with empty cells
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.power_diagram(depots, [c * c for c in capacities], bounds=(0, 0, 100, 100))
+///     >>> print(result['areas'])
+#[pyfunction]
+#[pyo3(signature = (points, weights, bounds=None))]
+pub fn power_diagram(
+    points: Vec<(f64, f64)>,
+    weights: Vec<f64>,
+    bounds: Option<(f64, f64, f64, f64)>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = power_diagram_impl(&points, &weights, bounds).map_err(crate::to_py_err)?;
+        let redundant: Vec<usize> = (0..points.len()).filter(|&i| result.cells[i].is_empty()).collect();
+
+        let mut output = HashMap::new();
+        output.insert("cells".to_string(), result.cells.into_py(py));
+        output.insert("areas".to_string(), result.areas.into_py(py));
+        output.insert("centroids".to_string(), result.centroids.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("redundant".to_string(), redundant.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct PowerDiagram {
+    pub cells: Vec<Vec<(f64, f64)>>,
+    pub areas: Vec<f64>,
+    pub centroids: Vec<(f64, f64)>,
+    pub edges: Vec<(usize, usize)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+}
+
+/// Clip a polygon whose vertices carry the label of the edge starting at them
+/// against `a * x + b * y <= c`, labeling the new edge along the line with
+/// `label`.
+fn clip_labeled(polygon: &[((f64, f64), usize)], a: f64, b: f64, c: f64, label: usize) -> Vec<((f64, f64), usize)> {
+    let mut output = Vec::with_capacity(polygon.len() + 1);
+    for (k, &(p, edge)) in polygon.iter().enumerate() {
+        let (q, _) = polygon[(k + 1) % polygon.len()];
+        let dp = a * p.0 + b * p.1 - c;
+        let dq = a * q.0 + b * q.1 - c;
+        let crossing = |t: f64| (p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1));
+        if dp < 0.0 && dq > 0.0 {
+            output.push((p, edge));
+            output.push((crossing(dp / (dp - dq)), label));
+        } else if dp == 0.0 && dq > 0.0 {
+            output.push((p, label));
+        } else if dp <= 0.0 {
+            output.push((p, edge));
+        } else if dq < 0.0 {
+            output.push((crossing(dp / (dp - dq)), edge));
+        }
+    }
+    output
+}
+
+pub(crate) fn power_diagram_impl(
+    points: &[(f64, f64)],
+    weights: &[f64],
+    bounds: Option<(f64, f64, f64, f64)>,
+) -> Result<PowerDiagram, Box<dyn std::error::Error>> {
+    if weights.len() != points.len() {
+        return Err(format!("got {} weights for {} points", weights.len(), points.len()).into());
+    }
+    if let Some(w) = weights.iter().find(|w| !w.is_finite()) {
+        return Err(format!("weights must be finite, got {}", w).into());
+    }
+    let (xmin, ymin, xmax, ymax) = match bounds {
+        Some(bounds) => bounds,
+        None => {
+            let (xmin, ymin, xmax, ymax) =
+                geometry::bounding_box(points).ok_or("at least one point is required")?;
+            let extent = (xmax - xmin).max(ymax - ymin);
+            let margin = if extent > 0.0 { 0.1 * extent } else { 1.0 };
+            (xmin - margin, ymin - margin, xmax + margin, ymax + margin)
+        }
+    };
+    if !(xmin < xmax && ymin < ymax) {
+        return Err("bounds must satisfy xmin < xmax and ymin < ymax".into());
+    }
+    let bbox: Vec<((f64, f64), usize)> =
+        [(xmin, ymin), (xmax, ymin), (xmax, ymax), (xmin, ymax)].into_iter().map(|p| (p, BOX)).collect();
+    // Shared boundary segments shorter than this are treated as a single point
+    let tolerance = 1e-12 * (xmax - xmin).max(ymax - ymin);
+
+    let (dt, handles) = delaunay(points)?;
+    let mut at_vertex: Vec<Vec<usize>> = vec![Vec::new(); dt.num_vertices()];
+    for (i, handle) in handles.iter().enumerate() {
+        at_vertex[handle.index()].push(i);
+    }
+    let max_weight = weights.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let mut visited = vec![0; dt.num_vertices()];
+    let mut labeled_cells = Vec::with_capacity(points.len());
+    for (i, (&(x, y), &weight)) in points.iter().zip(weights).enumerate() {
+        let mut cell = bbox.clone();
+        // Sites are visited by increasing distance; once no site that far
+        // away can reach into the cell, the cell is final
+        walk_by_distance(&dt, handles[i], &mut visited, i + 1, |distance, v| {
+            let reach = cell.iter().map(|&((px, py), _)| (px - x).hypot(py - y)).fold(0.0, f64::max);
+            if distance >= reach + (reach * reach - weight + max_weight).max(0.0).sqrt() {
+                return false;
+            }
+            for &j in &at_vertex[v] {
+                if j == i {
+                    continue;
+                }
+                let (q, other) = (points[j], weights[j]);
+                if q == (x, y) {
+                    if other > weight || (other == weight && j < i) {
+                        cell.clear();
+                    }
+                } else {
+                    let (a, b) = (q.0 - x, q.1 - y);
+                    let c = 0.5 * (q.0 * q.0 + q.1 * q.1 - x * x - y * y - other + weight);
+                    cell = clip_labeled(&cell, a, b, c, j);
+                }
+                if cell.is_empty() {
+                    return false;
+                }
+            }
+            true
+        });
+        labeled_cells.push(cell);
+    }
+
+    let mut edges = BTreeSet::new();
+    for (i, cell) in labeled_cells.iter().enumerate() {
+        for (k, &(p, j)) in cell.iter().enumerate() {
+            let (q, _) = cell[(k + 1) % cell.len()];
+            if j != BOX && (q.0 - p.0).hypot(q.1 - p.1) > tolerance {
+                edges.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+
+    // The dual edges are the regular triangulation's edges; constraining them
+    // in a triangulation of the non-redundant sites fills in the faces
+    let mut cdt = Cdt::default();
+    let mut site_of = HashMap::new();
+    let mut vertex_of = vec![None; points.len()];
+    for (i, cell) in labeled_cells.iter().enumerate() {
+        if !cell.is_empty() {
+            let handle = cdt.insert(Point2::new(points[i].0, points[i].1))?;
+            site_of.insert(handle, i);
+            vertex_of[i] = Some(handle);
+        }
+    }
+    for &(i, j) in &edges {
+        if let (Some(from), Some(to)) = (vertex_of[i], vertex_of[j]) {
+            if cdt.can_add_constraint(from, to) {
+                cdt.add_constraint(from, to);
+            }
+        }
+    }
+    let triangles = cdt
+        .inner_faces()
+        .map(|face| {
+            let [a, b, c] = face.vertices().map(|v| site_of[&v.fix()]);
+            (a, b, c)
+        })
+        .collect();
+
+    let cells: Vec<Vec<(f64, f64)>> =
+        labeled_cells.into_iter().map(|cell| cell.into_iter().map(|(p, _)| p).collect()).collect();
+    let areas = cells.iter().map(|cell| geometry::signed_area(cell).abs()).collect();
+    let centroids = cells.iter().map(|cell| geometry::centroid(cell)).collect();
+    Ok(PowerDiagram {
+        cells,
+        areas,
+        centroids,
+        edges: edges.into_iter().collect(),
+        triangles,
+    })
+}
@@ -0,0 +1,120 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::mesh::Mesh;
+
+/// Convert a triangle mesh into a quad-dominant mesh.
+///
+/// Pairs of adjacent triangles whose union is a convex quad with all corner
+/// angles within `90° ± (1 - min_quality) * 90°` are merged. Candidate pairs
+/// are matched greedily from the best quad down, so every triangle ends up
+/// in at most one quad; unmatched triangles are returned as they are.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles'
+///     min_quality (float, optional): Minimum quad quality in [0, 1], where 1 is a
+///         rectangle and 0 allows any convex quad. Default: 0.5 (corners within 45-135°)
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': The input vertices
+///         - 'quads': List of (i, j, k, l) counter-clockwise quads
+///         - 'triangles': Remaining (i, j, k) triangles
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], max_edge_length=1.0)
+///     >>> mixed = pyspade.quadrangulate(mesh, min_quality=0.6)
+///     >>> print(len(mixed['quads']), len(mixed['triangles']))
+#[pyfunction]
+#[pyo3(signature = (mesh, min_quality=0.5))]
+pub fn quadrangulate(mesh: Mesh, min_quality: f64) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (quads, triangles) = quadrangulate_impl(&mesh, min_quality).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), mesh.vertices.into_py(py));
+        output.insert("quads".to_string(), quads.into_py(py));
+        output.insert("triangles".to_string(), triangles.into_py(py));
+
+        Ok(output)
+    })
+}
+
+type Quad = (usize, usize, usize, usize);
+
+pub(crate) fn quadrangulate_impl(
+    mesh: &Mesh,
+    min_quality: f64,
+) -> Result<(Vec<Quad>, Vec<(usize, usize, usize)>), Box<dyn std::error::Error>> {
+    if !(0.0..=1.0).contains(&min_quality) {
+        return Err(format!("min_quality must be in [0, 1], got {}", min_quality).into());
+    }
+
+    let mut by_edge: HashMap<(usize, usize), usize> = HashMap::new();
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        by_edge.extend([((a, b), t), ((b, c), t), ((c, a), t)]);
+    }
+
+    // (quality, first triangle, second triangle, quad)
+    let mut candidates: Vec<(f64, usize, usize, Quad)> = Vec::new();
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        for (u, v, w) in [(a, b, c), (b, c, a), (c, a, b)] {
+            let Some(&other) = by_edge.get(&(v, u)) else {
+                continue;
+            };
+            if other <= t {
+                continue;
+            }
+            let (x, y, z) = mesh.triangles[other];
+            let opposite = [x, y, z].into_iter().find(|&p| p != u && p != v).unwrap_or(u);
+            let quad = (u, opposite, v, w);
+            if let Some(quality) = quad_quality(mesh, quad) {
+                if quality >= min_quality {
+                    candidates.push((quality, t, other, quad));
+                }
+            }
+        }
+    }
+    candidates.sort_by(|p, q| q.0.total_cmp(&p.0));
+
+    let mut used = vec![false; mesh.triangles.len()];
+    let mut quads = Vec::new();
+    for (_, t, other, quad) in candidates {
+        if !used[t] && !used[other] {
+            used[t] = true;
+            used[other] = true;
+            quads.push(quad);
+        }
+    }
+    let triangles = mesh
+        .triangles
+        .iter()
+        .zip(&used)
+        .filter(|&(_, &used)| !used)
+        .map(|(&triangle, _)| triangle)
+        .collect();
+
+    Ok((quads, triangles))
+}
+
+/// `1 - max |angle - 90°| / 90°` over the corners of a convex quad, or
+/// `None` if the quad is not strictly convex.
+fn quad_quality(mesh: &Mesh, (a, b, c, d): Quad) -> Option<f64> {
+    let corners = [a, b, c, d].map(|v| (mesh.vertices[v].0, mesh.vertices[v].1));
+    let mut worst: f64 = 0.0;
+    for (k, &q) in corners.iter().enumerate() {
+        let (p, r) = (corners[(k + 3) % 4], corners[(k + 1) % 4]);
+        let (ux, uy) = (p.0 - q.0, p.1 - q.1);
+        let (vx, vy) = (r.0 - q.0, r.1 - q.1);
+        // Counter-clockwise corners turn left: the cross product from the
+        // outgoing to the incoming edge is positive
+        let cross = vx * uy - vy * ux;
+        if cross <= 0.0 {
+            return None;
+        }
+        let angle = cross.atan2(ux * vx + uy * vy).to_degrees();
+        worst = worst.max((angle - 90.0).abs());
+    }
+    Some(1.0 - worst / 90.0)
+}
@@ -0,0 +1,35 @@
+//! Small seedable pseudo-random generator, so sampling needs no extra dependency.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// SplitMix64 generator: fast, statistically sound for sampling, and fully
+/// reproducible for a given seed.
+///
+/// Functions with random choices take a `seed: Option<u64>` argument and
+/// pass it straight to [`Rng::new`], so results can be reproduced exactly.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Generator for `seed`, or seeded from the clock when `None`.
+    pub fn new(seed: Option<u64>) -> Self {
+        let seed = seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos() as u64)
+        });
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,166 @@
+use pyo3::prelude::*;
+use spade::Point2;
+use std::collections::HashMap;
+
+use crate::arrays::float64_array;
+use crate::interpolator::Outside;
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::sizing::{distance_2, in_triangle};
+use crate::terrain::barycentric_weights;
+
+/// Sample a triangulated surface onto a regular grid.
+///
+/// Exactly one of `resolution` and `shape` must be given. Cells whose center
+/// is not covered by any triangle get a value chosen by `outside`.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' ((x, y, z) tuples) and 'triangles', e.g. from `tin()`
+///     resolution (float, optional): Cell size in x/y units
+///     shape (tuple, optional): Grid size as (rows, cols)
+///     bounds (tuple, optional): Grid extent as (xmin, ymin, xmax, ymax). Default: mesh bounding box
+///     method (str, optional): "linear" (barycentric) or "nearest" (closest triangle corner).
+///         Default: "linear"
+///     outside (str, optional): Value of cells outside the mesh: "nan", "nearest" (the z of the
+///         closest mesh vertex) or "constant" (fill_value). Default: "nan"
+///     fill_value (float, optional): Value of cells outside the mesh for outside="constant"
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'array': (rows, cols) float64 NumPy array, rows from top (ymax) to bottom (ymin)
+///         - 'transform': GDAL-style geotransform (xmin, cell_width, 0, ymax, 0, -cell_height)
+///
+/// Example:
+///     >>> import numpy as np
+///     >>> import pyspade
+///     >>> mesh = pyspade.tin([(0, 0, 0.0), (10, 0, 1.0), (10, 10, 2.0), (0, 10, 1.0)])
+///     >>> grid = pyspade.rasterize(mesh, resolution=1.0)
+///     >>> dem = grid['array']
+///     >>> print(dem.shape, np.nanmax(dem))
+#[pyfunction]
+#[pyo3(signature = (mesh, resolution=None, shape=None, bounds=None, method="linear", outside="nan", fill_value=None))]
+pub fn rasterize(
+    mesh: Mesh,
+    resolution: Option<f64>,
+    shape: Option<(usize, usize)>,
+    bounds: Option<(f64, f64, f64, f64)>,
+    method: &str,
+    outside: &str,
+    fill_value: Option<f64>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let outside = Outside::parse(outside, fill_value).map_err(crate::to_py_err)?;
+        let (array, transform) =
+            rasterize_impl(&mesh, resolution, shape, bounds, method, outside).map_err(crate::to_py_err)?;
+
+        let (rows, cols) = (array.len(), array.first().map_or(0, Vec::len));
+        let mut output = HashMap::new();
+        output.insert("array".to_string(), float64_array(py, &array.concat(), &[rows, cols])?);
+        output.insert("transform".to_string(), transform.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) type Geotransform = (f64, f64, f64, f64, f64, f64);
+
+pub(crate) fn rasterize_impl(
+    mesh: &Mesh,
+    resolution: Option<f64>,
+    shape: Option<(usize, usize)>,
+    bounds: Option<(f64, f64, f64, f64)>,
+    method: &str,
+    outside: Outside,
+) -> Result<(Vec<Vec<f64>>, Geotransform), Box<dyn std::error::Error>> {
+    let nearest = match method {
+        "linear" => false,
+        "nearest" => true,
+        _ => return Err(format!("unknown method '{}', expected 'linear' or 'nearest'", method).into()),
+    };
+    let (xmin, ymin, xmax, ymax) = match bounds {
+        Some(bounds) => bounds,
+        None => {
+            let xy: Vec<(f64, f64)> = mesh.vertices.iter().map(|&(x, y, _)| (x, y)).collect();
+            crate::geometry::bounding_box(&xy).ok_or("mesh has no vertices")?
+        }
+    };
+    let (width, height) = (xmax - xmin, ymax - ymin);
+    if width.is_nan() || height.is_nan() || width <= 0.0 || height <= 0.0 {
+        return Err("bounds must have a positive extent".into());
+    }
+    let (rows, cols) = match (resolution, shape) {
+        (Some(cell), None) if cell > 0.0 => ((height / cell).ceil() as usize, (width / cell).ceil() as usize),
+        (None, Some((rows, cols))) if rows > 0 && cols > 0 => (rows, cols),
+        _ => return Err("exactly one of a positive resolution or a non-empty shape is required".into()),
+    };
+    let (dx, dy) = match resolution {
+        Some(cell) => (cell, cell),
+        None => (width / cols as f64, height / rows as f64),
+    };
+
+    let mut array = vec![vec![f64::NAN; cols]; rows];
+    let mut covered = vec![vec![false; cols]; rows];
+    for t in 0..mesh.triangles.len() {
+        let corners = mesh.corners(t);
+        let [a, b, c] = corners.map(|(x, y, _)| Point2::new(x, y));
+        let tx = (a.x.min(b.x).min(c.x), a.x.max(b.x).max(c.x));
+        let ty = (a.y.min(b.y).min(c.y), a.y.max(b.y).max(c.y));
+
+        // Column/row ranges whose cell centers may fall inside the triangle
+        let col0 = ((tx.0 - xmin) / dx - 0.5).ceil().max(0.0) as usize;
+        let col1 = ((tx.1 - xmin) / dx - 0.5).floor().min(cols as f64 - 1.0);
+        let row0 = ((ymax - ty.1) / dy - 0.5).ceil().max(0.0) as usize;
+        let row1 = ((ymax - ty.0) / dy - 0.5).floor().min(rows as f64 - 1.0);
+        if col1 < 0.0 || row1 < 0.0 {
+            continue;
+        }
+
+        for (row, values) in array.iter_mut().enumerate().take(row1 as usize + 1).skip(row0) {
+            let y = ymax - (row as f64 + 0.5) * dy;
+            for (col, value) in values.iter_mut().enumerate().take(col1 as usize + 1).skip(col0) {
+                let p = Point2::new(xmin + (col as f64 + 0.5) * dx, y);
+                if !in_triangle(p, a, b, c) {
+                    continue;
+                }
+                covered[row][col] = true;
+                let weights = barycentric_weights(a, b, c, p);
+                *value = if nearest {
+                    let k = (0..3).max_by(|&i, &j| weights[i].total_cmp(&weights[j])).unwrap_or(0);
+                    corners[k].2
+                } else {
+                    weights[0] * corners[0].2 + weights[1] * corners[1].2 + weights[2] * corners[2].2
+                };
+            }
+        }
+    }
+
+    if !matches!(outside, Outside::Nan) {
+        let grid = matches!(outside, Outside::Nearest).then(|| TriangleGrid::new(mesh));
+        for (row, values) in array.iter_mut().enumerate() {
+            let y = ymax - (row as f64 + 0.5) * dy;
+            for (col, value) in values.iter_mut().enumerate() {
+                if covered[row][col] {
+                    continue;
+                }
+                *value = match (outside, &grid) {
+                    (Outside::Constant(fill), _) => fill,
+                    (_, Some(grid)) => nearest_height(mesh, grid, Point2::new(xmin + (col as f64 + 0.5) * dx, y)),
+                    _ => f64::NAN,
+                };
+            }
+        }
+    }
+
+    Ok((array, (xmin, dx, 0.0, ymax, 0.0, -dy)))
+}
+
+/// z of the mesh vertex closest to `p`.
+fn nearest_height(mesh: &Mesh, grid: &TriangleGrid, p: Point2<f64>) -> f64 {
+    let closest_corner = |t: usize| {
+        mesh.corners(t)
+            .into_iter()
+            .map(|(x, y, z)| (distance_2(p, Point2::new(x, y)), z))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap_or((f64::INFINITY, f64::NAN))
+    };
+    grid.nearest(p.x, p.y, |t| closest_corner(t).0.sqrt()).map_or(f64::NAN, |(t, _)| closest_corner(t).1)
+}
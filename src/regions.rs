@@ -0,0 +1,360 @@
+use pyo3::prelude::*;
+use spade::handles::{FixedFaceHandle, InnerTag};
+use spade::{ConstrainedDelaunayTriangulation, FixedVertexHandle, Point2, Triangulation};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::geometry;
+use crate::logging;
+
+pub(crate) type Cdt = ConstrainedDelaunayTriangulation<Point2<f64>>;
+
+/// Region index of every inner face (`None` outside all regions).
+pub(crate) type FaceLabels = HashMap<FixedFaceHandle<InnerTag>, Option<usize>>;
+
+/// Triangulate several polygons that may share boundaries as one conforming mesh.
+///
+/// Vertices at identical positions are merged, so an edge shared by two
+/// regions is constrained exactly once and both sides of the interface use
+/// the same vertices. Every output triangle is labeled with the id of the
+/// region containing it; triangles outside all regions are dropped, along
+/// with the vertices only they used.
+///
+/// Args:
+///     regions (list): Region polygons, each as list of (x, y) tuples
+///     ids (list, optional): Integer id per region. Default: region index
+///     max_edge_length (float, optional): Target maximum edge length for mesh refinement
+///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edge indices
+///         - 'labels': Region id of each triangle
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.triangulate_regions(
+///     ...     regions=[[(0, 0), (5, 0), (5, 10), (0, 10)],
+///     ...              [(5, 0), (10, 0), (10, 10), (5, 10)]],
+///     ...     ids=[1, 2],
+///     ...     max_edge_length=1.0
+///     ... )
+///     >>> print(set(result['labels']))
+#[pyfunction]
+#[pyo3(signature = (regions, ids=None, max_edge_length=None, min_angle=None))]
+pub fn triangulate_regions(
+    regions: Vec<Vec<(f64, f64)>>,
+    ids: Option<Vec<i64>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = triangulate_regions_impl(&regions, ids, max_edge_length, min_angle)
+            .map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+        output.insert("labels".to_string(), result.labels.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct RegionMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+    pub labels: Vec<i64>,
+}
+
+pub(crate) fn triangulate_regions_impl(
+    regions: &[Vec<(f64, f64)>],
+    ids: Option<Vec<i64>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+) -> Result<RegionMesh, Box<dyn std::error::Error>> {
+    let ids = ids.unwrap_or_else(|| (0..regions.len() as i64).collect());
+    if ids.len() != regions.len() {
+        return Err(format!("expected {} ids, got {}", regions.len(), ids.len()).into());
+    }
+
+    let (cdt, face_region) = mesh_regions(regions, max_edge_length, min_angle)?;
+
+    let mut triangles = Vec::new();
+    let mut labels = Vec::new();
+    for face in cdt.inner_faces() {
+        if let Some(region) = face_region[&face.fix()] {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            triangles.push((a, b, c));
+            labels.push(ids[region]);
+        }
+    }
+
+    let (vertices, index) = used_vertices(&cdt, &triangles);
+    let triangles = triangles.into_iter().map(|(a, b, c)| (index[a], index[b], index[c])).collect();
+    let edges = constraint_edges(&cdt)
+        .into_iter()
+        .filter(|&(a, b)| index[a] != usize::MAX && index[b] != usize::MAX)
+        .map(|(a, b)| (index[a], index[b]))
+        .collect();
+
+    Ok(RegionMesh {
+        vertices,
+        triangles,
+        edges,
+        labels,
+    })
+}
+
+/// Mesh all regions in one CDT and label every inner face with the index of
+/// the region containing it.
+pub(crate) fn mesh_regions(
+    regions: &[Vec<(f64, f64)>],
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+) -> Result<(Cdt, FaceLabels), Box<dyn std::error::Error>> {
+    let mut cdt = Cdt::default();
+    for region in regions {
+        insert_ring(&mut cdt, region)?;
+    }
+
+    if max_edge_length.is_some() || min_angle.is_some() {
+        let before = cdt.num_vertices();
+        let result = cdt.refine(crate::refinement_parameters(max_edge_length, min_angle));
+        logging::refinement(before, cdt.num_vertices(), result.refinement_complete);
+    }
+
+    let face_region = label_faces(&cdt, regions);
+    Ok((cdt, face_region))
+}
+
+/// Mesh adjacent subdomains so that their shared interfaces conform exactly.
+///
+/// All subdomains are meshed in one triangulation with a single vertex
+/// array, so a vertex on an interface has the same index in every
+/// subdomain that touches it.
+///
+/// Args:
+///     domains (list): Subdomain polygons, each as list of (x, y) tuples
+///     max_edge_length (float, optional): Target maximum edge length for mesh refinement
+///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates shared by all subdomains (z=0.0)
+///         - 'domains': Per-subdomain lists of (i, j, k) triangles indexing 'vertices'
+///         - 'interfaces': Dict mapping a subdomain pair (a, b) with a < b to the
+///           sorted indices of the vertices on their common interface
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.mesh_subdomains(
+///     ...     domains=[[(0, 0), (5, 0), (5, 10), (0, 10)],
+///     ...              [(5, 0), (10, 0), (10, 10), (5, 10)]],
+///     ...     max_edge_length=1.0
+///     ... )
+///     >>> print(result['interfaces'][(0, 1)])
+#[pyfunction]
+#[pyo3(signature = (domains, max_edge_length=None, min_angle=None))]
+pub fn mesh_subdomains(
+    domains: Vec<Vec<(f64, f64)>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = mesh_subdomains_impl(&domains, max_edge_length, min_angle).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("domains".to_string(), result.domains.into_py(py));
+        output.insert("interfaces".to_string(), result.interfaces.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct SubdomainMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub domains: Vec<Vec<(usize, usize, usize)>>,
+    pub interfaces: BTreeMap<(usize, usize), Vec<usize>>,
+}
+
+pub(crate) fn mesh_subdomains_impl(
+    domains: &[Vec<(f64, f64)>],
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+) -> Result<SubdomainMesh, Box<dyn std::error::Error>> {
+    let (cdt, face_region) = mesh_regions(domains, max_edge_length, min_angle)?;
+
+    let mut triangles = vec![Vec::new(); domains.len()];
+    for face in cdt.inner_faces() {
+        if let Some(domain) = face_region[&face.fix()] {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            triangles[domain].push((a, b, c));
+        }
+    }
+
+    let mut interface_sets: BTreeMap<(usize, usize), BTreeSet<usize>> = BTreeMap::new();
+    for edge in cdt.undirected_edges() {
+        if !edge.is_constraint_edge() {
+            continue;
+        }
+        let directed = edge.as_directed();
+        let left = directed.face().as_inner().and_then(|f| face_region[&f.fix()]);
+        let right = directed.rev().face().as_inner().and_then(|f| face_region[&f.fix()]);
+        if let (Some(a), Some(b)) = (left, right) {
+            if a != b {
+                let set = interface_sets.entry((a.min(b), a.max(b))).or_default();
+                set.extend(edge.vertices().map(|v| v.fix().index()));
+            }
+        }
+    }
+
+    let (vertices, index) = used_vertices(&cdt, &triangles.concat());
+    let domains = triangles
+        .into_iter()
+        .map(|domain| domain.into_iter().map(|(a, b, c)| (index[a], index[b], index[c])).collect())
+        .collect();
+    let interfaces = interface_sets
+        .into_iter()
+        .map(|(pair, set)| (pair, set.into_iter().map(|v| index[v]).collect()))
+        .collect();
+
+    Ok(SubdomainMesh {
+        vertices,
+        domains,
+        interfaces,
+    })
+}
+
+/// The vertices of `cdt` used by `triangles`, in triangulation order, and
+/// the new index of every triangulation vertex (`usize::MAX` if unused).
+/// Refinement also splits faces outside all regions, and their Steiner
+/// points would otherwise show up as stray vertices.
+fn used_vertices(cdt: &Cdt, triangles: &[(usize, usize, usize)]) -> (Vec<(f64, f64, f64)>, Vec<usize>) {
+    let mut used = vec![false; cdt.num_vertices()];
+    for &(a, b, c) in triangles {
+        used[a] = true;
+        used[b] = true;
+        used[c] = true;
+    }
+    let mut vertices = Vec::new();
+    let mut index = vec![usize::MAX; used.len()];
+    for (v, position) in output_vertices(cdt).into_iter().enumerate() {
+        if used[v] {
+            index[v] = vertices.len();
+            vertices.push(position);
+        }
+    }
+    (vertices, index)
+}
+
+/// Vertex positions of a planar triangulation as (x, y, 0.0) tuples.
+pub(crate) fn output_vertices(cdt: &Cdt) -> Vec<(f64, f64, f64)> {
+    cdt.vertices()
+        .map(|v| {
+            let p = v.position();
+            (p.x, p.y, 0.0)
+        })
+        .collect()
+}
+
+/// Insert a closed ring as a chain of constraint edges, returning the handle
+/// of each ring vertex. Vertices that already exist are reused.
+pub(crate) fn insert_ring(
+    cdt: &mut Cdt,
+    ring: &[(f64, f64)],
+) -> Result<Vec<FixedVertexHandle>, Box<dyn std::error::Error>> {
+    let handles = ring
+        .iter()
+        .map(|&(x, y)| cdt.insert(Point2::new(x, y)))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (i, &from) in handles.iter().enumerate() {
+        let to = handles[(i + 1) % handles.len()];
+        add_constraint_checked(cdt, from, to)?;
+    }
+    Ok(handles)
+}
+
+/// Add a constraint edge, reporting an error instead of panicking when it
+/// would cross an existing constraint.
+pub(crate) fn add_constraint_checked(
+    cdt: &mut Cdt,
+    from: FixedVertexHandle,
+    to: FixedVertexHandle,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if from == to {
+        return Ok(());
+    }
+    if !cdt.can_add_constraint(from, to) {
+        let (p, q) = (cdt.vertex(from).position(), cdt.vertex(to).position());
+        return Err(format!(
+            "constraint edge ({}, {})-({}, {}) intersects another constraint edge",
+            p.x, p.y, q.x, q.y
+        )
+        .into());
+    }
+    cdt.add_constraint(from, to);
+    Ok(())
+}
+
+/// All constraint edges of the triangulation as vertex index pairs.
+pub(crate) fn constraint_edges(cdt: &Cdt) -> Vec<(usize, usize)> {
+    cdt.undirected_edges()
+        .filter(|edge| edge.is_constraint_edge())
+        .map(|edge| {
+            let [a, b] = edge.vertices().map(|v| v.fix().index());
+            (a, b)
+        })
+        .collect()
+}
+
+/// Group inner faces into components bounded by constraint edges and assign
+/// each component to the innermost (smallest) polygon containing it.
+pub(crate) fn label_faces(cdt: &Cdt, polygons: &[Vec<(f64, f64)>]) -> FaceLabels {
+    let areas: Vec<f64> = polygons.iter().map(|p| geometry::signed_area(p).abs()).collect();
+    let mut labels = HashMap::with_capacity(cdt.num_inner_faces());
+
+    for seed in cdt.inner_faces() {
+        if labels.contains_key(&seed.fix()) {
+            continue;
+        }
+        let c = seed.center();
+        let region = polygons
+            .iter()
+            .enumerate()
+            .filter(|(_, polygon)| geometry::point_in_polygon((c.x, c.y), polygon))
+            .min_by(|(a, _), (b, _)| areas[*a].total_cmp(&areas[*b]))
+            .map(|(i, _)| i);
+
+        for face in face_component(cdt, seed.fix()) {
+            labels.insert(face, region);
+        }
+    }
+    labels
+}
+
+/// All inner faces reachable from `seed` without crossing a constraint edge.
+pub(crate) fn face_component(cdt: &Cdt, seed: FixedFaceHandle<InnerTag>) -> Vec<FixedFaceHandle<InnerTag>> {
+    let mut visited = HashSet::new();
+    let mut component = Vec::new();
+    let mut stack = vec![seed];
+    visited.insert(seed);
+    while let Some(fixed) = stack.pop() {
+        component.push(fixed);
+        for edge in cdt.face(fixed).adjacent_edges() {
+            if edge.as_undirected().is_constraint_edge() {
+                continue;
+            }
+            if let Some(neighbor) = edge.rev().face().as_inner() {
+                if visited.insert(neighbor.fix()) {
+                    stack.push(neighbor.fix());
+                }
+            }
+        }
+    }
+    component
+}
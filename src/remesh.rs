@@ -0,0 +1,282 @@
+use pyo3::prelude::*;
+use spade::handles::{FixedFaceHandle, InnerTag};
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::collections::{HashMap, HashSet};
+
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::regions::{constraint_edges, Cdt};
+use crate::smoothing::Frozen;
+use crate::terrain::barycentric_weights;
+
+/// Remesh to near-equilateral triangles of a uniform edge length.
+///
+/// Boundary edges and the mesh's constrained 'edges' (if present) are kept
+/// as features: they are resampled to `target_length` but their geometry
+/// never changes. Each iteration splits interior edges longer than 4/3 of
+/// the target, collapses edges shorter than 4/5 of it, restores the
+/// Delaunay property by flips and moves every free vertex to the average of
+/// its neighbors. z values are interpolated from the input mesh.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges'
+///     target_length (float): Desired edge length
+///     iterations (int, optional): Number of split/collapse/flip/smooth cycles. Default: 5
+///     frozen (list, optional): Vertices kept in place like the features, as a list of vertex
+///         indices or a boolean mask with one entry per vertex. Default: None
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edges (resampled features)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> raw = pyspade.triangulate(outer, holes, max_edge_length=2.0, min_angle=20.0)
+///     >>> smooth = pyspade.remesh(raw, target_length=2.0, iterations=10)
+#[pyfunction]
+#[pyo3(signature = (mesh, target_length, iterations=5, frozen=None))]
+pub fn remesh(mesh: Mesh, target_length: f64, iterations: usize, frozen: Option<Frozen>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let frozen = match frozen {
+            Some(frozen) => frozen.mask(mesh.vertices.len()).map_err(crate::to_py_err)?,
+            None => vec![false; mesh.vertices.len()],
+        };
+        let result = remesh_impl(&mesh, target_length, iterations, &frozen).map_err(crate::to_py_err)?;
+        Ok(result.into_dict(py))
+    })
+}
+
+/// Feature vertices and edges (never moved or removed) plus the free
+/// interior vertices, from which the triangulation is rebuilt every pass.
+struct Remesher<'a> {
+    mesh: &'a Mesh,
+    grid: TriangleGrid,
+    fixed: Vec<Point2<f64>>,
+    features: Vec<(usize, usize)>,
+    free: Vec<Point2<f64>>,
+}
+
+impl Remesher<'_> {
+    /// Triangulation of the current vertices, the faces inside the input
+    /// mesh, and the free vertex index of every triangulation vertex.
+    fn build(&self) -> Result<(Cdt, HashSet<FixedFaceHandle<InnerTag>>, Vec<Option<usize>>), Box<dyn std::error::Error>> {
+        let mut cdt = Cdt::default();
+        let fixed = self
+            .fixed
+            .iter()
+            .map(|&p| cdt.insert(p))
+            .collect::<Result<Vec<FixedVertexHandle>, _>>()?;
+        for &(a, b) in &self.features {
+            if fixed[a] != fixed[b] && cdt.can_add_constraint(fixed[a], fixed[b]) {
+                cdt.add_constraint(fixed[a], fixed[b]);
+            }
+        }
+        let mut free_index: Vec<Option<usize>> = vec![None; cdt.num_vertices()];
+        for (i, &p) in self.free.iter().enumerate() {
+            let handle = cdt.insert(p)?;
+            if handle.index() == free_index.len() {
+                free_index.push(Some(i));
+            }
+        }
+
+        let inside = cdt
+            .inner_faces()
+            .filter(|face| {
+                let c = face.center();
+                self.grid.locate(self.mesh, c.x, c.y).is_some()
+            })
+            .map(|face| face.fix())
+            .collect();
+        Ok((cdt, inside, free_index))
+    }
+
+    /// Split long edges and collapse short ones.
+    fn split_and_collapse(&mut self, target_length: f64) -> Result<(), Box<dyn std::error::Error>> {
+        let (cdt, inside, free_index) = self.build()?;
+        let (long_2, short_2) = ((4.0 / 3.0 * target_length).powi(2), (0.8 * target_length).powi(2));
+
+        let mut midpoints = Vec::new();
+        let mut removed = vec![false; self.free.len()];
+        let mut touched = vec![false; cdt.num_vertices()];
+        for edge in cdt.undirected_edges() {
+            let directed = edge.as_directed();
+            let in_mesh = [directed, directed.rev()]
+                .iter()
+                .any(|e| e.face().as_inner().is_some_and(|face| inside.contains(&face.fix())));
+            if !in_mesh || edge.is_constraint_edge() {
+                continue;
+            }
+            let length_2 = edge.length_2();
+            let [u, v] = edge.vertices().map(|v| v.fix().index());
+            if length_2 > long_2 {
+                let [p, q] = edge.positions();
+                midpoints.push(Point2::new(0.5 * (p.x + q.x), 0.5 * (p.y + q.y)));
+            } else if length_2 < short_2 && !touched[u] && !touched[v] {
+                // Drop a free endpoint; the other one stays put this pass
+                if let Some(i) = free_index[v].or(free_index[u]) {
+                    removed[i] = true;
+                    touched[u] = true;
+                    touched[v] = true;
+                }
+            }
+        }
+
+        let mut free: Vec<Point2<f64>> = self
+            .free
+            .iter()
+            .zip(&removed)
+            .filter(|&(_, &removed)| !removed)
+            .map(|(&p, _)| p)
+            .collect();
+        free.extend(midpoints);
+        self.free = free;
+        Ok(())
+    }
+
+    /// Move every free vertex to the average of its neighbors, unless that
+    /// would take it outside the input mesh.
+    fn smooth(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let (cdt, _, free_index) = self.build()?;
+        for vertex in cdt.vertices() {
+            let Some(i) = free_index[vertex.fix().index()] else {
+                continue;
+            };
+            let (mut sx, mut sy, mut n) = (0.0, 0.0, 0.0);
+            for edge in vertex.out_edges() {
+                let p = edge.to().position();
+                sx += p.x;
+                sy += p.y;
+                n += 1.0;
+            }
+            if n > 0.0 {
+                let p = Point2::new(sx / n, sy / n);
+                if self.grid.locate(self.mesh, p.x, p.y).is_some() {
+                    self.free[i] = p;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Height of the input mesh at `p`, falling back to the nearest input
+    /// vertex for points that rounding put just outside it.
+    fn height_at(&self, p: Point2<f64>) -> f64 {
+        match self.grid.locate(self.mesh, p.x, p.y) {
+            Some(t) => {
+                let corners = self.mesh.corners(t);
+                let [a, b, c] = corners.map(|(x, y, _)| Point2::new(x, y));
+                let w = barycentric_weights(a, b, c, p);
+                w[0] * corners[0].2 + w[1] * corners[1].2 + w[2] * corners[2].2
+            }
+            None => self
+                .mesh
+                .vertices
+                .iter()
+                .min_by(|a, b| {
+                    let da = (a.0 - p.x).powi(2) + (a.1 - p.y).powi(2);
+                    let db = (b.0 - p.x).powi(2) + (b.1 - p.y).powi(2);
+                    da.total_cmp(&db)
+                })
+                .map_or(0.0, |v| v.2),
+        }
+    }
+}
+
+pub(crate) fn remesh_impl(
+    mesh: &Mesh,
+    target_length: f64,
+    iterations: usize,
+    frozen: &[bool],
+) -> Result<Mesh, Box<dyn std::error::Error>> {
+    if target_length.is_nan() || target_length <= 0.0 {
+        return Err(format!("target_length must be positive, got {}", target_length).into());
+    }
+    if mesh.triangles.is_empty() {
+        return Err("mesh has no triangles".into());
+    }
+
+    // Features: boundary edges (used by one triangle) and constrained edges
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((u.min(v), u.max(v))).or_default() += 1;
+        }
+    }
+    let mut feature_edges: HashSet<(usize, usize)> =
+        uses.into_iter().filter(|&(_, count)| count == 1).map(|(edge, _)| edge).collect();
+    feature_edges.extend(mesh.edges.iter().map(|&(u, v)| (u.min(v), u.max(v))));
+
+    // Resample features to the target length
+    let point = |v: usize| Point2::new(mesh.vertices[v].0, mesh.vertices[v].1);
+    let mut fixed: Vec<Point2<f64>> = Vec::new();
+    let mut fixed_index: HashMap<usize, usize> = HashMap::new();
+    let mut features = Vec::new();
+    let mut sorted: Vec<(usize, usize)> = feature_edges.into_iter().filter(|&(u, v)| u != v).collect();
+    sorted.sort_unstable();
+    for (u, v) in sorted {
+        let mut index = |w: usize| {
+            *fixed_index.entry(w).or_insert_with(|| {
+                fixed.push(point(w));
+                fixed.len() - 1
+            })
+        };
+        let (a, b) = (index(u), index(v));
+        let (p, q) = (point(u), point(v));
+        let pieces = (((q.x - p.x).hypot(q.y - p.y)) / target_length).ceil().max(1.0) as usize;
+        let mut previous = a;
+        for k in 1..pieces {
+            let t = k as f64 / pieces as f64;
+            fixed.push(Point2::new(p.x + t * (q.x - p.x), p.y + t * (q.y - p.y)));
+            features.push((previous, fixed.len() - 1));
+            previous = fixed.len() - 1;
+        }
+        features.push((previous, b));
+    }
+
+    let used: HashSet<usize> = mesh.triangles.iter().flat_map(|&(a, b, c)| [a, b, c]).collect();
+    let mut pinned: Vec<usize> = used.iter().copied().filter(|&v| frozen[v] && !fixed_index.contains_key(&v)).collect();
+    pinned.sort_unstable();
+    for v in pinned {
+        fixed_index.insert(v, fixed.len());
+        fixed.push(point(v));
+    }
+    let mut free_vertices: Vec<usize> = used.into_iter().filter(|v| !fixed_index.contains_key(v)).collect();
+    free_vertices.sort_unstable();
+    let free = free_vertices.into_iter().map(point).collect();
+
+    let mut remesher = Remesher {
+        mesh,
+        grid: TriangleGrid::new(mesh),
+        fixed,
+        features,
+        free,
+    };
+    for _ in 0..iterations {
+        remesher.split_and_collapse(target_length)?;
+        remesher.smooth()?;
+    }
+
+    let (cdt, inside, _) = remesher.build()?;
+    let vertices = cdt
+        .vertices()
+        .map(|v| {
+            let p = v.position();
+            (p.x, p.y, remesher.height_at(p))
+        })
+        .collect();
+    let triangles = cdt
+        .inner_faces()
+        .filter(|face| inside.contains(&face.fix()))
+        .map(|face| {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            (a, b, c)
+        })
+        .collect();
+
+    Ok(Mesh {
+        vertices,
+        triangles,
+        edges: constraint_edges(&cdt),
+    })
+}
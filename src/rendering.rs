@@ -0,0 +1,430 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::mesh::Mesh;
+
+/// Reorder a mesh for GPU vertex cache locality.
+///
+/// Triangles are reordered with Forsyth's linear-speed vertex cache
+/// optimization, then vertices are renumbered in order of first use so the
+/// vertex buffer is fetched sequentially as well. The geometry is unchanged.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles'
+///     cache_size (int, optional): Simulated vertex cache size. Default: 32
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': Reordered vertex coordinates
+///         - 'triangles': Reordered triangle vertex indices (same winding as the input)
+///         - 'vertex_map': Input index of each output vertex
+///         - 'acmr': (before, after) average cache misses per triangle
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate([(0, 0), (100, 0), (100, 100), (0, 100)], max_edge_length=1.0)
+///     >>> fast = pyspade.optimize_vertex_cache(mesh)
+///     >>> print(fast['acmr'])
+#[pyfunction]
+#[pyo3(signature = (mesh, cache_size=32))]
+pub fn optimize_vertex_cache(mesh: Mesh, cache_size: usize) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = optimize_vertex_cache_impl(&mesh, cache_size).map_err(crate::to_py_err)?;
+
+        let mut output = result.mesh.into_dict(py);
+        output.insert("vertex_map".to_string(), result.vertex_map.into_py(py));
+        output.insert("acmr".to_string(), result.acmr.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct CacheOptimized {
+    pub mesh: Mesh,
+    pub vertex_map: Vec<usize>,
+    pub acmr: (f64, f64),
+}
+
+pub(crate) fn optimize_vertex_cache_impl(
+    mesh: &Mesh,
+    cache_size: usize,
+) -> Result<CacheOptimized, Box<dyn std::error::Error>> {
+    if cache_size < 4 {
+        return Err(format!("cache_size must be at least 4, got {}", cache_size).into());
+    }
+    let before = acmr(&mesh.triangles, cache_size);
+    let order = forsyth_order(mesh, cache_size);
+
+    let mut vertex_map: Vec<usize> = Vec::with_capacity(mesh.vertices.len());
+    let mut new_index: Vec<Option<usize>> = vec![None; mesh.vertices.len()];
+    let mut triangles = Vec::with_capacity(order.len());
+    for t in order {
+        let (a, b, c) = mesh.triangles[t];
+        let [a, b, c] = [a, b, c].map(|v| {
+            *new_index[v].get_or_insert_with(|| {
+                vertex_map.push(v);
+                vertex_map.len() - 1
+            })
+        });
+        triangles.push((a, b, c));
+    }
+    let vertices: Vec<(f64, f64, f64)> = vertex_map.iter().map(|&v| mesh.vertices[v]).collect();
+    let after = acmr(&triangles, cache_size);
+
+    Ok(CacheOptimized {
+        mesh: Mesh {
+            vertices,
+            triangles,
+            edges: Vec::new(),
+        },
+        vertex_map,
+        acmr: (before, after),
+    })
+}
+
+/// Forsyth vertex score for a vertex at `position` in the LRU cache (if any)
+/// with `valence` remaining triangles.
+fn vertex_score(position: Option<usize>, valence: usize, cache_size: usize) -> f64 {
+    if valence == 0 {
+        return -1.0;
+    }
+    let cache_score = match position {
+        None => 0.0,
+        // The most recent triangle's vertices get a fixed score so the
+        // next triangle is not biased towards reusing just one of them
+        Some(p) if p < 3 => 0.75,
+        Some(p) if p < cache_size => (1.0 - (p - 3) as f64 / (cache_size - 3) as f64).powf(1.5),
+        Some(_) => 0.0,
+    };
+    cache_score + 2.0 * (valence as f64).powf(-0.5)
+}
+
+/// Triangle order produced by Forsyth's algorithm.
+fn forsyth_order(mesh: &Mesh, cache_size: usize) -> Vec<usize> {
+    let corners = |t: usize| {
+        let (a, b, c) = mesh.triangles[t];
+        [a, b, c]
+    };
+    let mut vertex_triangles: Vec<Vec<usize>> = vec![Vec::new(); mesh.vertices.len()];
+    for t in 0..mesh.triangles.len() {
+        for v in corners(t) {
+            vertex_triangles[v].push(t);
+        }
+    }
+    let mut scores: Vec<f64> = vertex_triangles
+        .iter()
+        .map(|triangles| vertex_score(None, triangles.len(), cache_size))
+        .collect();
+    let mut emitted = vec![false; mesh.triangles.len()];
+
+    let mut order = Vec::with_capacity(mesh.triangles.len());
+    let mut cache: Vec<usize> = Vec::with_capacity(cache_size + 3);
+    let mut cursor = 0;
+    let mut best = None;
+    while order.len() < mesh.triangles.len() {
+        let t = match best {
+            Some(t) => t,
+            None => {
+                // Nothing adjacent to the cache is left; continue from the
+                // first triangle not yet emitted
+                while emitted[cursor] {
+                    cursor += 1;
+                }
+                cursor
+            }
+        };
+        emitted[t] = true;
+        order.push(t);
+
+        for v in corners(t) {
+            vertex_triangles[v].retain(|&other| other != t);
+            cache.retain(|&cached| cached != v);
+            cache.insert(0, v);
+        }
+        let evicted: Vec<usize> = cache.drain(cache_size.min(cache.len())..).collect();
+
+        for (position, &v) in cache.iter().enumerate() {
+            scores[v] = vertex_score(Some(position), vertex_triangles[v].len(), cache_size);
+        }
+        for &v in &evicted {
+            scores[v] = vertex_score(None, vertex_triangles[v].len(), cache_size);
+        }
+
+        best = None;
+        let mut best_score = f64::NEG_INFINITY;
+        for &v in cache.iter().chain(&evicted) {
+            for &other in &vertex_triangles[v] {
+                let score: f64 = corners(other).iter().map(|&u| scores[u]).sum();
+                if score > best_score {
+                    best_score = score;
+                    best = Some(other);
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Average cache misses per triangle for an LRU cache of `cache_size`.
+fn acmr(triangles: &[(usize, usize, usize)], cache_size: usize) -> f64 {
+    if triangles.is_empty() {
+        return 0.0;
+    }
+    let mut cache: Vec<usize> = Vec::with_capacity(cache_size + 1);
+    let mut misses = 0;
+    for &(a, b, c) in triangles {
+        for v in [a, b, c] {
+            match cache.iter().position(|&cached| cached == v) {
+                Some(p) => {
+                    cache.remove(p);
+                }
+                None => misses += 1,
+            }
+            cache.insert(0, v);
+            cache.truncate(cache_size);
+        }
+    }
+    misses as f64 / triangles.len() as f64
+}
+
+/// Planar texture coordinates for a mesh.
+///
+/// With `scale`, `u = (x - offset_x) * scale_x` and `v = (y - offset_y) * scale_y`.
+/// Otherwise x/y are normalized to [0, 1] over `bounds`, e.g. the extent of
+/// an orthophoto, so the image maps directly onto the mesh.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles'
+///     offset (tuple, optional): (x, y) origin of the mapping when `scale` is given. Default: (0, 0)
+///     scale (tuple, optional): (sx, sy) UV units per x/y unit
+///     bounds (tuple, optional): (xmin, ymin, xmax, ymax) mapped to the unit square.
+///         Default: mesh bounding box
+///
+/// Returns:
+///     list: (u, v) per vertex
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.tin(points_xyz)
+///     >>> uvs = pyspade.planar_uvs(mesh, bounds=(xmin, ymin, xmax, ymax))
+#[pyfunction]
+#[pyo3(signature = (mesh, offset=None, scale=None, bounds=None))]
+pub fn planar_uvs(
+    mesh: Mesh,
+    offset: Option<(f64, f64)>,
+    scale: Option<(f64, f64)>,
+    bounds: Option<(f64, f64, f64, f64)>,
+) -> PyResult<Vec<(f64, f64)>> {
+    planar_uvs_impl(&mesh, offset, scale, bounds).map_err(crate::to_py_err)
+}
+
+pub(crate) fn planar_uvs_impl(
+    mesh: &Mesh,
+    offset: Option<(f64, f64)>,
+    scale: Option<(f64, f64)>,
+    bounds: Option<(f64, f64, f64, f64)>,
+) -> Result<Vec<(f64, f64)>, Box<dyn std::error::Error>> {
+    let ((ox, oy), (sx, sy)) = match (scale, bounds) {
+        (Some(_), Some(_)) => return Err("scale and bounds are mutually exclusive".into()),
+        (Some(scale), None) => (offset.unwrap_or((0.0, 0.0)), scale),
+        (None, bounds) => {
+            if offset.is_some() {
+                return Err("offset requires scale".into());
+            }
+            let (xmin, ymin, xmax, ymax) = match bounds {
+                Some(bounds) => bounds,
+                None => {
+                    let xy: Vec<(f64, f64)> = mesh.vertices.iter().map(|&(x, y, _)| (x, y)).collect();
+                    crate::geometry::bounding_box(&xy).ok_or("mesh has no vertices")?
+                }
+            };
+            let (width, height) = (xmax - xmin, ymax - ymin);
+            if width.is_nan() || height.is_nan() || width <= 0.0 || height <= 0.0 {
+                return Err("bounds must have a positive extent".into());
+            }
+            ((xmin, ymin), (1.0 / width, 1.0 / height))
+        }
+    };
+    Ok(mesh
+        .vertices
+        .iter()
+        .map(|&(x, y, _)| ((x - ox) * sx, (y - oy) * sy))
+        .collect())
+}
+
+/// Area-weighted per-vertex normals of a 2.5D mesh.
+///
+/// Every triangle contributes its unnormalized face normal (whose length is
+/// twice its area) to its three corners. Normals point up (+z) for
+/// counter-clockwise triangles; unused vertices get (0, 0, 1).
+///
+/// Args:
+///     mesh (dict): Mesh with (x, y, z) 'vertices' and 'triangles'
+///
+/// Returns:
+///     list: Unit (nx, ny, nz) normal per vertex
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.grid_to_tin(dem, transform, max_error=0.1)
+///     >>> normals = pyspade.vertex_normals(mesh)
+#[pyfunction]
+pub fn vertex_normals(mesh: Mesh) -> PyResult<Vec<(f64, f64, f64)>> {
+    Ok(vertex_normals_impl(&mesh.vertices, &mesh.triangles))
+}
+
+pub(crate) fn vertex_normals_impl(
+    vertices: &[(f64, f64, f64)],
+    triangles: &[(usize, usize, usize)],
+) -> Vec<(f64, f64, f64)> {
+    let mut sums = vec![(0.0, 0.0, 0.0); vertices.len()];
+    for &(a, b, c) in triangles {
+        let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+        let u = (pb.0 - pa.0, pb.1 - pa.1, pb.2 - pa.2);
+        let v = (pc.0 - pa.0, pc.1 - pa.1, pc.2 - pa.2);
+        let n = (u.1 * v.2 - u.2 * v.1, u.2 * v.0 - u.0 * v.2, u.0 * v.1 - u.1 * v.0);
+        for i in [a, b, c] {
+            let sum: &mut (f64, f64, f64) = &mut sums[i];
+            *sum = (sum.0 + n.0, sum.1 + n.1, sum.2 + n.2);
+        }
+    }
+    sums.into_iter()
+        .map(|(x, y, z)| {
+            let length = (x * x + y * y + z * z).sqrt();
+            if length > 0.0 {
+                (x / length, y / length, z / length)
+            } else {
+                (0.0, 0.0, 1.0)
+            }
+        })
+        .collect()
+}
+
+/// Orient every facet of a 2.5D mesh to face up (or down), for export.
+///
+/// STL, OBJ and glTF viewers derive a facet's front side from its winding,
+/// so a mesh assembled from several sources can show inside-out patches.
+/// Triangles whose normal points the wrong way in z have their last two
+/// indices swapped; vertical and degenerate triangles are left as they are.
+///
+/// Args:
+///     mesh (dict): Mesh with (x, y, z) 'vertices' and 'triangles'
+///     up (bool, optional): If True, normals point to +z (counter-clockwise seen from
+///         above); if False, to -z. Default: True
+///
+/// Returns:
+///     dict: The mesh with keys:
+///         - 'vertices': Vertex coordinates, unchanged
+///         - 'triangles': Triangles, consistently oriented
+///         - 'edges': Constrained edges, unchanged (only if the input has any)
+///         - 'flipped': Indices of the triangles that were flipped
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.orient_facets(pyspade.tin(points_xyz))
+///     >>> normals = pyspade.vertex_normals(mesh)
+#[pyfunction]
+#[pyo3(signature = (mesh, up=true))]
+pub fn orient_facets(mut mesh: Mesh, up: bool) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let flipped = orient_facets_impl(&mut mesh, up);
+
+        let mut output = mesh.into_dict(py);
+        output.insert("flipped".to_string(), flipped.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Flip the triangles of `mesh` facing the wrong way in z, returning their
+/// indices.
+pub(crate) fn orient_facets_impl(mesh: &mut Mesh, up: bool) -> Vec<usize> {
+    let sign = if up { 1.0 } else { -1.0 };
+    let mut flipped = Vec::new();
+    for t in 0..mesh.triangles.len() {
+        let [p, q, r] = mesh.corners(t);
+        let nz = (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0);
+        if nz * sign < 0.0 {
+            let (a, b, c) = mesh.triangles[t];
+            mesh.triangles[t] = (a, c, b);
+            flipped.push(t);
+        }
+    }
+    flipped
+}
+
+/// Hillshade of a terrain mesh lit by the sun, for quick-look shaded relief.
+///
+/// The illumination is the cosine of the angle between the surface normal
+/// and the direction to the sun, clamped at 0 for slopes facing away, as in
+/// GIS hillshade tools but without rasterizing first. Normals are taken
+/// facing up whatever the triangle winding. Per vertex, the area-weighted
+/// vertex normals give smooth shading when the values are interpolated
+/// across the triangles. Degenerate triangles and unused vertices get NaN.
+///
+/// Args:
+///     mesh (dict): Terrain mesh with (x, y, z) 'vertices' and 'triangles'
+///     azimuth (float, optional): Direction to the sun in degrees, clockwise from north
+///         (+y). Default: 315.0 (northwest)
+///     altitude (float, optional): Angle of the sun above the horizon in degrees.
+///         Default: 45.0
+///     z_factor (float, optional): Vertical exaggeration applied to z. Default: 1.0
+///     per_vertex (bool, optional): If True, return one value per vertex instead of one
+///         per triangle. Default: False
+///
+/// Returns:
+///     list: Illumination in [0, 1] per triangle (or per vertex)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> terrain = pyspade.tin(points_xyz)
+///     >>> shade = pyspade.hillshade(terrain, azimuth=315.0, altitude=45.0, z_factor=2.0)
+///     >>> gray = [int(255 * s) for s in shade]
+#[pyfunction]
+#[pyo3(signature = (mesh, azimuth=315.0, altitude=45.0, z_factor=1.0, per_vertex=false))]
+pub fn hillshade(mesh: Mesh, azimuth: f64, altitude: f64, z_factor: f64, per_vertex: bool) -> PyResult<Vec<f64>> {
+    if !(0.0..=90.0).contains(&altitude) {
+        return Err(crate::to_py_err("altitude must be between 0 and 90 degrees".into()));
+    }
+    if z_factor.is_nan() || z_factor <= 0.0 {
+        return Err(crate::to_py_err("z_factor must be positive".into()));
+    }
+    Ok(hillshade_impl(&mesh, azimuth, altitude, z_factor, per_vertex))
+}
+
+pub(crate) fn hillshade_impl(mesh: &Mesh, azimuth: f64, altitude: f64, z_factor: f64, per_vertex: bool) -> Vec<f64> {
+    let (azimuth, altitude) = (azimuth.to_radians(), altitude.to_radians());
+    let sun = (azimuth.sin() * altitude.cos(), azimuth.cos() * altitude.cos(), altitude.sin());
+    let shade = |(x, y, z): (f64, f64, f64)| {
+        let length = (x * x + y * y + z * z).sqrt();
+        if length > 0.0 {
+            ((x * sun.0 + y * sun.1 + z * sun.2) / length).max(0.0)
+        } else {
+            f64::NAN
+        }
+    };
+
+    // Unnormalized face normals (twice the area long), facing up
+    let normals = (0..mesh.triangles.len()).map(|t| {
+        let [p, q, r] = mesh.corners(t);
+        let u = (q.0 - p.0, q.1 - p.1, (q.2 - p.2) * z_factor);
+        let v = (r.0 - p.0, r.1 - p.1, (r.2 - p.2) * z_factor);
+        let n = (u.1 * v.2 - u.2 * v.1, u.2 * v.0 - u.0 * v.2, u.0 * v.1 - u.1 * v.0);
+        if n.2 < 0.0 {
+            (-n.0, -n.1, -n.2)
+        } else {
+            n
+        }
+    });
+    if !per_vertex {
+        return normals.map(shade).collect();
+    }
+    let mut sums = vec![(0.0, 0.0, 0.0); mesh.vertices.len()];
+    for (&(a, b, c), n) in mesh.triangles.iter().zip(normals) {
+        for i in [a, b, c] {
+            let sum: &mut (f64, f64, f64) = &mut sums[i];
+            *sum = (sum.0 + n.0, sum.1 + n.1, sum.2 + n.2);
+        }
+    }
+    sums.into_iter().map(shade).collect()
+}
@@ -0,0 +1,209 @@
+//! Cleanup of polygon rings before triangulation.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::constraints::check_constraints_impl;
+use crate::geometry;
+use crate::validation::{duplicate_vertices, open_ring, ring_inside};
+
+type Ring = Vec<(f64, f64)>;
+
+/// Fix common defects of a polygon with holes.
+///
+/// In order: vertices closer than `tolerance` (across all rings) are snapped
+/// onto the first of them, repeated consecutive points are removed, rings
+/// are split where they cross or touch themselves, zero-area pieces are
+/// dropped, outer rings are made counter-clockwise and holes clockwise, and
+/// each hole is assigned to the outer ring containing it. A self-intersecting
+/// outer ring (e.g. a bow tie) can fall apart into several polygons.
+///
+/// Args:
+///     outer (list): Exterior boundary vertices as list of (x, y) tuples
+///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples. Default: []
+///     tolerance (float, optional): Distance below which vertices are snapped together.
+///         Default: 0.0 (only exact duplicates)
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'polygons': List of (outer, holes) tuples ready for `triangulate()`
+///         - 'changes': Human-readable log of every change made
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.repair(outer, holes, tolerance=1e-3)
+///     >>> meshes = [pyspade.triangulate(o, h) for o, h in result['polygons']]
+#[pyfunction]
+#[pyo3(signature = (outer, holes=None, tolerance=0.0))]
+pub fn repair(
+    outer: Vec<(f64, f64)>,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    tolerance: f64,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = repair_impl(&outer, &holes.unwrap_or_default(), tolerance).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("polygons".to_string(), result.polygons.into_py(py));
+        output.insert("changes".to_string(), result.changes.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct Repaired {
+    pub polygons: Vec<(Ring, Vec<Ring>)>,
+    pub changes: Vec<String>,
+}
+
+pub(crate) fn repair_impl(
+    outer: &[(f64, f64)],
+    holes: &[Ring],
+    tolerance: f64,
+) -> Result<Repaired, Box<dyn std::error::Error>> {
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(format!("tolerance must be non-negative, got {}", tolerance).into());
+    }
+    let mut changes = Vec::new();
+    let name = |r: usize| if r == 0 { "outer ring".to_string() } else { format!("hole {}", r - 1) };
+
+    let mut rings: Vec<Ring> = std::iter::once(outer)
+        .chain(holes.iter().map(Vec::as_slice))
+        .map(|ring| open_ring(ring).to_vec())
+        .collect();
+
+    // Snap near-coincident vertices onto the first of them
+    if tolerance > 0.0 {
+        let vertices: Vec<(f64, f64, f64)> = rings.iter().flatten().map(|&(x, y)| (x, y, 0.0)).collect();
+        let mut flat: Vec<(f64, f64)> = rings.iter().flatten().copied().collect();
+        let mut snapped = 0;
+        for (i, j) in duplicate_vertices(&vertices, tolerance) {
+            if flat[j] != flat[i] {
+                flat[j] = flat[i];
+                snapped += 1;
+            }
+        }
+        if snapped > 0 {
+            changes.push(format!("snapped {} vertices onto vertices within {}", snapped, tolerance));
+        }
+        let mut values = flat.into_iter();
+        for ring in &mut rings {
+            ring.iter_mut().for_each(|p| *p = values.next().expect("one value per vertex"));
+        }
+    }
+
+    let mut outers: Vec<Ring> = Vec::new();
+    let mut inners: Vec<(usize, Ring)> = Vec::new();
+    for (r, mut ring) in rings.into_iter().enumerate() {
+        let count = ring.len();
+        ring.dedup();
+        if ring.len() > 1 && ring.first() == ring.last() {
+            ring.pop();
+        }
+        if ring.len() < count {
+            changes.push(format!("{}: removed {} repeated points", name(r), count - ring.len()));
+        }
+
+        let (noded, inserted) = node_ring(&ring);
+        let pieces = split_loops(noded);
+        if inserted > 0 {
+            changes.push(format!("{}: inserted {} self-intersection points", name(r), inserted));
+        }
+        if pieces.len() > 1 {
+            changes.push(format!("{}: split into {} rings where it meets itself", name(r), pieces.len()));
+        }
+
+        for mut piece in pieces {
+            let area = geometry::signed_area(&piece);
+            if piece.len() < 3 || area == 0.0 {
+                changes.push(format!("{}: dropped a degenerate piece of {} points", name(r), piece.len()));
+                continue;
+            }
+            // Outer rings counter-clockwise, holes clockwise
+            if (r == 0) == (area < 0.0) {
+                piece.reverse();
+                let direction = if r == 0 { "counter-clockwise" } else { "clockwise" };
+                changes.push(format!("{}: reversed to run {}", name(r), direction));
+            }
+            if r == 0 {
+                outers.push(piece);
+            } else {
+                inners.push((r, piece));
+            }
+        }
+    }
+
+    let mut polygons: Vec<(Ring, Vec<Ring>)> = outers.into_iter().map(|ring| (ring, Vec::new())).collect();
+    for (r, hole) in inners {
+        match polygons.iter_mut().find(|(outer, _)| ring_inside(&hole, outer)) {
+            Some((_, holes)) => holes.push(hole),
+            None => changes.push(format!("{}: dropped, as it lies outside the outer ring", name(r))),
+        }
+    }
+
+    Ok(Repaired { polygons, changes })
+}
+
+/// `ring` with the points where it crosses or touches itself inserted as
+/// vertices, and the number of such points.
+fn node_ring(ring: &[(f64, f64)]) -> (Ring, usize) {
+    let n = ring.len();
+    let mut splits: Vec<Vec<(f64, f64)>> = vec![Vec::new(); n];
+    let mut inserted = 0;
+    let segment = |s: usize| (ring[s], ring[(s + 1) % n]);
+    for conflict in check_constraints_impl(ring, &[], &[]) {
+        let (i, j) = conflict.pair;
+        inserted += 1;
+        let points = if conflict.kind == "overlap" {
+            // The ends of each segment within the other
+            let ((a, b), (c, d)) = (segment(i), segment(j));
+            vec![a, b, c, d]
+        } else {
+            vec![conflict.point]
+        };
+        for s in [i, j] {
+            let (a, b) = segment(s);
+            splits[s].extend(points.iter().copied().filter(|&p| p != a && p != b && on_segment(p, a, b)));
+        }
+    }
+
+    let mut noded = Vec::with_capacity(n + inserted * 2);
+    for (s, mut points) in splits.into_iter().enumerate() {
+        let (a, _) = segment(s);
+        noded.push(a);
+        points.sort_by(|p, q| distance_2(a, *p).total_cmp(&distance_2(a, *q)));
+        points.dedup();
+        noded.extend(points);
+    }
+    (noded, inserted)
+}
+
+fn distance_2(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+/// Whether `p`, known to lie on the line through `a`-`b` up to rounding,
+/// lies between `a` and `b`.
+fn on_segment(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let t = ((p.0 - a.0) * dx + (p.1 - a.1) * dy) / (dx * dx + dy * dy);
+    t > 0.0 && t < 1.0
+}
+
+/// Split a closed ring at every repeated vertex into simple loops.
+fn split_loops(ring: Ring) -> Vec<Ring> {
+    let mut loops = Vec::new();
+    let mut path: Ring = Vec::with_capacity(ring.len());
+    for p in ring {
+        match path.iter().position(|&q| q == p) {
+            Some(k) => {
+                let mut piece = vec![p];
+                piece.extend(path.split_off(k + 1));
+                loops.push(piece);
+            }
+            None => path.push(p),
+        }
+    }
+    loops.push(path);
+    loops
+}
@@ -0,0 +1,189 @@
+use pyo3::prelude::*;
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::collections::HashMap;
+
+use crate::geometry;
+use crate::logging;
+use crate::regions::{constraint_edges, insert_ring, label_faces, output_vertices, Cdt};
+use crate::segments;
+
+/// Mesh a domain in which every polyline becomes a chain of constrained edges.
+///
+/// Polylines (e.g. road centerlines) may cross each other; crossings are
+/// split into shared vertices. With `corridor_width`, a band around every
+/// polyline is seeded with points at `corridor_max_edge_length` spacing so
+/// the mesh is finer along the lines.
+///
+/// Args:
+///     domain (list): Domain boundary as list of (x, y) tuples
+///     polylines (list): Open polylines, each as list of (x, y) tuples
+///     max_edge_length (float, optional): Target maximum edge length for mesh refinement
+///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
+///     corridor_width (float, optional): Total width of the densified band around each polyline
+///     corridor_max_edge_length (float, optional): Edge length inside the corridor.
+///         Default: corridor_width / 2
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edge indices
+///         - 'polylines': Per input polyline, the vertex indices of its constrained chain
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.mesh_polylines(
+///     ...     domain=[(0, 0), (100, 0), (100, 100), (0, 100)],
+///     ...     polylines=[[(10, 50), (90, 50)], [(50, 10), (50, 90)]],
+///     ...     max_edge_length=10.0,
+///     ...     corridor_width=8.0
+///     ... )
+///     >>> print(result['polylines'][0])
+#[pyfunction]
+#[pyo3(signature = (
+    domain,
+    polylines,
+    max_edge_length=None,
+    min_angle=None,
+    corridor_width=None,
+    corridor_max_edge_length=None,
+))]
+pub fn mesh_polylines(
+    domain: Vec<(f64, f64)>,
+    polylines: Vec<Vec<(f64, f64)>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+    corridor_width: Option<f64>,
+    corridor_max_edge_length: Option<f64>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let corridor = corridor_width.map(|width| (width, corridor_max_edge_length.unwrap_or(0.5 * width)));
+        let result = mesh_polylines_impl(domain, &polylines, max_edge_length, min_angle, corridor)
+            .map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+        output.insert("polylines".to_string(), result.polylines.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct PolylineMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+    pub polylines: Vec<Vec<usize>>,
+}
+
+/// `corridor` is `(width, edge_length)` of the densified band, if any.
+pub(crate) fn mesh_polylines_impl(
+    domain: Vec<(f64, f64)>,
+    polylines: &[Vec<(f64, f64)>],
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+    corridor: Option<(f64, f64)>,
+) -> Result<PolylineMesh, Box<dyn std::error::Error>> {
+    if domain.len() < 3 {
+        return Err("domain must have at least 3 vertices".into());
+    }
+    if let Some((width, h)) = corridor {
+        if width.is_nan() || width <= 0.0 || h.is_nan() || h <= 0.0 {
+            return Err("corridor_width and corridor_max_edge_length must be positive".into());
+        }
+    }
+
+    let mut cdt = Cdt::default();
+    insert_ring(&mut cdt, &domain)?;
+
+    let mut polyline_handles: Vec<Vec<FixedVertexHandle>> = Vec::with_capacity(polylines.len());
+    for polyline in polylines {
+        let mut handles: Vec<FixedVertexHandle> = Vec::with_capacity(polyline.len());
+        for &(x, y) in polyline {
+            let handle = cdt.insert(Point2::new(x, y))?;
+            if handles.last() != Some(&handle) {
+                handles.push(handle);
+            }
+        }
+        for pair in handles.windows(2) {
+            // Crossing polylines are split at their intersection points
+            cdt.add_constraint_and_split(pair[0], pair[1], |p| p);
+        }
+        polyline_handles.push(handles);
+    }
+
+    if let Some((width, h)) = corridor {
+        for point in corridor_points(polylines, width, h) {
+            if geometry::point_in_polygon((point.x, point.y), &domain) {
+                cdt.insert(point)?;
+            }
+        }
+    }
+
+    if max_edge_length.is_some() || min_angle.is_some() || corridor.is_some() {
+        let before = cdt.num_vertices();
+        let result = cdt.refine(crate::refinement_parameters(max_edge_length, min_angle));
+        logging::refinement(before, cdt.num_vertices(), result.refinement_complete);
+    }
+
+    let labels = label_faces(&cdt, std::slice::from_ref(&domain));
+    let triangles = cdt
+        .inner_faces()
+        .filter(|face| labels[&face.fix()].is_some())
+        .map(|face| {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            (a, b, c)
+        })
+        .collect();
+
+    let polylines = polyline_handles
+        .iter()
+        .map(|handles| {
+            let mut chain: Vec<usize> = handles.first().map(|v| v.index()).into_iter().collect();
+            for pair in handles.windows(2) {
+                for edge in segments::trace_segment(&cdt, pair[0], pair[1]) {
+                    chain.push(cdt.directed_edge(edge).to().fix().index());
+                }
+            }
+            chain
+        })
+        .collect();
+
+    Ok(PolylineMesh {
+        vertices: output_vertices(&cdt),
+        triangles,
+        edges: constraint_edges(&cdt),
+        polylines,
+    })
+}
+
+/// Staggered rows of points parallel to each polyline segment, spaced `h`
+/// apart, filling a band of total `width` (the centerline itself excluded).
+fn corridor_points(polylines: &[Vec<(f64, f64)>], width: f64, h: f64) -> Vec<Point2<f64>> {
+    let row_spacing = h * 3f64.sqrt() / 2.0;
+    let rows = (0.5 * width / row_spacing).floor() as i64;
+    let mut points = Vec::new();
+    for polyline in polylines {
+        for pair in polyline.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            let length = (x1 - x0).hypot(y1 - y0);
+            if length == 0.0 {
+                continue;
+            }
+            let (tx, ty) = ((x1 - x0) / length, (y1 - y0) / length);
+            let (nx, ny) = (-ty, tx);
+            let steps = (length / h).round().max(1.0) as i64;
+            for row in (-rows..=rows).filter(|&row| row != 0) {
+                let offset = row as f64 * row_spacing;
+                let shift = if row.rem_euclid(2) == 1 { 0.5 } else { 0.0 };
+                for k in 0..steps {
+                    let t = (k as f64 + shift) * length / steps as f64;
+                    points.push(Point2::new(x0 + t * tx + offset * nx, y0 + t * ty + offset * ny));
+                }
+            }
+        }
+    }
+    points
+}
@@ -0,0 +1,104 @@
+//! Random points on a mesh, e.g. for Monte-Carlo solar or noise analysis.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::arrays::{float64_array, int64_array};
+use crate::mesh::Mesh;
+use crate::random::Rng;
+
+/// Draw random points on a mesh.
+///
+/// A triangle is picked with probability proportional to its area and a
+/// point is drawn uniformly inside it (barycentric sampling), so points are
+/// uniformly distributed: over the xy footprint with `weighting="area"`, or
+/// over the 3D surface with `weighting="uniform_density"` (which puts more
+/// points on steep terrain).
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices' and 'triangles'
+///     n (int): Number of points to draw
+///     weighting (str, optional): "area" (planar area) or "uniform_density"
+///         (surface area). Default: "area"
+///     seed (int, optional): Random seed for reproducible samples. Default: None
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'points': (n, 3) float64 NumPy array of (x, y, z) sample points on the mesh
+///         - 'triangles': int64 NumPy array with the index of the triangle each point lies in
+///
+/// Example:
+///     >>> import pyspade
+///     >>> ground = pyspade.tin(points_xyz)
+///     >>> samples = pyspade.sample_points(ground, 1_000_000, seed=42)
+#[pyfunction]
+#[pyo3(signature = (mesh, n, weighting="area", seed=None))]
+pub fn sample_points(mesh: Mesh, n: usize, weighting: &str, seed: Option<u64>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (points, triangles) = py
+            .allow_threads(|| sample_points_impl(&mesh, n, weighting, seed).map_err(|e| e.to_string()))
+            .map_err(|e| crate::to_py_err(e.into()))?;
+        let points: Vec<f64> = points.into_iter().flat_map(|(x, y, z)| [x, y, z]).collect();
+        let triangles: Vec<i64> = triangles.into_iter().map(|t| t as i64).collect();
+
+        let mut output = HashMap::new();
+        output.insert("points".to_string(), float64_array(py, &points, &[n, 3])?);
+        output.insert("triangles".to_string(), int64_array(py, &triangles, &[n])?);
+
+        Ok(output)
+    })
+}
+
+type SampledPoints = (Vec<(f64, f64, f64)>, Vec<usize>);
+
+pub(crate) fn sample_points_impl(
+    mesh: &Mesh,
+    n: usize,
+    weighting: &str,
+    seed: Option<u64>,
+) -> Result<SampledPoints, Box<dyn std::error::Error>> {
+    let surface = match weighting {
+        "area" => false,
+        "uniform_density" => true,
+        _ => return Err(format!("unknown weighting '{}', expected 'area' or 'uniform_density'", weighting).into()),
+    };
+
+    // Cumulative triangle areas for picking triangles by binary search
+    let mut cumulative = Vec::with_capacity(mesh.triangles.len());
+    let mut total = 0.0;
+    for t in 0..mesh.triangles.len() {
+        let [p, q, r] = mesh.corners(t);
+        let (u, v) = ((q.0 - p.0, q.1 - p.1, q.2 - p.2), (r.0 - p.0, r.1 - p.1, r.2 - p.2));
+        let z = u.0 * v.1 - u.1 * v.0;
+        let area = if surface {
+            0.5 * (u.1 * v.2 - u.2 * v.1).hypot(u.2 * v.0 - u.0 * v.2).hypot(z)
+        } else {
+            0.5 * z.abs()
+        };
+        total += area;
+        cumulative.push(total);
+    }
+    if total <= 0.0 || total.is_nan() {
+        return Err("mesh has no area to sample".into());
+    }
+
+    let mut rng = Rng::new(seed);
+    let mut points = Vec::with_capacity(n);
+    let mut triangles = Vec::with_capacity(n);
+    for _ in 0..n {
+        let target = rng.next_f64() * total;
+        let t = cumulative.partition_point(|&c| c <= target).min(cumulative.len() - 1);
+        let (mut s, mut u) = (rng.next_f64(), rng.next_f64());
+        if s + u > 1.0 {
+            (s, u) = (1.0 - s, 1.0 - u);
+        }
+        let [p, q, r] = mesh.corners(t);
+        points.push((
+            p.0 + s * (q.0 - p.0) + u * (r.0 - p.0),
+            p.1 + s * (q.1 - p.1) + u * (r.1 - p.1),
+            p.2 + s * (q.2 - p.2) + u * (r.2 - p.2),
+        ));
+        triangles.push(t);
+    }
+    Ok((points, triangles))
+}
@@ -0,0 +1,45 @@
+//! Tracking of input segments through constraint splitting and refinement.
+
+use spade::handles::FixedDirectedEdgeHandle;
+use spade::{FixedVertexHandle, Triangulation};
+
+use crate::regions::Cdt;
+
+/// Follow the chain of constraint edges that an input segment `from`-`to`
+/// was split into, returning the directed sub-edges in order.
+///
+/// Stops early (returning the partial chain) if the segment is not
+/// represented by collinear constraint edges, e.g. because it was never
+/// added as a constraint.
+pub(crate) fn trace_segment(cdt: &Cdt, from: FixedVertexHandle, to: FixedVertexHandle) -> Vec<FixedDirectedEdgeHandle> {
+    let target = cdt.vertex(to).position();
+    let mut current = from;
+    let mut chain = Vec::new();
+    for _ in 0..cdt.num_vertices() {
+        if current == to {
+            break;
+        }
+        let origin = cdt.vertex(current).position();
+        let (dx, dy) = (target.x - origin.x, target.y - origin.y);
+        let length = dx.hypot(dy);
+        let next = cdt
+            .vertex(current)
+            .out_edges()
+            .filter(|edge| edge.as_undirected().is_constraint_edge())
+            .map(|edge| {
+                let p = edge.to().position();
+                let (ex, ey) = (p.x - origin.x, p.y - origin.y);
+                let cos = (ex * dx + ey * dy) / (ex.hypot(ey) * length);
+                (cos, edge)
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+        match next {
+            Some((cos, edge)) if cos > 1.0 - 1e-9 => {
+                chain.push(edge.fix());
+                current = edge.to().fix();
+            }
+            _ => break,
+        }
+    }
+    chain
+}
@@ -0,0 +1,484 @@
+//! Local mesh size control on top of Spade's global refinement parameters.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use spade::handles::{FixedFaceHandle, InnerTag};
+use spade::{FixedVertexHandle, Point2, PositionInTriangulation, Triangulation};
+use std::collections::HashSet;
+
+use crate::constraints::SegmentGrid;
+use crate::geometry;
+use crate::graphs::knn_graph_impl;
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::regions::{face_component, Cdt};
+use crate::terrain::barycentric_weights;
+use crate::voronoi::{delaunay, Delaunay};
+
+/// Locate the inner face containing `(x, y)`.
+pub(crate) fn locate_face(cdt: &Cdt, x: f64, y: f64) -> Option<FixedFaceHandle<InnerTag>> {
+    match cdt.locate(Point2::new(x, y)) {
+        PositionInTriangulation::OnFace(face) => Some(face),
+        PositionInTriangulation::OnEdge(edge) => {
+            let edge = cdt.directed_edge(edge);
+            edge.face()
+                .as_inner()
+                .or_else(|| edge.rev().face().as_inner())
+                .map(|face| face.fix())
+        }
+        _ => None,
+    }
+}
+
+/// Seed every region given as `(x, y, max_edge_length)` with a triangular
+/// lattice of that spacing.
+///
+/// A region is the set of faces reachable from the seed point without
+/// crossing a constraint edge. The subsequent quality refinement grades the
+/// mesh smoothly between the seeded regions and their surroundings.
+pub(crate) fn seed_region_sizes(cdt: &mut Cdt, seeds: &[(f64, f64, f64)]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut points = Vec::new();
+    for &(x, y, h) in seeds {
+        if h.is_nan() || h <= 0.0 {
+            return Err(format!("region size at ({}, {}) must be positive, got {}", x, y, h).into());
+        }
+        let start = locate_face(cdt, x, y)
+            .ok_or_else(|| format!("region seed ({}, {}) lies outside the triangulation", x, y))?;
+        points.extend(lattice_points(cdt, &face_component(cdt, start), (x, y), h));
+    }
+    for point in points {
+        cdt.insert(point)?;
+    }
+    Ok(())
+}
+
+/// Reject region sizes coarser than the global `max_edge_length`: refinement
+/// enforces the global limit everywhere, so they could not be honored.
+pub(crate) fn check_region_sizes(seeds: &[(f64, f64, f64)], max_edge_length: Option<f64>) -> PyResult<()> {
+    let Some(global) = max_edge_length else {
+        return Ok(());
+    };
+    match seeds.iter().find(|&&(_, _, h)| h > global) {
+        Some(&(x, y, h)) => Err(PyValueError::new_err(format!(
+            "region size {} at ({}, {}) exceeds max_edge_length={}, which applies everywhere",
+            h, x, y, global
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Lattice points of spacing `h` (anchored at `origin`) covering `faces`.
+pub(crate) fn lattice_points(
+    cdt: &Cdt,
+    faces: &[FixedFaceHandle<InnerTag>],
+    origin: (f64, f64),
+    h: f64,
+) -> Vec<Point2<f64>> {
+    let mut seen = HashSet::new();
+    let mut points = Vec::new();
+    for &face in faces {
+        lattice_points_in_face(cdt, face, origin, h, &mut seen, &mut points);
+    }
+    points
+}
+
+/// Add the constraint `from`-`to` as a chain of equal pieces no longer than
+/// `max_edge_length`.
+pub(crate) fn add_subdivided_constraint(
+    cdt: &mut Cdt,
+    from: FixedVertexHandle,
+    to: FixedVertexHandle,
+    max_edge_length: f64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Always subdivide from the lower end point, so a segment shared by two
+    // rings gets the very same split points from both
+    let (p, q) = (cdt.vertex(from).position(), cdt.vertex(to).position());
+    let (from, to, p, q) = if (p.x, p.y) > (q.x, q.y) { (to, from, q, p) } else { (from, to, p, q) };
+    let pieces = (distance_2(p, q).sqrt() / max_edge_length).ceil().max(1.0) as usize;
+    let mut previous = from;
+    for k in 1..pieces {
+        let t = k as f64 / pieces as f64;
+        let next = cdt.insert(Point2::new(p.x + t * (q.x - p.x), p.y + t * (q.y - p.y)))?;
+        cdt.add_constraint(previous, next);
+        previous = next;
+    }
+    cdt.add_constraint(previous, to);
+    Ok(())
+}
+
+/// Collect the lattice points (origin `origin`, spacing `h`) inside `face`,
+/// skipping points that would crowd the face's vertices or constraint edges.
+fn lattice_points_in_face(
+    cdt: &Cdt,
+    face: FixedFaceHandle<InnerTag>,
+    origin: (f64, f64),
+    h: f64,
+    seen: &mut HashSet<(i64, i64)>,
+    out: &mut Vec<Point2<f64>>,
+) {
+    let face = cdt.face(face);
+    let [a, b, c] = face.positions();
+    let dy = h * 3f64.sqrt() / 2.0;
+    let ymin = a.y.min(b.y).min(c.y);
+    let ymax = a.y.max(b.y).max(c.y);
+    let xmin = a.x.min(b.x).min(c.x);
+    let xmax = a.x.max(b.x).max(c.x);
+
+    let constraints: Vec<_> = face
+        .adjacent_edges()
+        .into_iter()
+        .filter(|edge| edge.as_undirected().is_constraint_edge())
+        .map(|edge| edge.positions())
+        .collect();
+
+    let j0 = ((ymin - origin.1) / dy).ceil() as i64;
+    let j1 = ((ymax - origin.1) / dy).floor() as i64;
+    for j in j0..=j1 {
+        let offset = if j.rem_euclid(2) == 1 { 0.5 * h } else { 0.0 };
+        let i0 = ((xmin - origin.0 - offset) / h).ceil() as i64;
+        let i1 = ((xmax - origin.0 - offset) / h).floor() as i64;
+        for i in i0..=i1 {
+            let p = Point2::new(origin.0 + offset + i as f64 * h, origin.1 + j as f64 * dy);
+            if !in_triangle(p, a, b, c) || seen.contains(&(i, j)) {
+                continue;
+            }
+            let crowded = [a, b, c].iter().any(|v| distance_2(p, *v) < 0.25 * h * h)
+                || constraints
+                    .iter()
+                    .any(|[s, t]| segment_distance_2(p, *s, *t) < 0.25 * h * h);
+            if !crowded {
+                seen.insert((i, j));
+                out.push(p);
+            }
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p` (positive if `p` lies left of `a`-`b`).
+pub(crate) fn orient(a: Point2<f64>, b: Point2<f64>, p: Point2<f64>) -> f64 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// Inclusive point-in-triangle test for either orientation.
+pub(crate) fn in_triangle(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> bool {
+    let (d0, d1, d2) = (orient(a, b, p), orient(b, c, p), orient(c, a, p));
+    let has_neg = d0 < 0.0 || d1 < 0.0 || d2 < 0.0;
+    let has_pos = d0 > 0.0 || d1 > 0.0 || d2 > 0.0;
+    !(has_neg && has_pos)
+}
+
+pub(crate) fn distance_2(a: Point2<f64>, b: Point2<f64>) -> f64 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+}
+
+/// Squared distance from `p` to the segment `s`-`t`.
+pub(crate) fn segment_distance_2(p: Point2<f64>, s: Point2<f64>, t: Point2<f64>) -> f64 {
+    let (dx, dy) = (t.x - s.x, t.y - s.y);
+    let len_2 = dx * dx + dy * dy;
+    if len_2 == 0.0 {
+        return distance_2(p, s);
+    }
+    let u = (((p.x - s.x) * dx + (p.y - s.y) * dy) / len_2).clamp(0.0, 1.0);
+    distance_2(p, Point2::new(s.x + u * dx, s.y + u * dy))
+}
+
+/// Most vertices `refine_to_size()` adds before giving up on a size field
+const MAX_SIZED_VERTICES: usize = 5_000_000;
+
+/// Target edge length over the domain from the local sizing inputs of
+/// `triangulate()`, blended into the global max_edge_length.
+#[derive(Default)]
+pub(crate) struct SizeField<'a> {
+    pub global: Option<f64>,
+    /// (x, y, size, radius) point features
+    pub points: Vec<(f64, f64, f64, f64)>,
+    /// (polyline, size, width) line features
+    pub lines: Vec<(Vec<(f64, f64)>, f64, f64)>,
+    pub boundary: Option<BoundaryGrading>,
+    pub background: Option<BackgroundSizes<'a>>,
+    pub density: Option<DensitySizes>,
+}
+
+/// Size growing linearly with the distance from the domain's boundary
+/// segments: `h0 + slope * d`.
+pub(crate) struct BoundaryGrading {
+    pub h0: f64,
+    pub slope: f64,
+    segments: Vec<(Point2<f64>, Point2<f64>)>,
+    grid: SegmentGrid,
+}
+
+impl BoundaryGrading {
+    /// Grading away from the segments of `rings`, each an implicitly closed ring.
+    pub fn new(h0: f64, slope: f64, rings: &[&[(f64, f64)]]) -> Result<Self, Box<dyn std::error::Error>> {
+        if h0.is_nan() || h0 <= 0.0 {
+            return Err(format!("boundary grading size must be positive, got {}", h0).into());
+        }
+        if slope.is_nan() || slope < 0.0 {
+            return Err(format!("boundary grading slope must be non-negative, got {}", slope).into());
+        }
+        let segments: Vec<(Point2<f64>, Point2<f64>)> = rings
+            .iter()
+            .flat_map(|ring| geometry::ring_edges(ring))
+            .map(|(p, q)| (Point2::new(p.0, p.1), Point2::new(q.0, q.1)))
+            .collect();
+        let grid = SegmentGrid::new(&segments);
+        Ok(BoundaryGrading { h0, slope, segments, grid })
+    }
+
+    fn size_at(&self, p: Point2<f64>) -> f64 {
+        if self.segments.is_empty() {
+            return f64::INFINITY;
+        }
+        self.h0 + self.slope * self.grid.nearest(&self.segments, p).1
+    }
+}
+
+/// Per-vertex sizes on a background mesh, linearly interpolated over its
+/// triangles and not limiting anything outside them.
+pub(crate) struct BackgroundSizes<'a> {
+    mesh: &'a Mesh,
+    sizes: &'a [f64],
+    grid: TriangleGrid,
+}
+
+impl<'a> BackgroundSizes<'a> {
+    pub fn new(mesh: &'a Mesh, sizes: &'a [f64]) -> Result<Self, Box<dyn std::error::Error>> {
+        if sizes.len() != mesh.vertices.len() {
+            return Err(format!(
+                "expected {} background sizes (one per background vertex), got {}",
+                mesh.vertices.len(),
+                sizes.len()
+            )
+            .into());
+        }
+        if let Some((v, size)) = sizes.iter().enumerate().find(|&(_, &size)| size.is_nan() || size <= 0.0) {
+            return Err(format!("background size of vertex {} must be positive, got {}", v, size).into());
+        }
+        Ok(BackgroundSizes {
+            mesh,
+            sizes,
+            grid: TriangleGrid::new(mesh),
+        })
+    }
+
+    fn size_at(&self, p: Point2<f64>) -> f64 {
+        let Some(t) = self.grid.locate(self.mesh, p.x, p.y) else {
+            return f64::INFINITY;
+        };
+        let (i, j, k) = self.mesh.triangles[t];
+        let [a, b, c] = self.mesh.corners(t).map(|(x, y, _)| Point2::new(x, y));
+        let w = barycentric_weights(a, b, c, p);
+        w[0] * self.sizes[i] + w[1] * self.sizes[j] + w[2] * self.sizes[k]
+    }
+}
+
+/// Sizes following the local spacing of a point cloud: the distance from
+/// each point to its k-th nearest neighbor, scaled, and interpolated linearly
+/// between the points (from the nearest point outside their hull).
+pub(crate) struct DensitySizes {
+    dt: Delaunay,
+    /// Size at each vertex of `dt`
+    sizes: Vec<f64>,
+}
+
+impl DensitySizes {
+    pub fn new(points: &[(f64, f64)], k: usize, scale: f64) -> Result<Self, Box<dyn std::error::Error>> {
+        if k == 0 {
+            return Err("density_neighbors must be positive".into());
+        }
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(format!("density_scale must be positive, got {}", scale).into());
+        }
+        let (_, distances) = knn_graph_impl(points, k)?;
+        let (dt, handles) = delaunay(points)?;
+        // Coincident points share a vertex; the densest of them sets its size
+        let mut sizes = vec![f64::INFINITY; dt.num_vertices()];
+        for (i, handle) in handles.iter().enumerate() {
+            let spacing = distances[i][k - 1];
+            if spacing == 0.0 {
+                return Err(format!(
+                    "density point ({}, {}) has more than {} coincident points",
+                    points[i].0, points[i].1, k
+                )
+                .into());
+            }
+            sizes[handle.index()] = sizes[handle.index()].min(scale * spacing);
+        }
+        Ok(DensitySizes { dt, sizes })
+    }
+
+    fn size_at(&self, p: Point2<f64>) -> f64 {
+        match self.dt.locate(p) {
+            PositionInTriangulation::OnFace(face) => {
+                let face = self.dt.face(face);
+                let [a, b, c] = face.vertices();
+                let w = barycentric_weights(a.position(), b.position(), c.position(), p);
+                let size = |vertex: usize| self.sizes[vertex];
+                w[0] * size(a.fix().index()) + w[1] * size(b.fix().index()) + w[2] * size(c.fix().index())
+            }
+            _ => self
+                .dt
+                .nearest_neighbor(p)
+                .map_or(f64::INFINITY, |vertex| self.sizes[vertex.fix().index()]),
+        }
+    }
+}
+
+impl SizeField<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+            && self.lines.is_empty()
+            && self.boundary.is_none()
+            && self.background.is_none()
+            && self.density.is_none()
+    }
+
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        for &(x, y, size, radius) in &self.points {
+            if size.is_nan() || size <= 0.0 {
+                return Err(format!("point size at ({}, {}) must be positive, got {}", x, y, size).into());
+            }
+            if radius.is_nan() || radius < 0.0 {
+                return Err(format!("point size radius at ({}, {}) must be non-negative, got {}", x, y, radius).into());
+            }
+        }
+        for (k, (line, size, width)) in self.lines.iter().enumerate() {
+            if line.is_empty() {
+                return Err(format!("line size {} has no points", k).into());
+            }
+            if size.is_nan() || *size <= 0.0 {
+                return Err(format!("line size {} must be positive, got {}", k, size).into());
+            }
+            if width.is_nan() || *width < 0.0 {
+                return Err(format!("line size {} width must be non-negative, got {}", k, width).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Target edge length at `(x, y)`, infinite where nothing limits it.
+    pub fn size_at(&self, x: f64, y: f64) -> f64 {
+        let global = self.global.unwrap_or(f64::INFINITY);
+        let p = Point2::new(x, y);
+        let points = self
+            .points
+            .iter()
+            .map(|&(px, py, size, radius)| blend(size, global, (x - px).hypot(y - py), radius));
+        let lines = self.lines.iter().map(|(line, size, width)| {
+            let distance = match line.as_slice() {
+                [only] => distance_2(p, Point2::new(only.0, only.1)).sqrt(),
+                _ => line
+                    .windows(2)
+                    .map(|pair| {
+                        segment_distance_2(p, Point2::new(pair[0].0, pair[0].1), Point2::new(pair[1].0, pair[1].1))
+                    })
+                    .fold(f64::INFINITY, f64::min)
+                    .sqrt(),
+            };
+            blend(*size, global, distance, *width)
+        });
+        let boundary = self.boundary.iter().map(|boundary| boundary.size_at(p));
+        let background = self.background.iter().map(|background| background.size_at(p));
+        let density = self.density.iter().map(|density| density.size_at(p));
+        points
+            .chain(lines)
+            .chain(boundary)
+            .chain(background)
+            .chain(density)
+            .fold(global, f64::min)
+    }
+}
+
+/// `size` at distance 0 growing linearly into `global` at distance `radius`;
+/// with no global size, `size` up to `radius` and no limit beyond.
+fn blend(size: f64, global: f64, distance: f64, radius: f64) -> f64 {
+    if distance <= radius && global.is_infinite() {
+        size
+    } else if distance < radius {
+        size + (global - size) * distance / radius
+    } else {
+        global
+    }
+}
+
+/// Add the constraint `from`-`to` as a chain of pieces following the size
+/// field: the number of pieces is the integral of 1 / size along the
+/// segment, rounded up, and the split points divide that integral evenly.
+pub(crate) fn add_graded_constraint(
+    cdt: &mut Cdt,
+    from: FixedVertexHandle,
+    to: FixedVertexHandle,
+    field: &SizeField,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (p, q) = (cdt.vertex(from).position(), cdt.vertex(to).position());
+    let (from, to, p, q) = if (p.x, p.y) > (q.x, q.y) { (to, from, q, p) } else { (from, to, p, q) };
+    let length = distance_2(p, q).sqrt();
+    let at = |t: f64| Point2::new(p.x + t * (q.x - p.x), p.y + t * (q.y - p.y));
+    let density = |t: f64| {
+        let point = at(t);
+        1.0 / field.size_at(point.x, point.y)
+    };
+
+    // Sample finely enough to resolve the smallest size seen at a coarse pass
+    let coarse = (0..=64).map(|k| density(k as f64 / 64.0)).fold(0.0, f64::max);
+    let samples = ((4.0 * length * coarse).ceil() as usize).clamp(64, 100_000);
+    let mut integral = vec![0.0; samples + 1];
+    let mut previous = density(0.0);
+    for k in 1..=samples {
+        let next = density(k as f64 / samples as f64);
+        integral[k] = integral[k - 1] + 0.5 * (previous + next) * length / samples as f64;
+        previous = next;
+    }
+    let pieces = integral[samples].ceil().max(1.0) as usize;
+
+    let mut last = from;
+    let mut k = 0;
+    for piece in 1..pieces {
+        let target = piece as f64 * integral[samples] / pieces as f64;
+        while integral[k + 1] < target {
+            k += 1;
+        }
+        let fraction = (target - integral[k]) / (integral[k + 1] - integral[k]);
+        let next = cdt.insert(at((k as f64 + fraction) / samples as f64))?;
+        cdt.add_constraint(last, next);
+        last = next;
+    }
+    cdt.add_constraint(last, to);
+    Ok(())
+}
+
+/// Split unconstrained edges longer than the size field at their midpoint
+/// (where `inside` holds for a face on either side) until none remain.
+/// Returns false if this stopped at the vertex limit.
+pub(crate) fn refine_to_size(
+    cdt: &mut Cdt,
+    field: &SizeField,
+    inside: impl Fn(Point2<f64>) -> bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    loop {
+        let mut midpoints = Vec::new();
+        for edge in cdt.undirected_edges() {
+            if edge.is_constraint_edge() {
+                continue;
+            }
+            let [p, q] = edge.positions();
+            let mid = Point2::new(0.5 * (p.x + q.x), 0.5 * (p.y + q.y));
+            if edge.length_2() <= field.size_at(mid.x, mid.y).powi(2) {
+                continue;
+            }
+            let directed = edge.as_directed();
+            if [directed, directed.rev()].iter().any(|e| e.face().as_inner().is_some_and(|face| inside(face.center()))) {
+                midpoints.push(mid);
+            }
+        }
+        if midpoints.is_empty() {
+            return Ok(true);
+        }
+        if cdt.num_vertices() + midpoints.len() > MAX_SIZED_VERTICES {
+            return Ok(false);
+        }
+        for point in midpoints {
+            cdt.insert(point)?;
+        }
+    }
+}
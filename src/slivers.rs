@@ -0,0 +1,290 @@
+//! Removal of near-degenerate triangles from `triangulate()` output.
+//!
+//! A sliver is a triangle whose height over its longest edge is below the
+//! tolerance. It is removed by collapsing the vertex opposite that edge into
+//! one of the edge's end points. That vertex lies within the tolerance of the
+//! edge, so a boundary run a-b-c that is nearly straight becomes a-c and the
+//! domain changes by less than the tolerance.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::TriangulationResult;
+
+type Triangle = (usize, usize, usize);
+
+fn key(u: usize, v: usize) -> (usize, usize) {
+    (u.min(v), u.max(v))
+}
+
+fn contains((a, b, c): Triangle, v: usize) -> bool {
+    a == v || b == v || c == v
+}
+
+fn orientation(vertices: &[(f64, f64, f64)], (a, b, c): Triangle) -> f64 {
+    let (p, q, r) = (vertices[a], vertices[b], vertices[c]);
+    (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+}
+
+/// The longest edge (a, c) of the triangle and its height over it, with the
+/// opposite vertex b.
+fn sliver_shape(vertices: &[(f64, f64, f64)], (p, q, r): Triangle) -> ((usize, usize, usize), f64) {
+    let length = |u: usize, v: usize| (vertices[u].0 - vertices[v].0).hypot(vertices[u].1 - vertices[v].1);
+    let (a, b, c) = [(p, r, q), (q, p, r), (r, q, p)]
+        .into_iter()
+        .max_by(|&(a, _, c), &(x, _, z)| length(a, c).total_cmp(&length(x, z)))
+        .expect("three edges");
+    let longest = length(a, c);
+    let height = if longest > 0.0 { orientation(vertices, (p, q, r)).abs() / longest } else { 0.0 };
+    ((a, b, c), height)
+}
+
+struct Collapser<'a> {
+    vertices: &'a [(f64, f64, f64)],
+    triangles: Vec<Option<Triangle>>,
+    /// Triangles per vertex; may list triangles that no longer contain it
+    incident: Vec<Vec<usize>>,
+    /// Constrained and boundary edges, with the marker and ring of
+    /// constrained ones
+    fixed: HashMap<(usize, usize), Option<(i64, i64)>>,
+}
+
+impl Collapser<'_> {
+    fn around(&self, v: usize) -> Vec<usize> {
+        self.incident[v]
+            .iter()
+            .copied()
+            .filter(|&t| self.triangles[t].is_some_and(|triangle| contains(triangle, v)))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    fn neighbors(&self, v: usize) -> HashSet<usize> {
+        self.around(v)
+            .into_iter()
+            .flat_map(|t| {
+                let (a, b, c) = self.triangles[t].expect("live triangle");
+                [a, b, c]
+            })
+            .filter(|&x| x != v)
+            .collect()
+    }
+
+    /// Collapse `remove` into `keep` if that keeps the fixed edges (merging a
+    /// straight run into one edge), the mesh manifold and no triangle
+    /// inverted or flat.
+    fn collapse(&mut self, remove: usize, keep: usize) -> bool {
+        let fixed: Vec<((usize, usize), Option<(i64, i64)>)> = self
+            .fixed
+            .iter()
+            .filter(|&(&(u, v), _)| u == remove || v == remove)
+            .map(|(&edge, &marker)| (edge, marker))
+            .collect();
+        let merged = match fixed.as_slice() {
+            [] => None,
+            [(first, marker), (second, other)] if marker == other => {
+                let ends: Vec<usize> = [first, second].iter().map(|&&(u, v)| if u == remove { v } else { u }).collect();
+                if !ends.contains(&keep) {
+                    return false;
+                }
+                let other_end = if ends[0] == keep { ends[1] } else { ends[0] };
+                Some((key(keep, other_end), *marker))
+            }
+            _ => return false,
+        };
+
+        let around = self.around(remove);
+        let on_edge: Vec<usize> = around
+            .iter()
+            .copied()
+            .filter(|&t| contains(self.triangles[t].expect("live triangle"), keep))
+            .collect();
+        if on_edge.is_empty() {
+            return false;
+        }
+        let opposite: HashSet<usize> = on_edge
+            .iter()
+            .map(|&t| {
+                let (a, b, c) = self.triangles[t].expect("live triangle");
+                a + b + c - remove - keep
+            })
+            .collect();
+        let shared: HashSet<usize> = self.neighbors(remove).intersection(&self.neighbors(keep)).copied().collect();
+        if shared != opposite {
+            return false;
+        }
+
+        let mut moved = Vec::new();
+        for &t in &around {
+            if on_edge.contains(&t) {
+                continue;
+            }
+            let triangle = self.triangles[t].expect("live triangle");
+            let (a, b, c) = triangle;
+            let swap = |x: usize| if x == remove { keep } else { x };
+            let target = (swap(a), swap(b), swap(c));
+            if orientation(self.vertices, target) * orientation(self.vertices, triangle) <= 0.0 {
+                return false;
+            }
+            moved.push((t, target));
+        }
+
+        for t in on_edge {
+            self.triangles[t] = None;
+        }
+        for (t, target) in moved {
+            self.triangles[t] = Some(target);
+            self.incident[keep].push(t);
+        }
+        self.incident[remove].clear();
+        self.fixed.retain(|&(u, v), _| u != remove && v != remove);
+        if let Some((edge, marker)) = merged {
+            self.fixed.insert(edge, marker);
+        }
+        true
+    }
+}
+
+/// Collapse sliver triangles of `result` thinner than `tolerance`, returning
+/// the number removed. Hole and hull triangles, if returned, are updated
+/// along and count for the validity checks, but are not searched for slivers.
+pub(crate) fn remove_slivers(result: &mut TriangulationResult, tolerance: f64) -> Result<usize, Box<dyn std::error::Error>> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err(format!("sliver_tolerance must be positive, got {}", tolerance).into());
+    }
+
+    let num_triangles = result.triangles.len();
+    let all: Vec<Triangle> = result
+        .triangles
+        .iter()
+        .copied()
+        .chain(result.hole_triangles.iter().flatten().map(|&(triangle, _)| triangle))
+        .chain(result.hull_triangles.iter().flatten().copied())
+        .collect();
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut incident = vec![Vec::new(); result.vertices.len()];
+    for (t, &(a, b, c)) in all.iter().enumerate() {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry(key(u, v)).or_default() += 1;
+        }
+        for v in [a, b, c] {
+            incident[v].push(t);
+        }
+    }
+    let mut fixed: HashMap<(usize, usize), Option<(i64, i64)>> =
+        uses.into_iter().filter(|&(_, count)| count == 1).map(|(edge, _)| (edge, None)).collect();
+    for (i, &(u, v)) in result.edges.iter().enumerate() {
+        let marker = result.edge_markers.as_ref().map_or(0, |markers| markers[i]);
+        let ring = result.edge_rings.as_ref().map_or(-1, |rings| rings[i]);
+        fixed.insert(key(u, v), Some((marker, ring)));
+    }
+
+    let mut collapser = Collapser {
+        vertices: &result.vertices,
+        triangles: all.into_iter().map(Some).collect(),
+        incident,
+        fixed,
+    };
+    let mut moved_to: Vec<usize> = (0..result.vertices.len()).collect();
+    let mut removed = 0;
+    // Every collapse removes a vertex, so this terminates
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for t in 0..num_triangles {
+            let Some(triangle) = collapser.triangles[t] else { continue };
+            let ((a, b, c), height) = sliver_shape(collapser.vertices, triangle);
+            if height >= tolerance {
+                continue;
+            }
+            let distance = |u: usize| {
+                let (p, q) = (collapser.vertices[u], collapser.vertices[b]);
+                (p.0 - q.0).hypot(p.1 - q.1)
+            };
+            let (near, far) = if distance(a) <= distance(c) { (a, c) } else { (c, a) };
+            for keep in [near, far] {
+                if collapser.collapse(b, keep) {
+                    moved_to[b] = keep;
+                    removed += 1;
+                    changed = true;
+                    break;
+                }
+            }
+        }
+    }
+    if removed == 0 {
+        return Ok(0);
+    }
+
+    // Drop the collapsed vertices, keeping the order of the others
+    let target = |mut v: usize| {
+        while moved_to[v] != v {
+            v = moved_to[v];
+        }
+        v
+    };
+    let mut index = vec![usize::MAX; result.vertices.len()];
+    let mut next = 0;
+    for (v, slot) in index.iter_mut().enumerate() {
+        if moved_to[v] == v {
+            *slot = next;
+            next += 1;
+        }
+    }
+    let kept = |v: usize| moved_to[v] == v;
+    result.input_vertices = (0..result.input_vertices).filter(|&v| kept(v)).count();
+    let (triangles, rest) = collapser.triangles.split_at(num_triangles);
+    let (hole_triangles, hull_triangles) = rest.split_at(result.hole_triangles.as_ref().map_or(0, Vec::len));
+    let remap = |&(a, b, c): &Triangle| (index[a], index[b], index[c]);
+    result.triangles = triangles.iter().flatten().map(remap).collect();
+    if let Some(holes) = &mut result.hole_triangles {
+        *holes = hole_triangles
+            .iter()
+            .zip(holes.iter())
+            .filter_map(|(triangle, &(_, hole))| triangle.as_ref().map(|triangle| (remap(triangle), hole)))
+            .collect();
+    }
+    if let Some(hull) = &mut result.hull_triangles {
+        *hull = hull_triangles.iter().flatten().map(remap).collect();
+    }
+
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    let mut markers = Vec::new();
+    let mut rings = Vec::new();
+    for (i, &(u, v)) in result.edges.iter().enumerate() {
+        let (u, v) = (target(u), target(v));
+        if u != v && seen.insert(key(u, v)) {
+            edges.push((index[u], index[v]));
+            if let Some(edge_markers) = &result.edge_markers {
+                markers.push(edge_markers[i]);
+            }
+            if let Some(edge_rings) = &result.edge_rings {
+                rings.push(edge_rings[i]);
+            }
+        }
+    }
+    result.edges = edges;
+    if result.edge_markers.is_some() {
+        result.edge_markers = Some(markers);
+    }
+    if result.edge_rings.is_some() {
+        result.edge_rings = Some(rings);
+    }
+
+    let mut v = 0;
+    result.vertices.retain(|_| {
+        v += 1;
+        kept(v - 1)
+    });
+    if let Some(attributes) = &mut result.vertex_attributes {
+        for values in attributes.values_mut() {
+            let mut v = 0;
+            values.retain(|_| {
+                v += 1;
+                kept(v - 1)
+            });
+        }
+    }
+    Ok(removed)
+}
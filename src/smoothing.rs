@@ -0,0 +1,310 @@
+//! Vertex smoothing of existing meshes with pinned boundary, constraint and
+//! user-frozen vertices.
+
+use pyo3::prelude::*;
+use spade::Point2;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::statistics;
+use crate::terrain::barycentric_weights;
+
+/// Vertices that must not move, given as a boolean mask over the vertices or
+/// as a list of vertex indices.
+pub(crate) enum Frozen {
+    Mask(Vec<bool>),
+    Indices(Vec<usize>),
+}
+
+impl<'py> FromPyObject<'py> for Frozen {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // Python booleans are integers too, so the mask is tried first
+        if let Ok(mask) = ob.extract() {
+            return Ok(Frozen::Mask(mask));
+        }
+        Ok(Frozen::Indices(ob.extract()?))
+    }
+}
+
+impl Frozen {
+    /// The frozen vertices as a mask over `num_vertices` vertices.
+    pub fn mask(&self, num_vertices: usize) -> Result<Vec<bool>, Box<dyn std::error::Error>> {
+        match self {
+            Frozen::Mask(mask) if mask.len() == num_vertices => Ok(mask.clone()),
+            Frozen::Mask(mask) => {
+                Err(format!("frozen mask has {} entries for {} vertices", mask.len(), num_vertices).into())
+            }
+            Frozen::Indices(indices) => {
+                let mut mask = vec![false; num_vertices];
+                for &v in indices {
+                    if v >= num_vertices {
+                        return Err(format!("frozen vertex {} out of range ({} vertices)", v, num_vertices).into());
+                    }
+                    mask[v] = true;
+                }
+                Ok(mask)
+            }
+        }
+    }
+}
+
+/// Smooth a mesh by moving every free vertex to the average of its neighbors,
+/// or by optimal Delaunay triangulation (ODT) updates.
+///
+/// The "odt" method moves a vertex to the area-weighted average of the
+/// circumcenters of its triangles, which tends to give better-conditioned
+/// triangles for PDE solvers than plain Laplacian averaging. Boundary
+/// vertices and the end points of the mesh's constrained 'edges' are pinned,
+/// as are the `frozen` vertices (surveyed control points, sensor nodes). The
+/// connectivity is kept: a move that would invert or flatten an incident
+/// triangle is halved once and otherwise skipped. z values are interpolated
+/// from the input mesh at the new positions.
+///
+/// Sweeps stop early once the smallest angle reaches `min_angle` or no vertex
+/// moves more than `tolerance` in a sweep, so `iterations` is an upper bound
+/// when either target is given.
+///
+/// Args:
+///     mesh (dict): Mesh with 'vertices', 'triangles' and optionally 'edges', e.g. from
+///         `triangulate()`
+///     iterations (int, optional): Number of smoothing sweeps, or the most to run when a
+///         target is given. Default: 10
+///     frozen (list, optional): Vertices that must not move, as a list of vertex indices or
+///         a boolean mask with one entry per vertex. Default: None
+///     min_angle (float, optional): Stop once no triangle angle is below this many
+///         degrees. Default: None
+///     tolerance (float, optional): Stop once a sweep moves no vertex farther than this.
+///         Default: None
+///     method (str, optional): "laplacian" (average of the neighbors) or "odt"
+///         (area-weighted average of the incident circumcenters). Default: "laplacian"
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates, in input order
+///         - 'triangles': The input triangles
+///         - 'edges': The input constrained edges (if any)
+///         - 'iterations': Number of sweeps run
+///         - 'min_angle': Smallest triangle angle in degrees after smoothing
+///         - 'max_displacement': Largest distance a vertex moved in the last sweep (0.0 if
+///           none ran)
+///         - 'converged': True if a given target was met (always False without targets)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> mesh = pyspade.triangulate(outer, max_edge_length=1.0)
+///     >>> smoothed = pyspade.smooth(mesh, iterations=100, min_angle=30.0, tolerance=1e-3, method="odt")
+///     >>> print(smoothed['iterations'], smoothed['min_angle'], smoothed['converged'])
+#[pyfunction]
+#[pyo3(signature = (mesh, iterations=10, frozen=None, min_angle=None, tolerance=None, method="laplacian"))]
+pub fn smooth(
+    mesh: Mesh,
+    iterations: usize,
+    frozen: Option<Frozen>,
+    min_angle: Option<f64>,
+    tolerance: Option<f64>,
+    method: &str,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let method = match method {
+            "laplacian" => Method::Laplacian,
+            "odt" => Method::Odt,
+            _ => return Err(crate::to_py_err(format!("unknown method '{}', expected 'laplacian' or 'odt'", method).into())),
+        };
+        let frozen = match frozen {
+            Some(frozen) => frozen.mask(mesh.vertices.len()).map_err(crate::to_py_err)?,
+            None => vec![false; mesh.vertices.len()],
+        };
+        let targets = Targets { min_angle, tolerance };
+        let result = smooth_impl(&mesh, iterations, &frozen, &targets, method).map_err(crate::to_py_err)?;
+
+        let mut output = Mesh {
+            vertices: result.vertices,
+            ..mesh
+        }
+        .into_dict(py);
+        output.insert("iterations".to_string(), result.iterations.into_py(py));
+        output.insert("min_angle".to_string(), result.min_angle.into_py(py));
+        output.insert("max_displacement".to_string(), result.max_displacement.into_py(py));
+        output.insert("converged".to_string(), result.converged.into_py(py));
+
+        Ok(output)
+    })
+}
+
+/// Where a vertex moves in one smoothing step.
+#[derive(Clone, Copy)]
+pub(crate) enum Method {
+    /// Average of the neighbors
+    Laplacian,
+    /// Area-weighted average of the incident triangles' circumcenters
+    Odt,
+}
+
+/// Quality targets ending smoothing before the sweep limit.
+pub(crate) struct Targets {
+    pub min_angle: Option<f64>,
+    pub tolerance: Option<f64>,
+}
+
+pub(crate) struct Smoothed {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub iterations: usize,
+    pub min_angle: f64,
+    pub max_displacement: f64,
+    pub converged: bool,
+}
+
+fn orientation(p: (f64, f64, f64), q: (f64, f64, f64), r: (f64, f64, f64)) -> f64 {
+    (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+}
+
+/// Circumcenter of a triangle in the xy plane and its area, or None if it is
+/// flat.
+fn circumcenter(p: (f64, f64, f64), q: (f64, f64, f64), r: (f64, f64, f64)) -> Option<((f64, f64), f64)> {
+    let (bx, by, cx, cy) = (q.0 - p.0, q.1 - p.1, r.0 - p.0, r.1 - p.1);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d == 0.0 {
+        return None;
+    }
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let center = (p.0 + (cy * b2 - by * c2) / d, p.1 + (bx * c2 - cx * b2) / d);
+    Some((center, d.abs() / 4.0))
+}
+
+/// Smallest planar angle of the triangles in degrees (180 for none).
+fn smallest_angle(vertices: &[(f64, f64, f64)], triangles: &[(usize, usize, usize)]) -> f64 {
+    let distance = |u: usize, v: usize| (vertices[u].0 - vertices[v].0).hypot(vertices[u].1 - vertices[v].1);
+    triangles
+        .iter()
+        .flat_map(|&(a, b, c)| statistics::angles([distance(b, c), distance(c, a), distance(a, b)]))
+        .fold(180.0, f64::min)
+}
+
+/// Vertices that smoothing may move: used by a triangle, not on the
+/// boundary, not on a constrained edge and not frozen.
+pub(crate) fn free_vertices(mesh: &Mesh, frozen: &[bool]) -> Vec<bool> {
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut free = vec![false; mesh.vertices.len()];
+    for &(a, b, c) in &mesh.triangles {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((u.min(v), u.max(v))).or_default() += 1;
+        }
+        for v in [a, b, c] {
+            free[v] = !frozen[v];
+        }
+    }
+    let pinned = uses.into_iter().filter(|&(_, count)| count == 1).map(|(edge, _)| edge).chain(mesh.edges.iter().copied());
+    for (u, v) in pinned {
+        free[u] = false;
+        free[v] = false;
+    }
+    free
+}
+
+pub(crate) fn smooth_impl(
+    mesh: &Mesh,
+    iterations: usize,
+    frozen: &[bool],
+    targets: &Targets,
+    method: Method,
+) -> Result<Smoothed, Box<dyn std::error::Error>> {
+    if let Some(angle) = targets.min_angle {
+        if angle.is_nan() || angle <= 0.0 || angle > 60.0 {
+            return Err(format!("min_angle must be in (0, 60], got {}", angle).into());
+        }
+    }
+    if let Some(tolerance) = targets.tolerance {
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(format!("tolerance must be non-negative, got {}", tolerance).into());
+        }
+    }
+    let free = free_vertices(mesh, frozen);
+    let mut incident: Vec<Vec<usize>> = vec![Vec::new(); mesh.vertices.len()];
+    // Ordered, so that the neighbor averages sum in the same order on every run
+    let mut neighbors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); mesh.vertices.len()];
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        for (u, v, w) in [(a, b, c), (b, c, a), (c, a, b)] {
+            incident[u].push(t);
+            neighbors[u].extend([v, w]);
+        }
+    }
+    let signs: Vec<f64> = mesh
+        .triangles
+        .iter()
+        .map(|&(a, b, c)| orientation(mesh.vertices[a], mesh.vertices[b], mesh.vertices[c]).signum())
+        .collect();
+
+    let mut vertices = mesh.vertices.clone();
+    let mut min_angle = smallest_angle(&vertices, &mesh.triangles);
+    let mut max_displacement = 0.0;
+    let mut converged = targets.min_angle.is_some_and(|target| min_angle >= target);
+    let mut sweeps = 0;
+    while sweeps < iterations && !converged {
+        sweeps += 1;
+        max_displacement = 0.0;
+        for v in (0..vertices.len()).filter(|&v| free[v]) {
+            let (x, y, z) = vertices[v];
+            let target = match method {
+                Method::Laplacian => {
+                    let n = neighbors[v].len() as f64;
+                    let (sx, sy) =
+                        neighbors[v].iter().fold((0.0, 0.0), |(sx, sy), &w| (sx + vertices[w].0, sy + vertices[w].1));
+                    (sx / n, sy / n)
+                }
+                Method::Odt => {
+                    let (mut sx, mut sy, mut area) = (0.0, 0.0, 0.0);
+                    for &t in &incident[v] {
+                        let (a, b, c) = mesh.triangles[t];
+                        if let Some(((cx, cy), weight)) = circumcenter(vertices[a], vertices[b], vertices[c]) {
+                            sx += weight * cx;
+                            sy += weight * cy;
+                            area += weight;
+                        }
+                    }
+                    if area == 0.0 {
+                        continue;
+                    }
+                    (sx / area, sy / area)
+                }
+            };
+            // Gauss-Seidel: moved vertices count at once for their neighbors
+            for step in [1.0, 0.5] {
+                let p = (x + step * (target.0 - x), y + step * (target.1 - y), z);
+                let valid = incident[v].iter().all(|&t| {
+                    let (a, b, c) = mesh.triangles[t];
+                    let at = |u: usize| if u == v { p } else { vertices[u] };
+                    orientation(at(a), at(b), at(c)) * signs[t] > 0.0
+                });
+                if valid {
+                    max_displacement = max_displacement.max((p.0 - x).hypot(p.1 - y));
+                    vertices[v] = p;
+                    break;
+                }
+            }
+        }
+        min_angle = smallest_angle(&vertices, &mesh.triangles);
+        converged = targets.min_angle.is_some_and(|target| min_angle >= target)
+            || targets.tolerance.is_some_and(|tolerance| max_displacement <= tolerance);
+    }
+
+    // Heights come from the input surface at the new positions
+    let grid = TriangleGrid::new(mesh);
+    for (v, vertex) in vertices.iter_mut().enumerate() {
+        if !free[v] {
+            continue;
+        }
+        if let Some(t) = grid.locate(mesh, vertex.0, vertex.1) {
+            let corners = mesh.corners(t);
+            let [a, b, c] = corners.map(|(x, y, _)| Point2::new(x, y));
+            let w = barycentric_weights(a, b, c, Point2::new(vertex.0, vertex.1));
+            vertex.2 = w[0] * corners[0].2 + w[1] * corners[1].2 + w[2] * corners[2].2;
+        }
+    }
+    Ok(Smoothed {
+        vertices,
+        iterations: sweeps,
+        min_angle,
+        max_displacement,
+        converged,
+    })
+}
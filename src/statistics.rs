@@ -0,0 +1,194 @@
+//! Summary statistics for meshes from any source.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::mesh::{vertex_row, Mesh};
+
+/// Compute size, topology and quality statistics of a triangle mesh.
+///
+/// Areas and lengths are measured in 3D, so they are surface measures for
+/// terrain meshes and plain planar ones when z is 0.
+///
+/// Args:
+///     vertices (list): Vertex coordinates as (x, y) or (x, y, z) tuples
+///     triangles (list): Triangles as (i, j, k) vertex indices
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'num_vertices': Number of vertices used by triangles
+///         - 'num_edges': Number of distinct edges
+///         - 'num_triangles': Number of triangles
+///         - 'area': Total triangle area
+///         - 'boundary_length': Total length of edges used by one triangle
+///         - 'boundary_loops': Number of closed boundary loops
+///         - 'euler_characteristic': V - E + F over the used vertices
+///         - 'components': Number of components connected through shared vertices
+///         - 'min_angle', 'max_angle': Extreme triangle angles in degrees
+///         - 'edge_length': (min, mean, max) edge length
+///         - 'quality': (min, mean) radius ratio 2r/R, which is 1 for equilateral triangles
+///
+/// Example:
+///     >>> import pyspade
+///     >>> stats = pyspade.mesh_statistics(mesh['vertices'], mesh['triangles'])
+///     >>> print(f"{stats['num_triangles']} triangles, min angle {stats['min_angle']:.1f}°")
+#[pyfunction]
+pub fn mesh_statistics(vertices: Vec<Vec<f64>>, triangles: Vec<(usize, usize, usize)>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let vertices = vertices.iter().map(|row| vertex_row(row)).collect::<PyResult<Vec<_>>>()?;
+        if triangles.iter().any(|&(a, b, c)| a.max(b).max(c) >= vertices.len()) {
+            return Err(PyValueError::new_err("mesh triangle references a missing vertex"));
+        }
+        let mesh = Mesh {
+            vertices,
+            triangles,
+            edges: Vec::new(),
+        };
+        let stats = mesh_statistics_impl(&mesh).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("num_vertices".to_string(), stats.num_vertices.into_py(py));
+        output.insert("num_edges".to_string(), stats.num_edges.into_py(py));
+        output.insert("num_triangles".to_string(), stats.num_triangles.into_py(py));
+        output.insert("area".to_string(), stats.area.into_py(py));
+        output.insert("boundary_length".to_string(), stats.boundary_length.into_py(py));
+        output.insert("boundary_loops".to_string(), stats.boundary_loops.into_py(py));
+        output.insert("euler_characteristic".to_string(), stats.euler_characteristic.into_py(py));
+        output.insert("components".to_string(), stats.components.into_py(py));
+        output.insert("min_angle".to_string(), stats.min_angle.into_py(py));
+        output.insert("max_angle".to_string(), stats.max_angle.into_py(py));
+        output.insert("edge_length".to_string(), stats.edge_length.into_py(py));
+        output.insert("quality".to_string(), stats.quality.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct MeshStatistics {
+    pub num_vertices: usize,
+    pub num_edges: usize,
+    pub num_triangles: usize,
+    pub area: f64,
+    pub boundary_length: f64,
+    pub boundary_loops: usize,
+    pub euler_characteristic: i64,
+    pub components: usize,
+    pub min_angle: f64,
+    pub max_angle: f64,
+    pub edge_length: (f64, f64, f64),
+    pub quality: (f64, f64),
+}
+
+fn distance(p: (f64, f64, f64), q: (f64, f64, f64)) -> f64 {
+    (q.0 - p.0).hypot(q.1 - p.1).hypot(q.2 - p.2)
+}
+
+/// Root of vertex `v` in a union-find forest, compressing the path.
+fn find(parent: &mut [usize], mut v: usize) -> usize {
+    while parent[v] != v {
+        parent[v] = parent[parent[v]];
+        v = parent[v];
+    }
+    v
+}
+
+/// Angles in degrees opposite the sides of lengths `[la, lb, lc]`, from the
+/// law of cosines.
+pub(crate) fn angles([la, lb, lc]: [f64; 3]) -> [f64; 3] {
+    [(la, lb, lc), (lb, lc, la), (lc, la, lb)].map(|(opposite, x, y)| {
+        let cos = ((x * x + y * y - opposite * opposite) / (2.0 * x * y)).clamp(-1.0, 1.0);
+        if x * y > 0.0 {
+            cos.acos().to_degrees()
+        } else {
+            0.0
+        }
+    })
+}
+
+pub(crate) fn mesh_statistics_impl(mesh: &Mesh) -> Result<MeshStatistics, Box<dyn std::error::Error>> {
+    if mesh.triangles.is_empty() {
+        return Err("mesh has no triangles".into());
+    }
+
+    let mut area = 0.0;
+    let (mut min_angle, mut max_angle) = (f64::INFINITY, 0.0f64);
+    let (mut min_quality, mut quality_sum) = (f64::INFINITY, 0.0);
+    // Ordered, so that the length sums below add up in the same order on
+    // every run
+    let mut uses: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    let mut parent: Vec<usize> = (0..mesh.vertices.len()).collect();
+    let mut used = vec![false; mesh.vertices.len()];
+    for (t, &(a, b, c)) in mesh.triangles.iter().enumerate() {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((u.min(v), u.max(v))).or_default() += 1;
+            let (ru, rv) = (find(&mut parent, u), find(&mut parent, v));
+            parent[ru] = rv;
+        }
+        for v in [a, b, c] {
+            used[v] = true;
+        }
+
+        let [p, q, r] = mesh.corners(t);
+        let [la, lb, lc] = [distance(q, r), distance(r, p), distance(p, q)];
+        let s = 0.5 * (la + lb + lc);
+        let triangle_area = (s * (s - la) * (s - lb) * (s - lc)).max(0.0).sqrt();
+        area += triangle_area;
+
+        for angle in angles([la, lb, lc]) {
+            min_angle = min_angle.min(angle);
+            max_angle = max_angle.max(angle);
+        }
+
+        // 2r/R with inradius r = A/s and circumradius R = la lb lc / 4A
+        let product = la * lb * lc;
+        let quality = if product > 0.0 { 8.0 * triangle_area * triangle_area / (s * product) } else { 0.0 };
+        min_quality = min_quality.min(quality);
+        quality_sum += quality;
+    }
+
+    let lengths: Vec<f64> = uses.keys().map(|&(u, v)| distance(mesh.vertices[u], mesh.vertices[v])).collect();
+    let min_length = lengths.iter().copied().fold(f64::INFINITY, f64::min);
+    let max_length = lengths.iter().copied().fold(0.0, f64::max);
+    let mean_length = lengths.iter().sum::<f64>() / lengths.len() as f64;
+
+    let boundary: Vec<(usize, usize)> =
+        uses.iter().filter(|&(_, &count)| count == 1).map(|(&edge, _)| edge).collect();
+    let boundary_length = boundary.iter().map(|&(u, v)| distance(mesh.vertices[u], mesh.vertices[v])).sum();
+
+    // Boundary loops are the independent cycles of the boundary edge graph:
+    // E - V + C
+    let mut index: HashMap<usize, usize> = HashMap::new();
+    for &(u, v) in &boundary {
+        for w in [u, v] {
+            let next = index.len();
+            index.entry(w).or_insert(next);
+        }
+    }
+    let mut loop_parent: Vec<usize> = (0..index.len()).collect();
+    for &(u, v) in &boundary {
+        let (ru, rv) = (find(&mut loop_parent, index[&u]), find(&mut loop_parent, index[&v]));
+        loop_parent[ru] = rv;
+    }
+    let boundary_components = (0..index.len()).filter(|&v| find(&mut loop_parent, v) == v).count();
+    let boundary_loops = boundary.len() + boundary_components - index.len();
+
+    let num_vertices = used.iter().filter(|&&u| u).count();
+    let components = (0..mesh.vertices.len()).filter(|&v| used[v] && find(&mut parent, v) == v).count();
+    let num_triangles = mesh.triangles.len();
+
+    Ok(MeshStatistics {
+        num_vertices,
+        num_edges: uses.len(),
+        num_triangles,
+        area,
+        boundary_length,
+        boundary_loops,
+        euler_characteristic: num_vertices as i64 - uses.len() as i64 + num_triangles as i64,
+        components,
+        min_angle,
+        max_angle,
+        edge_length: (min_length, mean_length, max_length),
+        quality: (min_quality, quality_sum / num_triangles as f64),
+    })
+}
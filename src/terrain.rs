@@ -0,0 +1,930 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
+use spade::{FixedVertexHandle, Point2, PositionInTriangulation, Triangulation};
+use std::collections::HashMap;
+
+use crate::arrays::PointArrayXYZ;
+use crate::attributes::{self, Attributes};
+use crate::logging;
+use crate::mesh::{Mesh, TriangleGrid};
+use crate::regions::{add_constraint_checked, constraint_edges, insert_ring, label_faces, output_vertices, Cdt};
+use crate::rendering::vertex_normals_impl;
+use crate::sizing;
+
+/// Mesh a terrain domain with building footprints constrained into it.
+///
+/// All footprints are inserted into one triangulation of the domain, so the
+/// mesh conforms to every footprint edge. Footprint interiors are meshed
+/// too and labeled, which lets callers drop, extrude or flatten them.
+///
+/// Args:
+///     domain (list): Terrain boundary as list of (x, y) tuples
+///     footprints (list): Building footprint polygons, each as list of (x, y) tuples
+///     max_edge_length (float, optional): Target maximum edge length for mesh refinement
+///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
+///     footprint_max_edge_length (float, optional): Finer target edge length inside footprints
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates (z=0.0)
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edge indices
+///         - 'labels': Per triangle, -1 for ground or the index of the footprint containing it
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.mesh_terrain(
+///     ...     domain=[(0, 0), (100, 0), (100, 100), (0, 100)],
+///     ...     footprints=[[(20, 20), (40, 20), (40, 40), (20, 40)]],
+///     ...     max_edge_length=10.0
+///     ... )
+///     >>> ground = [t for t, l in zip(result['triangles'], result['labels']) if l == -1]
+#[pyfunction]
+#[pyo3(signature = (domain, footprints, max_edge_length=None, min_angle=None, footprint_max_edge_length=None))]
+pub fn mesh_terrain(
+    domain: Vec<(f64, f64)>,
+    footprints: Vec<Vec<(f64, f64)>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+    footprint_max_edge_length: Option<f64>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = mesh_terrain_impl(domain, footprints, max_edge_length, min_angle, footprint_max_edge_length)
+            .map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+        output.insert("labels".to_string(), result.labels.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct TerrainMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+    pub labels: Vec<i64>,
+}
+
+pub(crate) fn mesh_terrain_impl(
+    domain: Vec<(f64, f64)>,
+    footprints: Vec<Vec<(f64, f64)>>,
+    max_edge_length: Option<f64>,
+    min_angle: Option<f64>,
+    footprint_max_edge_length: Option<f64>,
+) -> Result<TerrainMesh, Box<dyn std::error::Error>> {
+    if domain.len() < 3 {
+        return Err("domain must have at least 3 vertices".into());
+    }
+    let origin = domain[0];
+
+    // Polygon 0 is the domain, polygon k + 1 is footprint k
+    let mut polygons = Vec::with_capacity(footprints.len() + 1);
+    polygons.push(domain);
+    polygons.extend(footprints);
+
+    let mut cdt = Cdt::default();
+    for polygon in &polygons {
+        insert_ring(&mut cdt, polygon)?;
+    }
+
+    if let Some(h) = footprint_max_edge_length {
+        if h.is_nan() || h <= 0.0 {
+            return Err(format!("footprint_max_edge_length must be positive, got {}", h).into());
+        }
+        let labels = label_faces(&cdt, &polygons);
+        let footprint_faces: Vec<_> = cdt
+            .inner_faces()
+            .map(|face| face.fix())
+            .filter(|face| matches!(labels[face], Some(polygon) if polygon > 0))
+            .collect();
+        for point in sizing::lattice_points(&cdt, &footprint_faces, origin, h) {
+            cdt.insert(point)?;
+        }
+    }
+
+    if max_edge_length.is_some() || min_angle.is_some() || footprint_max_edge_length.is_some() {
+        let before = cdt.num_vertices();
+        let result = cdt.refine(crate::refinement_parameters(max_edge_length, min_angle));
+        logging::refinement(before, cdt.num_vertices(), result.refinement_complete);
+    }
+
+    let labels = label_faces(&cdt, &polygons);
+    let mut triangles = Vec::new();
+    let mut triangle_labels = Vec::new();
+    for face in cdt.inner_faces() {
+        if let Some(polygon) = labels[&face.fix()] {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            triangles.push((a, b, c));
+            triangle_labels.push(polygon as i64 - 1);
+        }
+    }
+
+    Ok(TerrainMesh {
+        vertices: output_vertices(&cdt),
+        triangles,
+        edges: constraint_edges(&cdt),
+        labels: triangle_labels,
+    })
+}
+
+/// Build a ground TIN from scattered (x, y, z) points.
+///
+/// Args:
+///     points_xyz (list): Ground points as an (n, 3) NumPy array or list of (x, y, z)
+///         tuples, or any other iterable (e.g. a list of arrays, a generator or a laspy
+///         chunk reader) yielding such arrays, which is consumed chunk by chunk without
+///         concatenating the chunks
+///     boundary (list, optional): Clipping polygon as list of (x, y) tuples. Its vertices
+///         are inserted with z interpolated from the points and only triangles inside it are kept
+///     max_edge_length (float, optional): Drop triangles with any edge longer than this,
+///         which removes the long, flat triangles that bridge data gaps and concave hulls
+///     vertex_attributes (dict, optional): Named per-point value arrays (e.g. intensity,
+///         classification), one value per input point. Default: None
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates
+///         - 'normals': Area-weighted unit vertex normals
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) boundary edge indices (empty without a boundary)
+///         - 'vertex_attributes': The vertex_attributes arrays aligned with 'vertices', interpolated
+///           at boundary vertices like z (only if vertex_attributes is given)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.tin(
+///     ...     [(0, 0, 1.0), (10, 0, 2.0), (10, 10, 3.0), (0, 10, 2.5), (5, 5, 4.0)],
+///     ...     boundary=[(1, 1), (9, 1), (9, 9), (1, 9)]
+///     ... )
+///     >>> print(result['vertices'][:3])
+#[pyfunction]
+#[pyo3(signature = (points_xyz, boundary=None, max_edge_length=None, vertex_attributes=None))]
+pub fn tin(
+    points_xyz: &Bound<'_, PyAny>,
+    boundary: Option<Vec<(f64, f64)>>,
+    max_edge_length: Option<f64>,
+    vertex_attributes: Option<Attributes>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let attributes = vertex_attributes.as_ref();
+        let chunks: Box<dyn Iterator<Item = PyResult<PointArrayXYZ>> + '_> = if is_point_array(points_xyz)? {
+            Box::new(std::iter::once(points_xyz.extract()))
+        } else {
+            Box::new(points_xyz.iter()?.map(|chunk| chunk?.extract()))
+        };
+        let chunks = chunks.map(|chunk| chunk.map(|points| points.0).map_err(Into::into));
+        let result =
+            tin_impl(chunks, boundary.as_deref(), max_edge_length, attributes).map_err(crate::to_py_err)?;
+        let normals = vertex_normals_impl(&result.vertices, &result.triangles);
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("normals".to_string(), normals.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("edges".to_string(), result.edges.into_py(py));
+        if vertex_attributes.is_some() {
+            output.insert("vertex_attributes".to_string(), result.attributes.into_py(py));
+        }
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct Tin {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+    pub attributes: Attributes,
+}
+
+/// Whether `points` is one point array (an array-like with a shape, or a list
+/// or tuple of points) rather than an iterable of chunks, such as a list of
+/// arrays.
+fn is_point_array(points: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if points.hasattr("shape")? {
+        return Ok(true);
+    }
+    if !points.is_instance_of::<PyList>() && !points.is_instance_of::<PyTuple>() {
+        return Ok(false);
+    }
+    // An empty list is an (invalid) point array; otherwise look at whether the
+    // first item is a point, i.e. a sequence of coordinates
+    match points.get_item(0) {
+        Ok(first) => is_point(&first),
+        Err(_) => Ok(true),
+    }
+}
+
+/// Whether `item` is a single point: a 1-D array or a sequence whose first
+/// element is a number.
+fn is_point(item: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if item.hasattr("ndim")? {
+        return Ok(item.getattr("ndim")?.extract::<usize>()? == 1);
+    }
+    Ok(item.get_item(0).is_ok_and(|coordinate| coordinate.extract::<f64>().is_ok()))
+}
+
+pub(crate) fn tin_impl(
+    chunks: impl Iterator<Item = Result<Vec<(f64, f64, f64)>, Box<dyn std::error::Error>>>,
+    boundary: Option<&[(f64, f64)]>,
+    max_edge_length: Option<f64>,
+    attributes: Option<&Attributes>,
+) -> Result<Tin, Box<dyn std::error::Error>> {
+    let mut cdt = Cdt::default();
+    let mut z = Vec::new();
+    let mut handles = Vec::new();
+    for chunk in chunks {
+        for (x, y, height) in chunk? {
+            let handle = cdt.insert(Point2::new(x, y))?;
+            // Duplicate (x, y) positions keep the first height
+            if handle.index() == z.len() {
+                z.push(height);
+            }
+            handles.push(handle);
+        }
+    }
+    if handles.len() < 3 {
+        return Err("at least 3 points are required".into());
+    }
+    if cdt.num_vertices() < handles.len() {
+        logging::info(|| format!("merged {} points with duplicate (x, y) positions", handles.len() - cdt.num_vertices()));
+    }
+    if let Some(attributes) = attributes {
+        attributes::check_lengths(attributes, handles.len())?;
+    }
+    let mut attributes = attributes
+        .map(|attributes| attributes::transfer(attributes, &handles, cdt.num_vertices()))
+        .unwrap_or_default();
+
+    if let Some(boundary) = boundary {
+        if boundary.len() < 3 {
+            return Err("boundary must have at least 3 vertices".into());
+        }
+        let heights: Vec<f64> = boundary
+            .iter()
+            .map(|&(x, y)| interpolate_linear(&cdt, &z, Point2::new(x, y)))
+            .collect();
+        let boundary_values: Vec<Vec<f64>> = attributes
+            .values()
+            .map(|values| {
+                boundary
+                    .iter()
+                    .map(|&(x, y)| interpolate_linear(&cdt, values, Point2::new(x, y)))
+                    .collect()
+            })
+            .collect();
+        let mut handles = Vec::with_capacity(boundary.len());
+        for (k, (&(x, y), &height)) in boundary.iter().zip(&heights).enumerate() {
+            let handle = cdt.insert(Point2::new(x, y))?;
+            if handle.index() == z.len() {
+                z.push(height);
+                for (values, interpolated) in attributes.values_mut().zip(&boundary_values) {
+                    values.push(interpolated[k]);
+                }
+            }
+            handles.push(handle);
+        }
+        for (i, &from) in handles.iter().enumerate() {
+            add_constraint_checked(&mut cdt, from, handles[(i + 1) % handles.len()])?;
+        }
+    }
+
+    let inside = boundary.map(|boundary| label_faces(&cdt, &[boundary.to_vec()]));
+    let max_length_2 = max_edge_length.map(|length| length * length);
+    let mut triangles = Vec::new();
+    for face in cdt.inner_faces() {
+        if let Some(labels) = &inside {
+            if labels[&face.fix()].is_none() {
+                continue;
+            }
+        }
+        if let Some(limit) = max_length_2 {
+            if face.adjacent_edges().iter().any(|edge| edge.length_2() > limit) {
+                continue;
+            }
+        }
+        let [a, b, c] = face.vertices().map(|v| v.fix().index());
+        triangles.push((a, b, c));
+    }
+
+    let vertices = cdt
+        .vertices()
+        .map(|v| {
+            let p = v.position();
+            (p.x, p.y, z[v.fix().index()])
+        })
+        .collect();
+
+    Ok(Tin {
+        vertices,
+        triangles,
+        edges: constraint_edges(&cdt),
+        attributes,
+    })
+}
+
+/// Linearly interpolate per-vertex values `values` (indexed by vertex index)
+/// at `point`. Outside the convex hull the value of the nearest hull edge
+/// endpoint is used.
+pub(crate) fn interpolate_linear(cdt: &Cdt, values: &[f64], point: Point2<f64>) -> f64 {
+    interpolate_located(cdt, values, point, cdt.locate(point))
+}
+
+/// Same as [`interpolate_linear`] for a point that was already located.
+pub(crate) fn interpolate_located(
+    cdt: &Cdt,
+    values: &[f64],
+    point: Point2<f64>,
+    location: PositionInTriangulation,
+) -> f64 {
+    match located_weights(cdt, point, location) {
+        Some(weights) => weights.iter().map(|&(v, w)| w * values[v]).sum(),
+        None => f64::NAN,
+    }
+}
+
+/// Vertices and weights that [`interpolate_located`] combines for a located
+/// point, padded with zero weights; `None` for an empty triangulation.
+pub(crate) fn located_weights(
+    cdt: &Cdt,
+    point: Point2<f64>,
+    location: PositionInTriangulation,
+) -> Option<[(usize, f64); 3]> {
+    match location {
+        PositionInTriangulation::OnVertex(v) => Some([(v.index(), 1.0), (v.index(), 0.0), (v.index(), 0.0)]),
+        PositionInTriangulation::OnEdge(edge) => {
+            let [a, b] = cdt.directed_edge(edge).vertices();
+            let t = edge_parameter(a.position(), b.position(), point);
+            let (a, b) = (a.fix().index(), b.fix().index());
+            Some([(a, 1.0 - t), (b, t), (a, 0.0)])
+        }
+        PositionInTriangulation::OnFace(face) => {
+            let face = cdt.face(face);
+            let [a, b, c] = face.vertices();
+            let [wa, wb, wc] = barycentric_weights(a.position(), b.position(), c.position(), point);
+            Some([(a.fix().index(), wa), (b.fix().index(), wb), (c.fix().index(), wc)])
+        }
+        PositionInTriangulation::OutsideOfConvexHull(edge) => {
+            let [a, b] = cdt.directed_edge(edge).vertices();
+            let t = edge_parameter(a.position(), b.position(), point);
+            let nearest = if t < 0.5 { a.fix().index() } else { b.fix().index() };
+            Some([(nearest, 1.0), (nearest, 0.0), (nearest, 0.0)])
+        }
+        PositionInTriangulation::NoTriangulation => {
+            let v = cdt.vertices().next()?.fix().index();
+            Some([(v, 1.0), (v, 0.0), (v, 0.0)])
+        }
+    }
+}
+
+/// Barycentric weights of `p` with respect to the triangle `a`, `b`, `c`.
+pub(crate) fn barycentric_weights(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, p: Point2<f64>) -> [f64; 3] {
+    let det = (b.y - c.y) * (a.x - c.x) + (c.x - b.x) * (a.y - c.y);
+    if det == 0.0 {
+        return [1.0 / 3.0; 3];
+    }
+    let wa = ((b.y - c.y) * (p.x - c.x) + (c.x - b.x) * (p.y - c.y)) / det;
+    let wb = ((c.y - a.y) * (p.x - c.x) + (a.x - c.x) * (p.y - c.y)) / det;
+    [wa, wb, 1.0 - wa - wb]
+}
+
+/// Position of the projection of `p` onto segment `a`-`b`, clamped to [0, 1].
+fn edge_parameter(a: Point2<f64>, b: Point2<f64>, p: Point2<f64>) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len_2 = dx * dx + dy * dy;
+    if len_2 == 0.0 {
+        return 0.0;
+    }
+    (((p.x - a.x) * dx + (p.y - a.y) * dy) / len_2).clamp(0.0, 1.0)
+}
+
+/// Convert a DEM raster into an adaptive TIN by greedy insertion.
+///
+/// Starting from the raster corners, the cell with the largest vertical error
+/// in every triangle is inserted, pass after pass, until no cell deviates from
+/// the TIN by more than `max_error`.
+///
+/// Args:
+///     array (list): Raster rows (top to bottom) of heights; NaN marks no-data cells
+///     transform (tuple): GDAL-style geotransform (x0, pixel_width, row_rotation,
+///         y0, column_rotation, pixel_height); use `transform.to_gdal()` for rasterio
+///     max_error (float): Maximum allowed vertical deviation at any cell center
+///     max_vertices (int, optional): Stop once the TIN has this many vertices
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates at cell centers
+///         - 'normals': Area-weighted unit vertex normals
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'max_error': Largest remaining vertical error
+///
+/// Example:
+///     >>> import pyspade
+///     >>> dem = [[0.0, 1.0, 2.0], [1.0, 2.0, 3.0], [2.0, 3.0, 9.0]]
+///     >>> result = pyspade.grid_to_tin(dem, (0, 1, 0, 3, 0, -1), max_error=0.5)
+///     >>> print(len(result['vertices']))
+#[pyfunction]
+#[pyo3(signature = (array, transform, max_error, max_vertices=None))]
+pub fn grid_to_tin(
+    array: Vec<Vec<f64>>,
+    transform: (f64, f64, f64, f64, f64, f64),
+    max_error: f64,
+    max_vertices: Option<usize>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (result, error) = grid_to_tin_impl(&array, transform, max_error, max_vertices).map_err(crate::to_py_err)?;
+        let normals = vertex_normals_impl(&result.vertices, &result.triangles);
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("normals".to_string(), normals.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("max_error".to_string(), error.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn grid_to_tin_impl(
+    array: &[Vec<f64>],
+    transform: (f64, f64, f64, f64, f64, f64),
+    max_error: f64,
+    max_vertices: Option<usize>,
+) -> Result<(Tin, f64), Box<dyn std::error::Error>> {
+    if max_error.is_nan() || max_error < 0.0 {
+        return Err(format!("max_error must be non-negative, got {}", max_error).into());
+    }
+    let rows = array.len();
+    let cols = array.first().map_or(0, |row| row.len());
+    if rows < 2 || cols < 2 || array.iter().any(|row| row.len() != cols) {
+        return Err("array must be a rectangular grid of at least 2 x 2 cells".into());
+    }
+    let (x0, dx_col, dx_row, y0, dy_col, dy_row) = transform;
+    let cell_center = |row: usize, col: usize| {
+        let (c, r) = (col as f64 + 0.5, row as f64 + 0.5);
+        Point2::new(x0 + c * dx_col + r * dx_row, y0 + c * dy_col + r * dy_row)
+    };
+
+    let mut samples = Vec::new();
+    let mut corners = Vec::new();
+    for (row, values) in array.iter().enumerate() {
+        for (col, &height) in values.iter().enumerate() {
+            if height.is_nan() {
+                continue;
+            }
+            if (row == 0 || row == rows - 1) && (col == 0 || col == cols - 1) {
+                corners.push(samples.len());
+            }
+            samples.push((cell_center(row, col), height));
+        }
+    }
+    let mut tin = GreedyTin::new(&samples, &corners, &[])?;
+    let worst_error = tin.refine(&samples, max_error, max_vertices)?;
+
+    let triangles = tin
+        .cdt
+        .inner_faces()
+        .map(|face| {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            (a, b, c)
+        })
+        .collect();
+    let vertices = tin.vertices();
+
+    Ok((
+        Tin {
+            vertices,
+            triangles,
+            edges: Vec::new(),
+            attributes: Attributes::new(),
+        },
+        worst_error,
+    ))
+}
+
+/// Build a TIN from a subset of scattered samples by greedy insertion.
+///
+/// Starting from the extreme samples, the sample with the largest vertical
+/// error in every triangle is inserted, pass after pass, until no sample
+/// deviates from the TIN by more than `max_error`: the adaptive DEM-to-TIN
+/// algorithm of `grid_to_tin()` for point clouds such as lidar ground
+/// returns. Samples sharing an (x, y) position keep the first height.
+///
+/// Args:
+///     points_xyz (list): Samples as an (n, 3) NumPy array or list of (x, y, z) tuples
+///     max_error (float): Maximum allowed vertical deviation at any sample
+///     max_vertices (int, optional): Stop once the TIN has this many vertices
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates, all of them samples
+///         - 'normals': Area-weighted unit vertex normals
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'indices': Index into points_xyz of each vertex
+///         - 'max_error': Largest remaining vertical error
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.adaptive_tin(ground_points, max_error=0.1)
+///     >>> print(f"kept {len(result['vertices'])} of {len(ground_points)} points")
+#[pyfunction]
+#[pyo3(signature = (points_xyz, max_error, max_vertices=None))]
+pub fn adaptive_tin(
+    points_xyz: PointArrayXYZ,
+    max_error: f64,
+    max_vertices: Option<usize>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (result, indices, error) = py
+            .allow_threads(|| adaptive_tin_impl(&points_xyz.0, max_error, max_vertices).map_err(|e| e.to_string()))
+            .map_err(|e| crate::to_py_err(e.into()))?;
+        let normals = vertex_normals_impl(&result.vertices, &result.triangles);
+
+        let mut output = HashMap::new();
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+        output.insert("normals".to_string(), normals.into_py(py));
+        output.insert("triangles".to_string(), result.triangles.into_py(py));
+        output.insert("indices".to_string(), indices.into_py(py));
+        output.insert("max_error".to_string(), error.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn adaptive_tin_impl(
+    points_xyz: &[(f64, f64, f64)],
+    max_error: f64,
+    max_vertices: Option<usize>,
+) -> Result<(Tin, Vec<usize>, f64), Box<dyn std::error::Error>> {
+    if max_error.is_nan() || max_error < 0.0 {
+        return Err(format!("max_error must be non-negative, got {}", max_error).into());
+    }
+    if points_xyz.len() < 3 {
+        return Err("at least 3 points are required".into());
+    }
+    let samples: Vec<(Point2<f64>, f64)> = points_xyz.iter().map(|&(x, y, z)| (Point2::new(x, y), z)).collect();
+
+    // The samples extreme in x, y, x + y and x - y span most of the hull
+    let keys: [fn(Point2<f64>) -> f64; 4] = [|p| p.x, |p| p.y, |p| p.x + p.y, |p| p.x - p.y];
+    let mut initial = Vec::new();
+    for key in keys {
+        let order = |&a: &usize, &b: &usize| key(samples[a].0).total_cmp(&key(samples[b].0));
+        initial.extend((0..samples.len()).min_by(order));
+        initial.extend((0..samples.len()).max_by(order));
+    }
+    initial.sort_unstable();
+    initial.dedup();
+    let mut tin = GreedyTin::new(&samples, &initial, &[])?;
+    let worst_error = tin.refine(&samples, max_error, max_vertices)?;
+
+    let triangles = tin
+        .cdt
+        .inner_faces()
+        .map(|face| {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            (a, b, c)
+        })
+        .collect();
+    let vertices = tin.vertices();
+
+    Ok((
+        Tin {
+            vertices,
+            triangles,
+            edges: Vec::new(),
+            attributes: Attributes::new(),
+        },
+        tin.sources,
+        worst_error,
+    ))
+}
+
+/// Simplify a 2.5D mesh to a vertical error tolerance.
+///
+/// Vertices are dropped as long as the surface stays within `max_error` of
+/// every input vertex, for publishing lightweight terrain tiles. Boundary
+/// edges and the constrained 'edges' are kept as they are. The result is
+/// built by greedy insertion from the vertices of those edges, adding the
+/// worst-fitting input vertex of every triangle until all are within the
+/// tolerance.
+///
+/// Args:
+///     mesh (dict): Mesh with (x, y, z) 'vertices', 'triangles' and optionally 'edges'
+///     max_error (float): Maximum allowed vertical deviation at any input vertex
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates, all of them input vertices
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edges (boundary and input 'edges')
+///         - 'indices': Input index of each vertex
+///         - 'max_error': Largest vertical error at an input vertex
+///
+/// Example:
+///     >>> import pyspade
+///     >>> terrain = pyspade.tin(points_xyz, boundary=tile)
+///     >>> light = pyspade.simplify_tin(terrain, max_error=0.25)
+#[pyfunction]
+pub fn simplify_tin(mesh: Mesh, max_error: f64) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (result, indices, error) = py
+            .allow_threads(|| simplify_tin_impl(&mesh, max_error).map_err(|e| e.to_string()))
+            .map_err(|e| crate::to_py_err(e.into()))?;
+
+        let mut output = result.into_dict(py);
+        output.insert("indices".to_string(), indices.into_py(py));
+        output.insert("max_error".to_string(), error.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn simplify_tin_impl(mesh: &Mesh, max_error: f64) -> Result<(Mesh, Vec<usize>, f64), Box<dyn std::error::Error>> {
+    if max_error.is_nan() || max_error < 0.0 {
+        return Err(format!("max_error must be non-negative, got {}", max_error).into());
+    }
+    let (used, samples, mut tin) = simplification_start(mesh)?;
+    let worst_error = tin.refine(&samples, max_error, None)?;
+    Ok((tin.to_mesh(mesh), tin.sources.iter().map(|&i| used[i]).collect(), worst_error))
+}
+
+/// Generate levels of detail of a 2.5D mesh with shared vertex numbering.
+///
+/// Levels are simplified as in `simplify_tin()`, by one greedy insertion run
+/// from the boundary and constrained edges that stops at each level in turn.
+/// Every level therefore uses the first vertices of the next finer one,
+/// numbered the same, so a client streaming terrain at several zoom levels
+/// only adds vertices when it zooms in.
+///
+/// Args:
+///     mesh (dict): Mesh with (x, y, z) 'vertices', 'triangles' and optionally 'edges'
+///     max_errors (list, optional): Vertical error tolerance of each level
+///     target_counts (list, optional): Vertex count of each level, instead of max_errors
+///
+/// Returns:
+///     list: One mesh dict per level, in the order given, with keys:
+///         - 'vertices': List of (x, y, z) vertex coordinates, the first vertices of every
+///           finer level
+///         - 'triangles': List of (i, j, k) triangle vertex indices (0-based)
+///         - 'edges': List of (i, j) constrained edges (boundary and input 'edges')
+///         - 'indices': Input index of each vertex
+///         - 'max_error': Largest vertical error at an input vertex
+///
+/// Example:
+///     >>> import pyspade
+///     >>> levels = pyspade.lods(terrain, max_errors=[4.0, 1.0, 0.25])
+///     >>> print([len(level['triangles']) for level in levels])
+#[pyfunction]
+#[pyo3(signature = (mesh, max_errors=None, target_counts=None))]
+pub fn lods(
+    mesh: Mesh,
+    max_errors: Option<Vec<f64>>,
+    target_counts: Option<Vec<usize>>,
+) -> PyResult<Vec<HashMap<String, PyObject>>> {
+    Python::with_gil(|py| {
+        let levels = match (max_errors, target_counts) {
+            (Some(errors), None) => errors.into_iter().map(Lod::MaxError).collect(),
+            (None, Some(counts)) => counts.into_iter().map(Lod::Vertices).collect(),
+            _ => return Err(crate::to_py_err("give either max_errors or target_counts".into())),
+        };
+        let results = py
+            .allow_threads(|| lods_impl(&mesh, &levels).map_err(|e| e.to_string()))
+            .map_err(|e| crate::to_py_err(e.into()))?;
+
+        Ok(results
+            .into_iter()
+            .map(|(level, indices, error)| {
+                let mut output = level.into_dict(py);
+                output.insert("indices".to_string(), indices.into_py(py));
+                output.insert("max_error".to_string(), error.into_py(py));
+                output
+            })
+            .collect())
+    })
+}
+
+/// Where one level of detail stops.
+#[derive(Clone, Copy)]
+pub(crate) enum Lod {
+    MaxError(f64),
+    Vertices(usize),
+}
+
+type Level = (Mesh, Vec<usize>, f64);
+
+pub(crate) fn lods_impl(mesh: &Mesh, levels: &[Lod]) -> Result<Vec<Level>, Box<dyn std::error::Error>> {
+    for &level in levels {
+        match level {
+            Lod::MaxError(error) if error.is_nan() || error < 0.0 => {
+                return Err(format!("max_errors must be non-negative, got {}", error).into());
+            }
+            Lod::Vertices(0) => return Err("target_counts must be positive".into()),
+            _ => {}
+        }
+    }
+    let (used, samples, mut tin) = simplification_start(mesh)?;
+
+    // Coarsest first, so each level continues the previous one's insertion
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    order.sort_by(|&a, &b| match (levels[a], levels[b]) {
+        (Lod::MaxError(a), Lod::MaxError(b)) => b.total_cmp(&a),
+        (Lod::Vertices(a), Lod::Vertices(b)) => a.cmp(&b),
+        _ => std::cmp::Ordering::Equal,
+    });
+    let mut results: Vec<Option<Level>> = (0..levels.len()).map(|_| None).collect();
+    for k in order {
+        let worst_error = match levels[k] {
+            Lod::MaxError(error) => tin.refine(&samples, error, None)?,
+            Lod::Vertices(count) => tin.refine(&samples, 0.0, Some(count))?,
+        };
+        results[k] = Some((tin.to_mesh(mesh), tin.sources.iter().map(|&i| used[i]).collect(), worst_error));
+    }
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Input index of each sample, the samples and the TIN to refine from.
+type Simplification = (Vec<usize>, Vec<(Point2<f64>, f64)>, GreedyTin);
+
+/// The vertices of `mesh` in use as samples (and the input index of each),
+/// and the greedy TIN of its boundary and constrained edges.
+fn simplification_start(mesh: &Mesh) -> Result<Simplification, Box<dyn std::error::Error>> {
+    if mesh.triangles.is_empty() {
+        return Err("mesh has no triangles".into());
+    }
+
+    // Samples are the vertices in use, in input order
+    let mut used: Vec<usize> = mesh.triangles.iter().flat_map(|&(a, b, c)| [a, b, c]).collect();
+    used.sort_unstable();
+    used.dedup();
+    let sample_of: HashMap<usize, usize> = used.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    let samples: Vec<(Point2<f64>, f64)> = used
+        .iter()
+        .map(|&v| {
+            let (x, y, z) = mesh.vertices[v];
+            (Point2::new(x, y), z)
+        })
+        .collect();
+
+    // Boundary edges (used by one triangle) and constrained edges are kept
+    let mut uses: HashMap<(usize, usize), usize> = HashMap::new();
+    for &(a, b, c) in &mesh.triangles {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((u.min(v), u.max(v))).or_default() += 1;
+        }
+    }
+    let mut features: Vec<(usize, usize)> = uses
+        .into_iter()
+        .filter(|&(_, count)| count == 1)
+        .map(|(edge, _)| edge)
+        .chain(mesh.edges.iter().map(|&(u, v)| (u.min(v), u.max(v))))
+        .filter(|&(u, v)| u != v)
+        .filter_map(|(u, v)| Some((*sample_of.get(&u)?, *sample_of.get(&v)?)))
+        .collect();
+    features.sort_unstable();
+    features.dedup();
+    let mut initial: Vec<usize> = features.iter().flat_map(|&(u, v)| [u, v]).collect();
+    initial.sort_unstable();
+    initial.dedup();
+    let tin = GreedyTin::new(&samples, &initial, &features)?;
+    Ok((used, samples, tin))
+}
+
+/// Triangulation grown by greedy insertion of `(position, height)` samples,
+/// with the height and sample index of each vertex. Vertices are numbered in
+/// insertion order, so a later state extends the vertices of an earlier one.
+struct GreedyTin {
+    cdt: Cdt,
+    z: Vec<f64>,
+    sources: Vec<usize>,
+}
+
+impl GreedyTin {
+    /// Start from the `initial` samples joined by the `constraints` between them.
+    fn new(
+        samples: &[(Point2<f64>, f64)],
+        initial: &[usize],
+        constraints: &[(usize, usize)],
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut tin = GreedyTin {
+            cdt: Cdt::default(),
+            z: Vec::new(),
+            sources: Vec::new(),
+        };
+        let mut handles = HashMap::new();
+        for &i in initial {
+            handles.insert(i, tin.insert(samples, i)?);
+        }
+        for &(a, b) in constraints {
+            let (Some(&from), Some(&to)) = (handles.get(&a), handles.get(&b)) else {
+                return Err(format!("constraint ({}, {}) joins samples that are not initial vertices", a, b).into());
+            };
+            add_constraint_checked(&mut tin.cdt, from, to)?;
+        }
+        Ok(tin)
+    }
+
+    /// Each pass inserts the sample with the largest vertical error in every
+    /// triangle (or beyond every hull edge, where the error counts as
+    /// infinite), until no sample deviates by more than `max_error` or the
+    /// triangulation has `max_vertices` vertices. Returns the largest
+    /// remaining error.
+    fn refine(
+        &mut self,
+        samples: &[(Point2<f64>, f64)],
+        max_error: f64,
+        max_vertices: Option<usize>,
+    ) -> Result<f64, Box<dyn std::error::Error>> {
+        let limit = max_vertices.unwrap_or(usize::MAX);
+        loop {
+            // Worst sample per triangle (or per hull edge for samples outside the hull)
+            let mut worst: HashMap<(u8, usize), (f64, usize)> = HashMap::new();
+            let mut worst_error = 0.0f64;
+            for (i, &(point, height)) in samples.iter().enumerate() {
+                let location = self.cdt.locate(point);
+                let key = match location {
+                    PositionInTriangulation::OnVertex(_) => continue,
+                    PositionInTriangulation::OnFace(face) => (0, face.index()),
+                    PositionInTriangulation::OnEdge(edge) => (1, edge.index()),
+                    PositionInTriangulation::OutsideOfConvexHull(edge) => (2, edge.index()),
+                    PositionInTriangulation::NoTriangulation => (3, 0),
+                };
+                let error = match location {
+                    PositionInTriangulation::OutsideOfConvexHull(_) | PositionInTriangulation::NoTriangulation => {
+                        f64::INFINITY
+                    }
+                    _ => (height - interpolate_located(&self.cdt, &self.z, point, location)).abs(),
+                };
+                worst_error = worst_error.max(error);
+                if error > max_error {
+                    let entry = worst.entry(key).or_insert((error, i));
+                    if error > entry.0 {
+                        *entry = (error, i);
+                    }
+                }
+            }
+            if worst.is_empty() || self.cdt.num_vertices() >= limit {
+                return Ok(worst_error);
+            }
+            let mut batch: Vec<(f64, usize)> = worst.into_values().collect();
+            batch.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+            batch.truncate(limit - self.cdt.num_vertices());
+            for (_, i) in batch {
+                self.insert(samples, i)?;
+            }
+        }
+    }
+
+    /// Insert sample `i`, recording its height and index unless its position
+    /// is already a vertex.
+    fn insert(
+        &mut self,
+        samples: &[(Point2<f64>, f64)],
+        i: usize,
+    ) -> Result<FixedVertexHandle, Box<dyn std::error::Error>> {
+        let (point, height) = samples[i];
+        let handle = self.cdt.insert(point)?;
+        if handle.index() == self.z.len() {
+            self.z.push(height);
+            self.sources.push(i);
+        }
+        Ok(handle)
+    }
+
+    /// The current state as a mesh, without the triangles outside `domain`
+    /// (the mesh the samples come from).
+    fn to_mesh(&self, domain: &Mesh) -> Mesh {
+        let grid = TriangleGrid::new(domain);
+        let triangles = self
+            .cdt
+            .inner_faces()
+            .filter(|face| {
+                let c = face.center();
+                grid.locate(domain, c.x, c.y).is_some()
+            })
+            .map(|face| {
+                let [a, b, c] = face.vertices().map(|v| v.fix().index());
+                (a, b, c)
+            })
+            .collect();
+        Mesh {
+            vertices: self.vertices(),
+            triangles,
+            edges: constraint_edges(&self.cdt),
+        }
+    }
+
+    /// Vertices with their heights.
+    fn vertices(&self) -> Vec<(f64, f64, f64)> {
+        self.cdt
+            .vertices()
+            .map(|v| {
+                let p = v.position();
+                (p.x, p.y, self.z[v.fix().index()])
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,142 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+/// Decimate a dense point cloud on a regular grid before triangulation.
+///
+/// Every grid cell keeps the point closest to its center. With `tolerance`,
+/// a least-squares plane is also fitted to each cell's points and every point
+/// deviating from it by more than `tolerance` is kept as well, so breaklines
+/// and other sharp features survive the thinning.
+///
+/// Args:
+///     points_xyz (list): Points as list of (x, y, z) tuples
+///     cell_size (float): Grid cell size in x/y units
+///     tolerance (float, optional): Vertical deviation from the local plane above which
+///         additional points are kept. Default: keep one point per cell only
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'indices': Sorted indices of the kept input points
+///         - 'points': The kept (x, y, z) points
+///
+/// Example:
+///     >>> import pyspade
+///     >>> thinned = pyspade.thin_points(points_xyz, cell_size=1.0, tolerance=0.1)
+///     >>> mesh = pyspade.tin(thinned['points'])
+#[pyfunction]
+#[pyo3(signature = (points_xyz, cell_size, tolerance=None))]
+pub fn thin_points(
+    points_xyz: Vec<(f64, f64, f64)>,
+    cell_size: f64,
+    tolerance: Option<f64>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let indices = thin_points_impl(&points_xyz, cell_size, tolerance).map_err(crate::to_py_err)?;
+        let points: Vec<(f64, f64, f64)> = indices.iter().map(|&i| points_xyz[i]).collect();
+
+        let mut output = HashMap::new();
+        output.insert("indices".to_string(), indices.into_py(py));
+        output.insert("points".to_string(), points.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn thin_points_impl(
+    points_xyz: &[(f64, f64, f64)],
+    cell_size: f64,
+    tolerance: Option<f64>,
+) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+    if cell_size.is_nan() || cell_size <= 0.0 {
+        return Err(format!("cell_size must be positive, got {}", cell_size).into());
+    }
+    if let Some(tolerance) = tolerance {
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(format!("tolerance must be non-negative, got {}", tolerance).into());
+        }
+    }
+
+    let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (i, &(x, y, _)) in points_xyz.iter().enumerate() {
+        let key = ((x / cell_size).floor() as i64, (y / cell_size).floor() as i64);
+        cells.entry(key).or_default().push(i);
+    }
+
+    let mut kept = Vec::new();
+    for ((cx, cy), members) in cells {
+        let center = ((cx as f64 + 0.5) * cell_size, (cy as f64 + 0.5) * cell_size);
+        let representative = members
+            .iter()
+            .copied()
+            .min_by(|&a, &b| {
+                let da = (points_xyz[a].0 - center.0).hypot(points_xyz[a].1 - center.1);
+                let db = (points_xyz[b].0 - center.0).hypot(points_xyz[b].1 - center.1);
+                da.total_cmp(&db)
+            })
+            .expect("grid cells are never empty");
+        kept.push(representative);
+
+        if let Some(tolerance) = tolerance {
+            if let Some(plane) = fit_plane(points_xyz, &members) {
+                kept.extend(members.iter().copied().filter(|&i| {
+                    let (x, y, z) = points_xyz[i];
+                    i != representative && (z - plane.evaluate(x, y)).abs() > tolerance
+                }));
+            }
+        }
+    }
+    kept.sort_unstable();
+    Ok(kept)
+}
+
+/// Plane `z = a * (x - x0) + b * (y - y0) + c`.
+pub(crate) struct Plane {
+    a: f64,
+    b: f64,
+    c: f64,
+    x0: f64,
+    y0: f64,
+}
+
+impl Plane {
+    pub fn evaluate(&self, x: f64, y: f64) -> f64 {
+        self.a * (x - self.x0) + self.b * (y - self.y0) + self.c
+    }
+}
+
+/// Least-squares plane through the selected points, or `None` if they are
+/// fewer than three or collinear.
+pub(crate) fn fit_plane(points_xyz: &[(f64, f64, f64)], members: &[usize]) -> Option<Plane> {
+    if members.len() < 3 {
+        return None;
+    }
+    let n = members.len() as f64;
+    let (sx, sy, sz) = members.iter().fold((0.0, 0.0, 0.0), |(sx, sy, sz), &i| {
+        let (x, y, z) = points_xyz[i];
+        (sx + x, sy + y, sz + z)
+    });
+    let (x0, y0, c) = (sx / n, sy / n, sz / n);
+
+    // Normal equations of the centered problem
+    let (mut sxx, mut sxy, mut syy, mut sxz, mut syz) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for &i in members {
+        let (x, y, z) = points_xyz[i];
+        let (dx, dy, dz) = (x - x0, y - y0, z - c);
+        sxx += dx * dx;
+        sxy += dx * dy;
+        syy += dy * dy;
+        sxz += dx * dz;
+        syz += dy * dz;
+    }
+    let det = sxx * syy - sxy * sxy;
+    if det.abs() <= f64::EPSILON * (sxx * syy).max(f64::MIN_POSITIVE) {
+        return None;
+    }
+    Some(Plane {
+        a: (sxz * syy - syz * sxy) / det,
+        b: (syz * sxx - sxz * sxy) / det,
+        c,
+        x0,
+        y0,
+    })
+}
@@ -0,0 +1,42 @@
+//! Worker threads for the bulk query functions.
+
+use std::num::NonZeroUsize;
+
+/// Environment variable capping the worker threads when a call does not
+/// pass `num_threads`, e.g. set by a job scheduler.
+const NUM_THREADS_VAR: &str = "PYSPADE_NUM_THREADS";
+
+/// Worker threads to use: `requested` if given, else `PYSPADE_NUM_THREADS`,
+/// else the available parallelism.
+pub(crate) fn num_threads(requested: Option<usize>) -> Result<usize, Box<dyn std::error::Error>> {
+    if requested == Some(0) {
+        return Err("num_threads must be at least 1".into());
+    }
+    Ok(requested
+        .or_else(|| {
+            std::env::var(NUM_THREADS_VAR)
+                .ok()
+                .and_then(|value| value.trim().parse().ok())
+                .filter(|&n| n > 0)
+        })
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, NonZeroUsize::get)))
+}
+
+/// `items.iter().map(f)` spread over up to `threads` scoped threads, in
+/// input order.
+pub(crate) fn parallel_map<T: Sync, R: Send>(items: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    if threads <= 1 || items.len() < 2 {
+        return items.iter().map(f).collect();
+    }
+    let chunk = items.len() / threads + 1;
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = items
+            .chunks(chunk)
+            .map(|part| scope.spawn(|| part.iter().map(&f).collect::<Vec<R>>()))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("worker thread panicked"))
+            .collect()
+    })
+}
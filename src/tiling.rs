@@ -0,0 +1,396 @@
+//! Tiled meshing of domains too large for one triangulation.
+
+use pyo3::prelude::*;
+use spade::{FixedVertexHandle, Point2, Triangulation};
+use std::collections::HashMap;
+
+use crate::geometry;
+use crate::logging;
+use crate::regions::{add_constraint_checked, constraint_edges, label_faces, Cdt};
+use crate::threads::{self, parallel_map};
+
+/// Mesh a large domain as a grid of independently meshed tiles.
+///
+/// The bounding box of `outer` is cut into square tiles of side `tile_size`,
+/// which are meshed in parallel. Tile borders and the domain boundary are
+/// split into segments of at most `max_edge_length` at positions computed
+/// from global coordinates only, and refinement never splits them further, so
+/// neighboring tiles have identical vertices along their common border and
+/// the tiles join into one conforming mesh. Tiles outside the domain are
+/// left out.
+///
+/// Args:
+///     outer (list): Outer boundary as list of (x, y) tuples
+///     tile_size (float): Side length of the square tiles
+///     max_edge_length (float): Target maximum edge length for mesh refinement
+///     holes (list, optional): List of holes, each as list of (x, y) tuples
+///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
+///     num_threads (int, optional): Worker threads. Default: the PYSPADE_NUM_THREADS
+///         environment variable, or all available cores
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'tiles': List of tile meshes, each a dict with 'vertices', 'triangles',
+///           'edges' (domain boundary edges in the tile), 'tile' ((column, row) in the
+///           tile grid), 'bounds' ((xmin, ymin, xmax, ymax)) and 'global_indices'
+///           (index into 'vertices' of each tile vertex)
+///         - 'vertices': List of (x, y, z) coordinates of all distinct vertices (z=0.0);
+///           a vertex on a tile border has the same global index in every tile
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.triangulate_tiled(
+///     ...     outer=[(0, 0), (10000, 0), (10000, 10000), (0, 10000)],
+///     ...     tile_size=1000.0,
+///     ...     max_edge_length=10.0
+///     ... )
+///     >>> print(len(result['tiles']), len(result['vertices']))
+#[pyfunction]
+#[pyo3(signature = (outer, tile_size, max_edge_length, holes=None, min_angle=None, num_threads=None))]
+pub fn triangulate_tiled(
+    outer: Vec<(f64, f64)>,
+    tile_size: f64,
+    max_edge_length: f64,
+    holes: Option<Vec<Vec<(f64, f64)>>>,
+    min_angle: Option<f64>,
+    num_threads: Option<usize>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let holes = holes.unwrap_or_default();
+        let result = py
+            .allow_threads(|| {
+                triangulate_tiled_impl(&outer, &holes, tile_size, max_edge_length, min_angle, num_threads)
+                    .map_err(|e| e.to_string())
+            })
+            .map_err(|e| crate::to_py_err(e.into()))?;
+
+        let tiles: Vec<HashMap<String, PyObject>> = result
+            .tiles
+            .into_iter()
+            .map(|tile| {
+                let mut output = HashMap::new();
+                output.insert("vertices".to_string(), tile.vertices.into_py(py));
+                output.insert("triangles".to_string(), tile.triangles.into_py(py));
+                output.insert("edges".to_string(), tile.edges.into_py(py));
+                output.insert("tile".to_string(), tile.tile.into_py(py));
+                output.insert("bounds".to_string(), tile.bounds.into_py(py));
+                output.insert("global_indices".to_string(), tile.global_indices.into_py(py));
+                output
+            })
+            .collect();
+
+        let mut output = HashMap::new();
+        output.insert("tiles".to_string(), tiles.into_py(py));
+        output.insert("vertices".to_string(), result.vertices.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct TileMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub triangles: Vec<(usize, usize, usize)>,
+    pub edges: Vec<(usize, usize)>,
+    pub tile: (usize, usize),
+    pub bounds: (f64, f64, f64, f64),
+    pub global_indices: Vec<usize>,
+}
+
+pub(crate) struct TiledMesh {
+    pub tiles: Vec<TileMesh>,
+    pub vertices: Vec<(f64, f64, f64)>,
+}
+
+/// Tile grid over the domain: tile lines lie at `origin + i * size`.
+struct TileGrid {
+    origin: (f64, f64),
+    size: f64,
+    /// Border segments per tile side
+    divisions: usize,
+}
+
+impl TileGrid {
+    fn x(&self, column: f64) -> f64 {
+        self.origin.0 + column * self.size
+    }
+
+    fn y(&self, row: f64) -> f64 {
+        self.origin.1 + row * self.size
+    }
+
+    fn bounds(&self, (column, row): (usize, usize)) -> (f64, f64, f64, f64) {
+        let (column, row) = (column as f64, row as f64);
+        (self.x(column), self.y(row), self.x(column + 1.0), self.y(row + 1.0))
+    }
+
+    /// Points along the border of a tile, counter-clockwise from its lower
+    /// left corner. A point is computed the same way by both tiles sharing it.
+    fn border(&self, (column, row): (usize, usize)) -> Vec<(f64, f64)> {
+        let n = self.divisions;
+        let (column, row) = (column as f64, row as f64);
+        let step = |k: usize| k as f64 / n as f64;
+        let bottom = (0..n).map(|k| (self.x(column + step(k)), self.y(row)));
+        let right = (0..n).map(|k| (self.x(column + 1.0), self.y(row + step(k))));
+        let top = (0..n).map(|k| (self.x(column + step(n - k)), self.y(row + 1.0)));
+        let left = (0..n).map(|k| (self.x(column), self.y(row + step(n - k))));
+        bottom.chain(right).chain(top).chain(left).collect()
+    }
+
+    /// The segments that may reach into each tile, indexed by
+    /// `row * columns + column`. A segment goes to every tile its bounding
+    /// box overlaps, widened by one tile so that rounding at tile lines never
+    /// loses it; clipping sorts out the rest.
+    fn buckets(
+        &self,
+        segments: &[((f64, f64), (f64, f64))],
+        columns: usize,
+        rows: usize,
+    ) -> Vec<Vec<((f64, f64), (f64, f64))>> {
+        let cell = |value: f64, origin: f64, count: usize| {
+            (((value - origin) / self.size).floor().max(0.0) as usize).min(count - 1)
+        };
+        let mut buckets = vec![Vec::new(); columns * rows];
+        for &(a, b) in segments {
+            let first_column = cell(a.0.min(b.0), self.origin.0, columns).saturating_sub(1);
+            let last_column = (cell(a.0.max(b.0), self.origin.0, columns) + 1).min(columns - 1);
+            let first_row = cell(a.1.min(b.1), self.origin.1, rows).saturating_sub(1);
+            let last_row = (cell(a.1.max(b.1), self.origin.1, rows) + 1).min(rows - 1);
+            for row in first_row..=last_row {
+                for column in first_column..=last_column {
+                    buckets[row * columns + column].push((a, b));
+                }
+            }
+        }
+        buckets
+    }
+}
+
+pub(crate) fn triangulate_tiled_impl(
+    outer: &[(f64, f64)],
+    holes: &[Vec<(f64, f64)>],
+    tile_size: f64,
+    max_edge_length: f64,
+    min_angle: Option<f64>,
+    num_threads: Option<usize>,
+) -> Result<TiledMesh, Box<dyn std::error::Error>> {
+    if tile_size.is_nan() || tile_size <= 0.0 {
+        return Err(format!("tile_size must be positive, got {}", tile_size).into());
+    }
+    crate::validate_edge_length(Some(max_edge_length))?;
+    if outer.len() < 3 {
+        return Err("outer boundary needs at least 3 points".into());
+    }
+    let threads = threads::num_threads(num_threads)?;
+
+    let (xmin, ymin, xmax, ymax) = geometry::bounding_box(outer).ok_or("outer boundary is empty")?;
+    let grid = TileGrid {
+        origin: (xmin, ymin),
+        size: tile_size,
+        divisions: (tile_size / max_edge_length).ceil().max(1.0) as usize,
+    };
+    let columns = (((xmax - xmin) / tile_size).ceil() as usize).max(1);
+    let rows = (((ymax - ymin) / tile_size).ceil() as usize).max(1);
+
+    let mut rings = vec![outer.to_vec()];
+    rings.extend(holes.iter().cloned());
+    let segments: Vec<((f64, f64), (f64, f64))> = rings
+        .iter()
+        .flat_map(|ring| geometry::ring_edges(ring).collect::<Vec<_>>())
+        .flat_map(|(p, q)| subdivide(p, q, max_edge_length))
+        .collect();
+
+    let buckets = grid.buckets(&segments, columns, rows);
+
+    let tiles: Vec<(usize, usize)> = (0..rows).flat_map(|row| (0..columns).map(move |column| (column, row))).collect();
+    let meshed = parallel_map(&tiles, threads, |&(column, row)| {
+        let candidates = &buckets[row * columns + column];
+        mesh_tile(&grid, (column, row), &rings, candidates, max_edge_length, min_angle).map_err(|e| e.to_string())
+    });
+
+    // Tiles compute shared border vertices identically, so merging by exact
+    // position numbers them once
+    let mut vertices = Vec::new();
+    let mut global: HashMap<(u64, u64), usize> = HashMap::new();
+    let mut output = Vec::new();
+    let mut incomplete = 0;
+    for result in meshed {
+        let Some((mut tile, complete)) = result? else {
+            continue;
+        };
+        if !complete {
+            incomplete += 1;
+        }
+        tile.global_indices = tile
+            .vertices
+            .iter()
+            .map(|&(x, y, z)| {
+                *global.entry((x.to_bits(), y.to_bits())).or_insert_with(|| {
+                    vertices.push((x, y, z));
+                    vertices.len() - 1
+                })
+            })
+            .collect();
+        output.push(tile);
+    }
+    if incomplete > 0 {
+        logging::warning(|| format!("refinement of {} tiles stopped at the vertex limit", incomplete));
+    }
+    logging::info(|| format!("meshed {} of {} tiles with {} vertices", output.len(), tiles.len(), vertices.len()));
+
+    Ok(TiledMesh { tiles: output, vertices })
+}
+
+/// Split the segment `p`-`q` into pieces of at most `max_length`, walking
+/// from its lexicographically smaller end so that the split points do not
+/// depend on the ring orientation.
+fn subdivide(p: (f64, f64), q: (f64, f64), max_length: f64) -> Vec<((f64, f64), (f64, f64))> {
+    let (a, b) = if p.0 < q.0 || (p.0 == q.0 && p.1 <= q.1) { (p, q) } else { (q, p) };
+    let n = ((b.0 - a.0).hypot(b.1 - a.1) / max_length).ceil().max(1.0) as usize;
+    let point = |k: usize| {
+        if k == n {
+            return b;
+        }
+        let t = k as f64 / n as f64;
+        (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1))
+    };
+    (0..n).map(|k| (point(k), point(k + 1))).collect()
+}
+
+/// Part of the segment `a`-`b` inside `bounds`, with the points where it
+/// crosses a tile line placed exactly on that line.
+fn clip_segment(
+    a: (f64, f64),
+    b: (f64, f64),
+    (xmin, ymin, xmax, ymax): (f64, f64, f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let on_x = |x: f64| (x, a.1 + (x - a.0) / dx * dy);
+    let on_y = |y: f64| (a.0 + (y - a.1) / dy * dx, y);
+    let (mut enter, mut exit) = ((0.0, a), (1.0, b));
+    for (delta, low, high, start, crossing) in [
+        (dx, xmin, xmax, a.0, &on_x as &dyn Fn(f64) -> (f64, f64)),
+        (dy, ymin, ymax, a.1, &on_y as &dyn Fn(f64) -> (f64, f64)),
+    ] {
+        if delta == 0.0 {
+            if start < low || start > high {
+                return None;
+            }
+            continue;
+        }
+        let (first, second) = if delta > 0.0 { (low, high) } else { (high, low) };
+        let t_first = (first - start) / delta;
+        let t_second = (second - start) / delta;
+        if t_first > enter.0 {
+            enter = (t_first, crossing(first));
+        }
+        if t_second < exit.0 {
+            exit = (t_second, crossing(second));
+        }
+    }
+    if enter.0 >= exit.0 {
+        return None;
+    }
+    let clamp = |(x, y): (f64, f64)| (x.clamp(xmin, xmax), y.clamp(ymin, ymax));
+    Some((clamp(enter.1), clamp(exit.1)))
+}
+
+/// Whether the axis-aligned segment `piece` contains the segment `p`-`q` on
+/// the same line.
+fn covers((a, b): ((f64, f64), (f64, f64)), p: (f64, f64), q: (f64, f64)) -> bool {
+    let within = |x: f64, low: f64, high: f64| x >= low.min(high) && x <= low.max(high);
+    if a.0 == b.0 {
+        p.0 == a.0 && q.0 == a.0 && within(p.1, a.1, b.1) && within(q.1, a.1, b.1)
+    } else {
+        p.1 == a.1 && q.1 == a.1 && within(p.0, a.0, b.0) && within(q.0, a.0, b.0)
+    }
+}
+
+/// Mesh one tile, or `None` if no triangle of it lies in the domain. Also
+/// returns whether refinement completed.
+fn mesh_tile(
+    grid: &TileGrid,
+    tile: (usize, usize),
+    rings: &[Vec<(f64, f64)>],
+    segments: &[((f64, f64), (f64, f64))],
+    max_edge_length: f64,
+    min_angle: Option<f64>,
+) -> Result<Option<(TileMesh, bool)>, Box<dyn std::error::Error>> {
+    let bounds = grid.bounds(tile);
+    let pieces: Vec<_> = segments.iter().filter_map(|&(a, b)| clip_segment(a, b, bounds)).collect();
+    let (xmin, ymin, xmax, ymax) = bounds;
+    let covered = || geometry::point_in_polygon((0.5 * (xmin + xmax), 0.5 * (ymin + ymax)), &rings[0]);
+    if pieces.is_empty() && !covered() {
+        return Ok(None);
+    }
+
+    let mut cdt = Cdt::default();
+    let border = grid.border(tile);
+    let handles = border
+        .iter()
+        .map(|&(x, y)| cdt.insert(Point2::new(x, y)))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (i, &from) in handles.iter().enumerate() {
+        add_constraint_checked(&mut cdt, from, handles[(i + 1) % handles.len()])?;
+    }
+    let on_border = |(p, q): ((f64, f64), (f64, f64))| {
+        (p.0 == q.0 && (p.0 == xmin || p.0 == xmax)) || (p.1 == q.1 && (p.1 == ymin || p.1 == ymax))
+    };
+    let border_pieces: Vec<_> = pieces.iter().copied().filter(|&piece| on_border(piece)).collect();
+    for (p, q) in pieces {
+        let from = cdt.insert(Point2::new(p.0, p.1))?;
+        let to = cdt.insert(Point2::new(q.0, q.1))?;
+        add_constraint_checked(&mut cdt, from, to)?;
+    }
+
+    // Border and boundary pieces are already short enough and must stay as
+    // they are to match the neighboring tiles
+    let params = crate::refinement_parameters(Some(max_edge_length), min_angle).keep_constraint_edges();
+    let complete = cdt.refine(params).refinement_complete;
+
+    // Innermost ring 0 means inside the outer boundary and outside all holes
+    let labels = label_faces(&cdt, rings);
+    let mut remap: HashMap<usize, usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut index = |v: usize| {
+        *remap.entry(v).or_insert_with(|| {
+            let p = cdt.vertex(FixedVertexHandle::from_index(v)).position();
+            vertices.push((p.x, p.y, 0.0));
+            vertices.len() - 1
+        })
+    };
+    let mut triangles = Vec::new();
+    for face in cdt.inner_faces() {
+        if labels[&face.fix()] == Some(0) {
+            let [a, b, c] = face.vertices().map(|v| v.fix().index());
+            triangles.push((index(a), index(b), index(c)));
+        }
+    }
+    if triangles.is_empty() {
+        return Ok(None);
+    }
+
+    // Constraint edges on the tile border were added by the clip, unless a
+    // domain boundary piece along the border covers them
+    let edges = constraint_edges(&cdt)
+        .into_iter()
+        .filter(|&(a, b)| {
+            let [p, q] = [a, b].map(|v| cdt.vertex(FixedVertexHandle::from_index(v)).position());
+            let vertical = p.x == q.x && (p.x == xmin || p.x == xmax);
+            let horizontal = p.y == q.y && (p.y == ymin || p.y == ymax);
+            !(vertical || horizontal) || border_pieces.iter().any(|&piece| covers(piece, (p.x, p.y), (q.x, q.y)))
+        })
+        .filter_map(|(a, b)| Some((*remap.get(&a)?, *remap.get(&b)?)))
+        .collect();
+
+    Ok(Some((
+        TileMesh {
+            vertices,
+            triangles,
+            edges,
+            tile,
+            bounds,
+            global_indices: Vec::new(),
+        },
+        complete,
+    )))
+}
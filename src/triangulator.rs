@@ -0,0 +1,137 @@
+//! Reusable triangulator for meshing many small polygons in a row.
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::arcs::{self, RingItem};
+use crate::regions::Cdt;
+use crate::{parse_winding, to_py_err, triangulate_into, TriangulateOptions};
+
+/// Triangulates polygon after polygon with fixed settings, reusing the
+/// internal triangulation's memory between calls.
+///
+/// For services meshing thousands of small polygons per second, where
+/// allocation and argument parsing dominate. Each call gives the same result
+/// as `triangulate()` with the same settings.
+///
+/// Args:
+///     max_edge_length (float, optional): Target maximum edge length for mesh refinement
+///     min_angle (float, optional): Minimum angle constraint in degrees (0-33.9°)
+///     triangulate_holes (bool, optional): If True, mesh inside holes; if False, exclude them.
+///         Default: False
+///     grid_size (float, optional): Snap input coordinates to a grid with this spacing, as in
+///         `triangulate()`. Default: None
+///     sliver_tolerance (float, optional): Remove slivers thinner than this, as in
+///         `triangulate()`. Default: None
+///     relax_min_angle (bool, optional): Lower min_angle when refinement stalls, as in
+///         `triangulate()`. Default: False
+///     max_edge_length_rel (float, optional): max_edge_length as a fraction of each polygon's
+///         bounding-box diagonal, for batches of polygons of very different sizes. Cannot be
+///         combined with max_edge_length. Default: None
+///     timeout (float, optional): Refinement time limit in seconds per polygon, as in
+///         `triangulate()`. Default: None
+///     winding (str, optional): Output triangle orientation, "ccw" or "cw". Default: "ccw"
+///
+/// Example:
+///     >>> import pyspade
+///     >>> triangulator = pyspade.Triangulator(max_edge_length=2.0, min_angle=25.0)
+///     >>> meshes = [triangulator.triangulate(footprint) for footprint in footprints]
+#[pyclass]
+pub struct Triangulator {
+    options: TriangulateOptions,
+    max_edge_length_rel: Option<f64>,
+    cdt: Cdt,
+}
+
+#[pymethods]
+impl Triangulator {
+    #[new]
+    #[pyo3(signature = (
+        max_edge_length=None,
+        min_angle=None,
+        triangulate_holes=false,
+        grid_size=None,
+        sliver_tolerance=None,
+        relax_min_angle=false,
+        max_edge_length_rel=None,
+        timeout=None,
+        winding="ccw",
+    ))]
+    fn new(
+        max_edge_length: Option<f64>,
+        min_angle: Option<f64>,
+        triangulate_holes: bool,
+        grid_size: Option<f64>,
+        sliver_tolerance: Option<f64>,
+        relax_min_angle: bool,
+        max_edge_length_rel: Option<f64>,
+        timeout: Option<f64>,
+        winding: &str,
+    ) -> PyResult<Self> {
+        if max_edge_length.is_some() && max_edge_length_rel.is_some() {
+            return Err(to_py_err("give max_edge_length or max_edge_length_rel, not both".into()));
+        }
+        Ok(Triangulator {
+            options: TriangulateOptions {
+                max_edge_length,
+                min_angle,
+                triangulate_holes,
+                grid_size,
+                sliver_tolerance,
+                relax_min_angle,
+                timeout,
+                clockwise: parse_winding(winding).map_err(to_py_err)?,
+                ..TriangulateOptions::default()
+            },
+            max_edge_length_rel,
+            cdt: Cdt::default(),
+        })
+    }
+
+    /// Triangulate one polygon with optional holes.
+    ///
+    /// Args:
+    ///     outer (list): Exterior boundary vertices as list of (x, y) tuples or arc dicts
+    ///     holes (list, optional): List of hole polygons. Default: []
+    ///     diagnostics (bool, optional): If True, also return a diagnostics report. Default: False
+    ///
+    /// Returns:
+    ///     dict: As returned by `triangulate()`
+    #[pyo3(signature = (outer, holes=None, diagnostics=false))]
+    fn triangulate(
+        &mut self,
+        py: Python<'_>,
+        outer: Vec<RingItem>,
+        holes: Option<Vec<Vec<RingItem>>>,
+        diagnostics: bool,
+    ) -> PyResult<HashMap<String, PyObject>> {
+        if self.max_edge_length_rel.is_some() {
+            self.options.max_edge_length = arcs::resolve_edge_length(
+                std::iter::once(outer.as_slice()).chain(holes.iter().flatten().map(Vec::as_slice)),
+                None,
+                self.max_edge_length_rel,
+            )
+            .map_err(to_py_err)?;
+        }
+        let max_edge_length = self.options.max_edge_length;
+        let integer = self.options.grid_size.is_none()
+            && arcs::all_integer(std::iter::once(outer.as_slice()).chain(holes.iter().flatten().map(Vec::as_slice)));
+        let outer = arcs::expand_ring(&outer, max_edge_length).map_err(to_py_err)?;
+        let holes = holes
+            .map(|holes| {
+                holes
+                    .iter()
+                    .map(|hole| arcs::expand_ring(hole, max_edge_length))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+            .map_err(to_py_err)?;
+
+        let (cdt, options) = (&mut self.cdt, &self.options);
+        let result = py
+            .allow_threads(|| triangulate_into(cdt, outer, holes, options).map_err(|e| e.to_string()))
+            .map_err(|e| to_py_err(e.into()))?;
+
+        Ok(result.into_dict(py, diagnostics, integer))
+    }
+}
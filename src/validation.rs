@@ -0,0 +1,355 @@
+//! Consistency checks for meshes from any source, and for polygons before
+//! they are triangulated.
+
+use pyo3::prelude::*;
+use spade::Point2;
+use std::collections::HashMap;
+
+use crate::constraints::check_constraints_impl;
+use crate::geometry;
+use crate::mesh::vertex_row;
+use crate::sizing::segment_distance_2;
+
+/// Check a triangle mesh for common defects.
+///
+/// Unlike the functions taking a mesh dict, nothing is rejected up front:
+/// triangles with out-of-range indices are reported and left out of the
+/// remaining checks.
+///
+/// Args:
+///     vertices (list): Vertex coordinates as (x, y) or (x, y, z) tuples
+///     triangles (list): Triangles as (i, j, k) vertex indices
+///     tolerance (float, optional): Distance below which two vertices count as
+///         duplicates and a triangle's height counts as zero. Default: 0.0 (exact)
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'valid': True if no defect was found
+///         - 'invalid_triangles': Triangles with a negative or out-of-range index
+///         - 'degenerate_triangles': Triangles with a repeated index or zero area
+///         - 'flipped_triangles': Triangles whose winding (in the xy plane) is
+///           opposite to that of the majority
+///         - 'inconsistent_edges': (i, j) edges traversed in the same direction
+///           by both of their triangles
+///         - 'non_manifold_edges': (i, j) edges shared by more than two triangles
+///         - 'duplicate_vertices': (i, j) pairs where vertex j repeats vertex i < j
+///         - 'unreferenced_vertices': Vertices used by no triangle
+///
+/// Example:
+///     >>> import pyspade
+///     >>> report = pyspade.validate_mesh(mesh['vertices'], mesh['triangles'])
+///     >>> if not report['valid']:
+///     ...     print(report['non_manifold_edges'])
+#[pyfunction]
+#[pyo3(signature = (vertices, triangles, tolerance=0.0))]
+pub fn validate_mesh(
+    vertices: Vec<Vec<f64>>,
+    triangles: Vec<(i64, i64, i64)>,
+    tolerance: f64,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let points = vertices.iter().map(|row| vertex_row(row)).collect::<PyResult<Vec<_>>>()?;
+        let report = validate_mesh_impl(&points, &triangles, tolerance).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("valid".to_string(), report.is_valid().into_py(py));
+        output.insert("invalid_triangles".to_string(), report.invalid_triangles.into_py(py));
+        output.insert("degenerate_triangles".to_string(), report.degenerate_triangles.into_py(py));
+        output.insert("flipped_triangles".to_string(), report.flipped_triangles.into_py(py));
+        output.insert("inconsistent_edges".to_string(), report.inconsistent_edges.into_py(py));
+        output.insert("non_manifold_edges".to_string(), report.non_manifold_edges.into_py(py));
+        output.insert("duplicate_vertices".to_string(), report.duplicate_vertices.into_py(py));
+        output.insert("unreferenced_vertices".to_string(), report.unreferenced_vertices.into_py(py));
+
+        Ok(output)
+    })
+}
+
+#[derive(Default)]
+pub(crate) struct ValidationReport {
+    pub invalid_triangles: Vec<usize>,
+    pub degenerate_triangles: Vec<usize>,
+    pub flipped_triangles: Vec<usize>,
+    pub inconsistent_edges: Vec<(usize, usize)>,
+    pub non_manifold_edges: Vec<(usize, usize)>,
+    pub duplicate_vertices: Vec<(usize, usize)>,
+    pub unreferenced_vertices: Vec<usize>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.invalid_triangles.is_empty()
+            && self.degenerate_triangles.is_empty()
+            && self.flipped_triangles.is_empty()
+            && self.inconsistent_edges.is_empty()
+            && self.non_manifold_edges.is_empty()
+            && self.duplicate_vertices.is_empty()
+            && self.unreferenced_vertices.is_empty()
+    }
+}
+
+pub(crate) fn validate_mesh_impl(
+    vertices: &[(f64, f64, f64)],
+    triangles: &[(i64, i64, i64)],
+    tolerance: f64,
+) -> Result<ValidationReport, Box<dyn std::error::Error>> {
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(format!("tolerance must be non-negative, got {}", tolerance).into());
+    }
+    let mut report = ValidationReport::default();
+    let n = vertices.len() as i64;
+
+    let mut valid: Vec<(usize, (usize, usize, usize))> = Vec::with_capacity(triangles.len());
+    for (t, &(a, b, c)) in triangles.iter().enumerate() {
+        if [a, b, c].iter().all(|&v| (0..n).contains(&v)) {
+            valid.push((t, (a as usize, b as usize, c as usize)));
+        } else {
+            report.invalid_triangles.push(t);
+        }
+    }
+
+    // Degenerate triangles, and the winding of the others
+    let mut windings: Vec<(usize, f64)> = Vec::with_capacity(valid.len());
+    for &(t, (a, b, c)) in &valid {
+        let [p, q, r] = [a, b, c].map(|v| vertices[v]);
+        let cross = (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0);
+        // Twice the area in 3D, for meshes that are not flat
+        let (ux, uy, uz) = (q.0 - p.0, q.1 - p.1, q.2 - p.2);
+        let (vx, vy, vz) = (r.0 - p.0, r.1 - p.1, r.2 - p.2);
+        let area_2 = (uy * vz - uz * vy).hypot(uz * vx - ux * vz).hypot(cross);
+        let longest = [(p, q), (q, r), (r, p)]
+            .iter()
+            .map(|(s, e)| (e.0 - s.0).hypot(e.1 - s.1).hypot(e.2 - s.2))
+            .fold(0.0, f64::max);
+        if a == b || b == c || c == a || area_2 <= tolerance * longest {
+            report.degenerate_triangles.push(t);
+        } else if cross != 0.0 {
+            windings.push((t, cross));
+        }
+    }
+    let counter_clockwise = windings.iter().filter(|&&(_, cross)| cross > 0.0).count();
+    let majority = if 2 * counter_clockwise >= windings.len() { 1.0 } else { -1.0 };
+    report.flipped_triangles = windings
+        .iter()
+        .filter(|&&(_, cross)| cross * majority < 0.0)
+        .map(|&(t, _)| t)
+        .collect();
+
+    // Edge use: number of triangles and how many traverse it from low to high
+    let mut uses: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    for &(_, (a, b, c)) in &valid {
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            if u == v {
+                continue;
+            }
+            let entry = uses.entry((u.min(v), u.max(v))).or_default();
+            entry.0 += 1;
+            entry.1 += usize::from(u < v);
+        }
+    }
+    let mut edges: Vec<((usize, usize), (usize, usize))> = uses.into_iter().collect();
+    edges.sort_unstable();
+    for (edge, (count, forward)) in edges {
+        if count > 2 {
+            report.non_manifold_edges.push(edge);
+        } else if count == 2 && forward != 1 {
+            report.inconsistent_edges.push(edge);
+        }
+    }
+
+    let mut referenced = vec![false; vertices.len()];
+    for &(_, (a, b, c)) in &valid {
+        for v in [a, b, c] {
+            referenced[v] = true;
+        }
+    }
+    report.unreferenced_vertices = (0..vertices.len()).filter(|&v| !referenced[v]).collect();
+    report.duplicate_vertices = duplicate_vertices(vertices, tolerance);
+
+    Ok(report)
+}
+
+/// Check a polygon with holes for defects, without triangulating it.
+///
+/// Rings are numbered 0 for the outer ring and k for hole k - 1; segments as
+/// for `triangulate()`'s protected_segments. A last vertex repeating the first
+/// closes the ring and is not reported as a duplicate.
+///
+/// Args:
+///     outer (list): Exterior boundary vertices as list of (x, y) tuples
+///     holes (list, optional): List of hole polygons, each as list of (x, y) tuples. Default: []
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'valid': True if no defect was found (orientation is not a defect)
+///         - 'intersections': (i, j) pairs of segments that cross, touch or overlap
+///         - 'intersection_points': (x, y) location of each intersection
+///         - 'duplicate_points': ((ring, vertex), (ring, vertex)) pairs where the second
+///           vertex repeats the first
+///         - 'degenerate_rings': Rings with fewer than 3 distinct vertices or zero area
+///         - 'clockwise_rings': Rings running clockwise
+///         - 'holes_outside': Indices into holes of holes not inside the outer ring
+///         - 'nested_holes': (i, j) index pairs where holes[j] lies inside holes[i]
+///
+/// Example:
+///     >>> import pyspade
+///     >>> report = pyspade.validate(outer, holes)
+///     >>> if not report['valid']:
+///     ...     print(report['intersections'], report['holes_outside'])
+#[pyfunction]
+#[pyo3(signature = (outer, holes=None))]
+pub fn validate(outer: Vec<(f64, f64)>, holes: Option<Vec<Vec<(f64, f64)>>>) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let report = validate_impl(&outer, &holes.unwrap_or_default());
+
+        let mut output = HashMap::new();
+        output.insert("valid".to_string(), report.is_valid().into_py(py));
+        output.insert("intersections".to_string(), report.intersections.into_py(py));
+        output.insert("intersection_points".to_string(), report.intersection_points.into_py(py));
+        output.insert("duplicate_points".to_string(), report.duplicate_points.into_py(py));
+        output.insert("degenerate_rings".to_string(), report.degenerate_rings.into_py(py));
+        output.insert("clockwise_rings".to_string(), report.clockwise_rings.into_py(py));
+        output.insert("holes_outside".to_string(), report.holes_outside.into_py(py));
+        output.insert("nested_holes".to_string(), report.nested_holes.into_py(py));
+
+        Ok(output)
+    })
+}
+
+type RingVertex = (usize, usize);
+
+#[derive(Default)]
+pub(crate) struct PolygonReport {
+    pub intersections: Vec<(usize, usize)>,
+    pub intersection_points: Vec<(f64, f64)>,
+    pub duplicate_points: Vec<(RingVertex, RingVertex)>,
+    pub degenerate_rings: Vec<usize>,
+    pub clockwise_rings: Vec<usize>,
+    pub holes_outside: Vec<usize>,
+    pub nested_holes: Vec<(usize, usize)>,
+}
+
+impl PolygonReport {
+    pub fn is_valid(&self) -> bool {
+        self.intersections.is_empty()
+            && self.duplicate_points.is_empty()
+            && self.degenerate_rings.is_empty()
+            && self.holes_outside.is_empty()
+            && self.nested_holes.is_empty()
+    }
+}
+
+/// `ring` without a last vertex that merely repeats the first.
+pub(crate) fn open_ring(ring: &[(f64, f64)]) -> &[(f64, f64)] {
+    match ring {
+        [first, rest @ .., last] if first == last && !rest.is_empty() => &ring[..ring.len() - 1],
+        _ => ring,
+    }
+}
+
+pub(crate) fn validate_impl(outer: &[(f64, f64)], holes: &[Vec<(f64, f64)>]) -> PolygonReport {
+    let rings: Vec<&[(f64, f64)]> = std::iter::once(outer)
+        .chain(holes.iter().map(Vec::as_slice))
+        .map(open_ring)
+        .collect();
+    let mut report = PolygonReport::default();
+
+    let open_holes: Vec<Vec<(f64, f64)>> = rings[1..].iter().map(|ring| ring.to_vec()).collect();
+    for conflict in check_constraints_impl(rings[0], &open_holes, &[]) {
+        report.intersections.push(conflict.pair);
+        report.intersection_points.push(conflict.point);
+    }
+
+    let vertices: Vec<(f64, f64, f64)> = rings.iter().flat_map(|ring| ring.iter().map(|&(x, y)| (x, y, 0.0))).collect();
+    let ring_vertex: Vec<RingVertex> = rings
+        .iter()
+        .enumerate()
+        .flat_map(|(r, ring)| (0..ring.len()).map(move |v| (r, v)))
+        .collect();
+    report.duplicate_points = duplicate_vertices(&vertices, 0.0)
+        .into_iter()
+        .map(|(i, j)| (ring_vertex[i], ring_vertex[j]))
+        .collect();
+
+    for (r, ring) in rings.iter().enumerate() {
+        let area = geometry::signed_area(ring);
+        if ring.len() < 3 || area == 0.0 {
+            report.degenerate_rings.push(r);
+        } else if area < 0.0 {
+            report.clockwise_rings.push(r);
+        }
+    }
+
+    for (h, hole) in rings[1..].iter().enumerate() {
+        if !hole.is_empty() && !ring_inside(hole, rings[0]) {
+            report.holes_outside.push(h);
+        }
+        for (g, other) in rings[1..].iter().enumerate() {
+            if g != h && !hole.is_empty() && ring_inside(hole, other) {
+                report.nested_holes.push((g, h));
+            }
+        }
+    }
+
+    report
+}
+
+/// Whether every vertex of `inner` lies inside or on `outer`, with at least
+/// one strictly inside.
+pub(crate) fn ring_inside(inner: &[(f64, f64)], outer: &[(f64, f64)]) -> bool {
+    let point = |(x, y): (f64, f64)| Point2::new(x, y);
+    let on_boundary =
+        |p: (f64, f64)| geometry::ring_edges(outer).any(|(s, t)| segment_distance_2(point(p), point(s), point(t)) == 0.0);
+    let mut strictly_inside = false;
+    for &p in inner {
+        if on_boundary(p) {
+            continue;
+        }
+        if !geometry::point_in_polygon(p, outer) {
+            return false;
+        }
+        strictly_inside = true;
+    }
+    strictly_inside
+}
+
+/// Pairs (i, j), i < j, of vertices at most `tolerance` apart; each vertex is
+/// paired with the first vertex it repeats.
+pub(crate) fn duplicate_vertices(vertices: &[(f64, f64, f64)], tolerance: f64) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    if tolerance == 0.0 {
+        let key = |p: (f64, f64, f64)| [p.0, p.1, p.2].map(|x| (x + 0.0).to_bits());
+        let mut first: HashMap<[u64; 3], usize> = HashMap::new();
+        for (j, &p) in vertices.iter().enumerate() {
+            match first.get(&key(p)) {
+                Some(&i) => pairs.push((i, j)),
+                None => {
+                    first.insert(key(p), j);
+                }
+            }
+        }
+        return pairs;
+    }
+
+    let cell = |p: (f64, f64, f64)| ((p.0 / tolerance).floor() as i64, (p.1 / tolerance).floor() as i64);
+    let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (j, &p) in vertices.iter().enumerate() {
+        let (cx, cy) = cell(p);
+        let repeated = (cx - 1..=cx + 1)
+            .flat_map(|x| (cy - 1..=cy + 1).map(move |y| (x, y)))
+            .filter_map(|c| grid.get(&c))
+            .flatten()
+            .copied()
+            .filter(|&i| {
+                let q = vertices[i];
+                (p.0 - q.0).hypot(p.1 - q.1).hypot(p.2 - q.2) <= tolerance
+            })
+            .min();
+        match repeated {
+            Some(i) => pairs.push((i, j)),
+            // Only vertices that repeat nothing are kept as references
+            None => grid.entry((cx, cy)).or_default().push(j),
+        }
+    }
+    pairs
+}
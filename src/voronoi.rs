@@ -0,0 +1,260 @@
+use pyo3::prelude::*;
+use spade::{DelaunayTriangulation, FixedVertexHandle, Point2, Triangulation};
+use std::collections::HashMap;
+
+use crate::geometry;
+
+pub(crate) type Delaunay = DelaunayTriangulation<Point2<f64>>;
+
+/// Compute the Voronoi diagram of a point set, clipped to a bounding box.
+///
+/// Args:
+///     points (list): Site coordinates as list of (x, y) tuples
+///     bounds (tuple, optional): Clipping box as (xmin, ymin, xmax, ymax).
+///         Default: bounding box of the sites padded by 10% of its extent
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'cells': List of Voronoi cell polygons (counter-clockwise (x, y) lists), one per site
+///         - 'areas': List of cell areas, one per site; duplicate sites share their cell's
+///           area evenly, so the areas sum to the area of the clipping box
+///         - 'centroids': List of (x, y) cell centroids, one per site
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.voronoi([(0, 0), (1, 0), (0, 1), (1, 1)])
+///     >>> print(result['areas'])
+#[pyfunction]
+#[pyo3(signature = (points, bounds=None))]
+pub fn voronoi(
+    points: Vec<(f64, f64)>,
+    bounds: Option<(f64, f64, f64, f64)>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let result = voronoi_impl(&points, bounds).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("cells".to_string(), result.cells.into_py(py));
+        output.insert("areas".to_string(), result.areas.into_py(py));
+        output.insert("centroids".to_string(), result.centroids.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) struct VoronoiResult {
+    pub cells: Vec<Vec<(f64, f64)>>,
+    pub areas: Vec<f64>,
+    pub centroids: Vec<(f64, f64)>,
+}
+
+pub(crate) fn voronoi_impl(
+    points: &[(f64, f64)],
+    bounds: Option<(f64, f64, f64, f64)>,
+) -> Result<VoronoiResult, Box<dyn std::error::Error>> {
+    let (xmin, ymin, xmax, ymax) = match bounds {
+        Some(bounds) => bounds,
+        None => {
+            let (xmin, ymin, xmax, ymax) =
+                geometry::bounding_box(points).ok_or("at least one point is required")?;
+            let extent = (xmax - xmin).max(ymax - ymin);
+            let margin = if extent > 0.0 { 0.1 * extent } else { 1.0 };
+            (xmin - margin, ymin - margin, xmax + margin, ymax + margin)
+        }
+    };
+    if !(xmin < xmax && ymin < ymax) {
+        return Err("bounds must satisfy xmin < xmax and ymin < ymax".into());
+    }
+
+    let (dt, handles) = delaunay(points)?;
+    let bbox = [(xmin, ymin), (xmax, ymin), (xmax, ymax), (xmin, ymax)];
+
+    // Duplicate sites share a handle, so compute each cell only once
+    let mut cell_cache: HashMap<FixedVertexHandle, usize> = HashMap::new();
+    let mut unique_cells: Vec<Vec<(f64, f64)>> = Vec::new();
+    let mut cells = Vec::with_capacity(handles.len());
+    let mut areas = Vec::with_capacity(handles.len());
+    let mut centroids = Vec::with_capacity(handles.len());
+
+    let mut copies: HashMap<FixedVertexHandle, usize> = HashMap::new();
+    for &handle in &handles {
+        *copies.entry(handle).or_default() += 1;
+    }
+    for handle in handles {
+        let slot = *cell_cache.entry(handle).or_insert_with(|| {
+            unique_cells.push(cell_polygon(&dt, handle, &bbox));
+            unique_cells.len() - 1
+        });
+        let cell = unique_cells[slot].clone();
+        areas.push(geometry::signed_area(&cell).abs() / copies[&handle] as f64);
+        centroids.push(geometry::centroid(&cell));
+        cells.push(cell);
+    }
+
+    Ok(VoronoiResult {
+        cells,
+        areas,
+        centroids,
+    })
+}
+
+/// Build a Delaunay triangulation of `points`, returning the handle of each
+/// input point in input order (duplicates map to the same handle).
+pub(crate) fn delaunay(
+    points: &[(f64, f64)],
+) -> Result<(Delaunay, Vec<FixedVertexHandle>), Box<dyn std::error::Error>> {
+    let mut dt = Delaunay::default();
+    let mut handles = Vec::with_capacity(points.len());
+    for &(x, y) in points {
+        handles.push(dt.insert(Point2::new(x, y))?);
+    }
+    Ok((dt, handles))
+}
+
+/// The Voronoi cell of a site is the intersection of the half-planes bounded
+/// by the bisectors to its Delaunay neighbors, intersected with `clip`.
+fn cell_polygon(dt: &Delaunay, site: FixedVertexHandle, clip: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let vertex = dt.vertex(site);
+    let s = vertex.position();
+    let mut cell = clip.to_vec();
+    for edge in vertex.out_edges() {
+        let n = edge.to().position();
+        let a = n.x - s.x;
+        let b = n.y - s.y;
+        let c = 0.5 * (n.x * n.x + n.y * n.y - s.x * s.x - s.y * s.y);
+        cell = geometry::clip_half_plane(&cell, a, b, c);
+        if cell.is_empty() {
+            break;
+        }
+    }
+    cell
+}
+
+/// Find the largest circle whose center lies in the domain and that contains
+/// no input point.
+///
+/// Candidate centers are the Voronoi vertices inside the domain, the domain
+/// corners, and the points where the domain boundary crosses Voronoi edges.
+///
+/// Args:
+///     points (list): Input points as list of (x, y) tuples
+///     boundary (list, optional): Polygon restricting the circle center, as list
+///         of (x, y) tuples. Default: convex hull of the points
+///
+/// Returns:
+///     dict: Dictionary with keys:
+///         - 'center': (x, y) center of the largest empty circle
+///         - 'radius': Radius of the circle (distance to the nearest input point)
+///
+/// Example:
+///     >>> import pyspade
+///     >>> result = pyspade.largest_empty_circle([(0, 0), (10, 0), (10, 10), (0, 10)])
+///     >>> print(result['center'], result['radius'])
+#[pyfunction]
+#[pyo3(signature = (points, boundary=None))]
+pub fn largest_empty_circle(
+    points: Vec<(f64, f64)>,
+    boundary: Option<Vec<(f64, f64)>>,
+) -> PyResult<HashMap<String, PyObject>> {
+    Python::with_gil(|py| {
+        let (center, radius) =
+            largest_empty_circle_impl(&points, boundary.as_deref()).map_err(crate::to_py_err)?;
+
+        let mut output = HashMap::new();
+        output.insert("center".to_string(), center.into_py(py));
+        output.insert("radius".to_string(), radius.into_py(py));
+
+        Ok(output)
+    })
+}
+
+pub(crate) fn largest_empty_circle_impl(
+    points: &[(f64, f64)],
+    boundary: Option<&[(f64, f64)]>,
+) -> Result<((f64, f64), f64), Box<dyn std::error::Error>> {
+    if points.is_empty() {
+        return Err("at least one point is required".into());
+    }
+    let (dt, _) = delaunay(points)?;
+
+    let domain: Vec<(f64, f64)> = match boundary {
+        Some(boundary) if boundary.len() < 3 => {
+            return Err("boundary must have at least 3 vertices".into());
+        }
+        Some(boundary) => boundary.to_vec(),
+        None => dt
+            .convex_hull()
+            .map(|edge| {
+                let p = edge.from().position();
+                (p.x, p.y)
+            })
+            .collect(),
+    };
+    if domain.is_empty() {
+        return Err("at least two distinct points are required".into());
+    }
+
+    let mut candidates: Vec<(f64, f64)> = domain.clone();
+    for face in dt.inner_faces() {
+        let c = face.circumcenter();
+        if geometry::point_in_polygon((c.x, c.y), &domain) {
+            candidates.push((c.x, c.y));
+        }
+    }
+    for (p, q) in geometry::ring_edges(&domain) {
+        voronoi_crossings(&dt, p, q, &mut candidates);
+    }
+
+    let mut best = (candidates[0], -1.0);
+    for candidate in candidates {
+        let nearest = dt
+            .nearest_neighbor(Point2::new(candidate.0, candidate.1))
+            .ok_or("triangulation is empty")?
+            .position();
+        let radius = ((nearest.x - candidate.0).powi(2) + (nearest.y - candidate.1).powi(2)).sqrt();
+        if radius > best.1 {
+            best = (candidate, radius);
+        }
+    }
+    Ok(best)
+}
+
+/// Walk along segment `p -> q` through the Voronoi cells it crosses, pushing
+/// every point where the segment leaves one cell and enters the next.
+fn voronoi_crossings(dt: &Delaunay, p: (f64, f64), q: (f64, f64), out: &mut Vec<(f64, f64)>) {
+    let Some(mut site) = dt.nearest_neighbor(Point2::new(p.0, p.1)) else {
+        return;
+    };
+    let d = (q.0 - p.0, q.1 - p.1);
+    let mut t = 0.0;
+    // Each step strictly advances t, so the walk visits every cell at most once
+    for _ in 0..dt.num_vertices() {
+        let s = site.position();
+        let mut next = None;
+        for edge in site.out_edges() {
+            let n = edge.to().position();
+            let (a, b) = (n.x - s.x, n.y - s.y);
+            let c = 0.5 * (n.x * n.x + n.y * n.y - s.x * s.x - s.y * s.y);
+            let slope = a * d.0 + b * d.1;
+            if slope <= 0.0 {
+                continue;
+            }
+            let t_n = (c - a * p.0 - b * p.1) / slope;
+            let closer = match next {
+                Some((best, _)) => t_n < best,
+                None => true,
+            };
+            if t_n > t && closer {
+                next = Some((t_n, edge.to()));
+            }
+        }
+        match next {
+            Some((t_n, neighbor)) if t_n < 1.0 => {
+                out.push((p.0 + t_n * d.0, p.1 + t_n * d.1));
+                t = t_n;
+                site = neighbor;
+            }
+            _ => break,
+        }
+    }
+}
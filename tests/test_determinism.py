@@ -0,0 +1,84 @@
+"""
+Output of pyspade must not depend on hash iteration order, which Rust seeds
+randomly per process. Each case runs in two fresh interpreters and the
+results are compared exactly.
+"""
+
+import subprocess
+import sys
+import textwrap
+
+import pytest
+
+CANONICAL = """
+def canonical(value):
+    if isinstance(value, dict):
+        return "{" + ", ".join(f"{canonical(k)}: {canonical(v)}" for k, v in sorted(value.items())) + "}"
+    if isinstance(value, (list, tuple)):
+        return "[" + ", ".join(canonical(v) for v in value) + "]"
+    if hasattr(value, "tolist"):
+        return canonical(value.tolist())
+    return repr(value)
+"""
+
+TERRAIN = """
+import math
+points = [
+    (x, y, math.sin(x / 7.0) * math.cos(y / 5.0) * 10.0 + 0.1 * x)
+    for x in range(0, 60, 3)
+    for y in range(0, 60, 3)
+]
+terrain = pyspade.tin(points)
+"""
+
+CASES = {
+    "mesh_statistics": TERRAIN + """
+result = pyspade.mesh_statistics(terrain['vertices'], terrain['triangles'])
+""",
+    "mesh_subdomains": """
+result = pyspade.mesh_subdomains(
+    [[(0, 0), (5, 0), (5, 10), (0, 10)], [(5, 0), (10, 0), (10, 10), (5, 10)], [(0, 10), (10, 10), (5, 15)]],
+    max_edge_length=1.0,
+)
+""",
+    "merge": """
+left = pyspade.triangulate([(0, 0), (5, 0), (5, 10), (0, 10)], max_edge_length=1.0)
+right = pyspade.triangulate([(5, 0), (10, 0), (10, 10), (5, 10)], max_edge_length=1.5)
+result = pyspade.merge([left, right])
+""",
+    "smooth": """
+mesh = pyspade.triangulate([(0, 0), (10, 0), (10, 10), (0, 10)], holes=[[(3, 3), (6, 4), (4, 7)]], max_edge_length=1.0)
+result = pyspade.smooth(mesh, iterations=5)
+""",
+    "triangulate_tiled": """
+result = pyspade.triangulate_tiled(
+    [(0, 0), (35, 0), (35, 30), (0, 30)], tile_size=10.0, max_edge_length=2.0, holes=[[(12, 12), (18, 12), (15, 18)]]
+)
+""",
+    "voronoi": TERRAIN + """
+result = pyspade.voronoi([(x, y) for x, y, _ in points] + [(3.0, 3.0)], bounds=(-5.0, -5.0, 65.0, 65.0))
+""",
+    "knn_graph": TERRAIN + """
+result = pyspade.knn_graph([(x, y) for x, y, _ in points], k=6)
+""",
+    "filled_contours": TERRAIN + """
+result = pyspade.filled_contours(terrain, levels=[-math.inf, -5.0, 0.0, 2.5, 5.0, math.inf])
+""",
+    "isobands": TERRAIN + """
+values = [math.hypot(x - 30.0, y - 20.0) for x, y, _ in terrain['vertices']]
+result = pyspade.isobands(terrain, values, breaks=[0.0, 5.0, 10.0, 20.0, 40.0])
+""",
+}
+
+
+def run(code):
+    script = "import pyspade\n" + CANONICAL + textwrap.dedent(code) + "\nprint(canonical(result))\n"
+    completed = subprocess.run([sys.executable, "-c", script], capture_output=True, text=True, check=True)
+    return completed.stdout
+
+
+@pytest.mark.parametrize("name", sorted(CASES))
+def test_identical_across_processes(name):
+    first = run(CASES[name])
+    second = run(CASES[name])
+    assert first == second
@@ -0,0 +1,20 @@
+import numpy as np
+import pytest
+
+import pyspade
+
+
+def test_knn_graph_matches_brute_force():
+    rng = np.random.default_rng(3)
+    points = rng.uniform(0.0, 10.0, size=(40, 2))
+    result = pyspade.knn_graph([tuple(p) for p in points], k=4)
+    assert result["indices"].shape == (40, 4) and result["distances"].shape == (40, 4)
+
+    distances = np.hypot(*(points[:, None, :] - points[None, :, :]).transpose(2, 0, 1))
+    np.fill_diagonal(distances, np.inf)
+    assert np.allclose(result["distances"], np.sort(distances, axis=1)[:, :4])
+
+
+def test_knn_graph_rejects_k_of_n():
+    with pytest.raises(RuntimeError):
+        pyspade.knn_graph([(0, 0), (1, 0), (0, 1)], k=3)
@@ -0,0 +1,30 @@
+import numpy as np
+
+import pyspade
+
+
+def test_nearest_finds_vertices_behind_constraints():
+    # A constraint wall at y = 0.5 with a dense row of vertices just above it
+    # and a sparse one well below, so the vertices of the faces under the wall
+    # are rarely the closest
+    bottom = [(-10.0, -1.0), (0.0, -1.0), (10.0, -1.0)]
+    wall = [(-10.0, 0.5), (10.0, 0.5), (0.0, 5.0)]
+    row = [(float(x), 0.6) for x in range(-9, 10)]
+    vertices = [(x, y, 0.0) for x, y in bottom + wall + row]
+    mesh = {"vertices": vertices, "triangles": [(3, 4, 5)]}
+    interpolator = pyspade.Interpolator.from_mesh(mesh, values=np.arange(len(vertices), dtype=float), outside="nearest")
+
+    queries = np.array([(x, y) for x in np.linspace(-9.5, 9.5, 39) for y in np.linspace(-0.9, 0.4, 6)])
+    xy = np.array(vertices)[:, :2]
+    expected = [np.argmin(((xy - query) ** 2).sum(axis=1)) for query in queries]
+    assert list(np.asarray(interpolator(queries), dtype=int)) == expected
+
+
+def test_multi_channel_values_give_a_2d_array():
+    points = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 1.0, 0.0)]
+    values = np.array([[0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [3.0, 1.0]])
+    interpolator = pyspade.Interpolator(points, values=values)
+    result = interpolator([(0.0, 0.0), (5.0, 5.0)])
+    assert result.dtype == np.float64 and result.shape == (2, 2)
+    assert list(result[0]) == [0.0, 1.0]
+    assert np.isnan(result[1]).all()
@@ -0,0 +1,26 @@
+from collections import Counter
+
+import pyspade
+
+
+def edge_counts(mesh):
+    counts = Counter()
+    for a, b, c in mesh["triangles"]:
+        for u, v in ((a, b), (b, c), (c, a)):
+            counts[min(u, v), max(u, v)] += 1
+    return counts
+
+
+def test_merged_tiles_are_watertight():
+    left = pyspade.triangulate([(0, 0), (5, 0), (5, 10), (0, 10)], max_edge_length=1.0)
+    right = pyspade.triangulate([(5, 0), (10, 0), (10, 10), (5, 10)], max_edge_length=1.5)
+    merged = pyspade.merge([left, right])
+    assert not merged["unmatched"]
+
+    counts = edge_counts(merged)
+    assert max(counts.values()) == 2
+    # Only the outer rectangle is left as boundary
+    for u, v in (edge for edge, count in counts.items() if count == 1):
+        (ux, uy, _), (vx, vy, _) = merged["vertices"][u], merged["vertices"][v]
+        assert (ux == vx and ux in (0, 10)) or (uy == vy and uy in (0, 10))
+    assert sorted(set(merged["sources"])) == [0, 1]
@@ -0,0 +1,42 @@
+import pytest
+
+import pyspade
+
+OUTER = [(0, 0), (10, 0), (10, 10), (0, 10)]
+INNER = [(3, 3), (7, 3), (7, 7), (3, 7)]
+
+
+def mean_area(mesh, inside):
+    areas = []
+    for a, b, c in mesh["triangles"]:
+        (ax, ay, _), (bx, by, _), (cx, cy, _) = (mesh["vertices"][v] for v in (a, b, c))
+        centroid = ((ax + bx + cx) / 3.0, (ay + by + cy) / 3.0)
+        if inside(centroid):
+            areas.append(abs((bx - ax) * (cy - ay) - (cx - ax) * (by - ay)) / 2.0)
+    return sum(areas) / len(areas)
+
+
+def in_inner(point):
+    return 3 < point[0] < 7 and 3 < point[1] < 7
+
+
+def test_region_sizes_refine_their_region():
+    mesh = pyspade.triangulate(
+        OUTER, holes=[INNER], triangulate_holes=True, max_edge_length=2.0, region_sizes=[(5, 5, 0.5)]
+    )
+    inner = mean_area(mesh, in_inner)
+    outer = mean_area(mesh, lambda point: not in_inner(point))
+    assert inner * 4 < outer
+
+
+def test_region_size_above_global_limit_raises():
+    with pytest.raises(ValueError, match="exceeds max_edge_length"):
+        pyspade.triangulate(
+            OUTER, holes=[INNER], triangulate_holes=True, max_edge_length=1.0, region_sizes=[(5, 5, 3.0)]
+        )
+
+
+@pytest.mark.parametrize("max_edge_length", [0.0, -1.0, float("nan"), float("inf")])
+def test_invalid_max_edge_length_is_rejected(max_edge_length):
+    with pytest.raises(RuntimeError, match="max_edge_length must be positive and finite"):
+        pyspade.triangulate(OUTER, max_edge_length=max_edge_length)
@@ -0,0 +1,28 @@
+import math
+
+import pyspade
+
+
+def boundary_length(result):
+    length = 0.0
+    for tile in result["tiles"]:
+        for a, b in tile["edges"]:
+            (ax, ay, _), (bx, by, _) = tile["vertices"][a], tile["vertices"][b]
+            length += math.hypot(bx - ax, by - ay)
+    return length
+
+
+def test_tiles_keep_domain_boundary_on_tile_lines():
+    result = pyspade.triangulate_tiled(
+        outer=[(0, 0), (40, 0), (40, 40), (0, 40)], tile_size=10.0, max_edge_length=2.0
+    )
+    assert len(result["tiles"]) == 16
+    assert math.isclose(boundary_length(result), 160.0)
+
+
+def test_tiles_keep_hole_edges_and_drop_tile_borders():
+    hole = [(10, 10), (30, 10), (30, 30), (10, 30)]
+    result = pyspade.triangulate_tiled(
+        outer=[(0, 0), (35, 0), (35, 35), (0, 35)], tile_size=10.0, max_edge_length=2.0, holes=[hole]
+    )
+    assert math.isclose(boundary_length(result), 4 * 35.0 + 4 * 20.0)
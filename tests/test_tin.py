@@ -0,0 +1,22 @@
+import numpy as np
+
+import pyspade
+
+
+def chunks():
+    rng = np.random.default_rng(7)
+    for offset in (0.0, 10.0):
+        xy = rng.uniform(0.0, 10.0, size=(50, 2)) + (offset, 0.0)
+        yield np.column_stack([xy, np.sin(xy[:, 0]) + xy[:, 1]])
+
+
+def test_list_of_chunks_matches_generator():
+    from_list = pyspade.tin(list(chunks()))
+    from_generator = pyspade.tin(chunk for chunk in chunks())
+    assert from_list == from_generator
+    assert len(from_list["vertices"]) == 100
+
+
+def test_list_of_tuples_is_one_point_array():
+    points = [tuple(point) for point in np.concatenate(list(chunks()))]
+    assert pyspade.tin(points) == pyspade.tin(np.concatenate(list(chunks())))
@@ -0,0 +1,11 @@
+import math
+
+import pyspade
+
+
+def test_cell_areas_cover_the_box():
+    sites = [(1.0, 1.0), (4.0, 2.0), (2.0, 5.0), (7.0, 7.0), (4.0, 2.0)]
+    result = pyspade.voronoi(sites, bounds=(0.0, 0.0, 10.0, 10.0))
+    assert len(result["cells"]) == len(sites)
+    assert math.isclose(sum(result["areas"]), 100.0)
+    assert result["areas"][1] == result["areas"][4]